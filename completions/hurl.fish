@@ -10,7 +10,11 @@ complete -c hurl -l continue-on-error -d 'Continue executing requests even if an
 complete -c hurl -l cookie -d 'Read cookies from FILE'
 complete -c hurl -l cookie-jar -d 'Write cookies to FILE after running the session (only for one session)'
 complete -c hurl -l curl -d 'Export each request to a list of curl commands'
+complete -c hurl -l default-header -d 'Add a header to each request that doesn't already have it'
 complete -c hurl -l delay -d 'Sets delay before each request (aka sleep)'
+complete -c hurl -l dry-run -d 'Evaluate requests without sending them'
+complete -c hurl -l env-prefix -d 'Allow templates to read environment variables starting with PREFIX'
+complete -c hurl -l environment -d 'Select the environment section of the variables files'
 complete -c hurl -l error-format -d 'Control the format of error messages'
 complete -c hurl -l file-root -d 'Set root directory to import files [default: input file directory]'
 complete -c hurl -l location -d 'Follow redirects'
@@ -21,6 +25,7 @@ complete -c hurl -l header -d 'Pass custom header(s) to server'
 complete -c hurl -l http1.0 -d 'Tell Hurl to use HTTP version 1.0'
 complete -c hurl -l http1.1 -d 'Tell Hurl to use HTTP version 1.1'
 complete -c hurl -l http2 -d 'Tell Hurl to use HTTP version 2'
+complete -c hurl -l http2-prior-knowledge -d 'Tell Hurl to use HTTP/2 without HTTP/1.1 Upgrade'
 complete -c hurl -l http3 -d 'Tell Hurl to use HTTP version 3'
 complete -c hurl -l ignore-asserts -d 'Ignore asserts defined in the Hurl file'
 complete -c hurl -l include -d 'Include the HTTP headers in the output'
@@ -52,6 +57,9 @@ complete -c hurl -l report-tap -d 'Write a TAP report to FILE'
 complete -c hurl -l resolve -d 'Provide a custom address for a specific HOST and PORT pair'
 complete -c hurl -l retry -d 'Maximum number of retries, 0 for no retries, -1 for unlimited retries'
 complete -c hurl -l retry-interval -d 'Interval in milliseconds before a retry'
+complete -c hurl -l retry-max-interval -d 'Maximum interval in milliseconds before a retry, with exponential backoff'
+complete -c hurl -l retry-max-time -d 'Maximum time in seconds allowed for retrying an entry'
+complete -c hurl -l retry-rate-limited -d 'Retry once an entry failing with a 429 or 503 response'
 complete -c hurl -l secret -d 'Define a variable which value is secret'
 complete -c hurl -l ssl-no-revoke -d '(Windows) Tell Hurl to disable certificate revocation checks'
 complete -c hurl -l test -d 'Activate test mode (use parallel execution)'
@@ -27,6 +27,7 @@ def main():
         + get_files("tests_failed_not_linted/*." + extension)
         + get_files("tests_error_parser/*." + extension)
         + get_files("tests_ssl/*." + extension)
+        + get_files("tests_unix_socket/*." + extension)
     )
     for f in sorted(script_files):
         test_script.test(f)
@@ -1,3 +0,0 @@
-#!/bin/bash
-set -Eeuo pipefail
-hurl tests_error_parser/invalid_option.hurl
@@ -0,0 +1,9 @@
+import time
+
+from app import app
+
+
+@app.route("/assert-duration")
+def assert_duration():
+    time.sleep(0.5)
+    return ""
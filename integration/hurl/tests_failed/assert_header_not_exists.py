@@ -0,0 +1,9 @@
+from app import app
+from flask import make_response
+
+
+@app.route("/error-assert-header-not-exists")
+def error_assert_header_not_exists():
+    resp = make_response()
+    resp.set_cookie("cookie1", "value1")
+    return resp
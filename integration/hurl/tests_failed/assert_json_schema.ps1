@@ -1,3 +1,3 @@
 Set-StrictMode -Version latest
 $ErrorActionPreference = 'Stop'
-hurl tests_error_parser/invalid_option.hurl
+hurl tests_failed/assert_json_schema.hurl
@@ -0,0 +1,13 @@
+from app import app
+from flask import Response
+
+
+@app.route("/error-assert-json-schema")
+def error_assert_json_schema():
+    return Response(
+        """{
+  "id": "123",
+  "tags": ["cute", 42]
+}""",
+        mimetype="application/json",
+    )
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+$env:HURL_TEST_USER = 'Bob'
+hurl --env-prefix HURL_OTHER_ tests_failed/env_variable_not_allowed.hurl
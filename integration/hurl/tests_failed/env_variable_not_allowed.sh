@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+export HURL_TEST_USER=Bob
+hurl --env-prefix HURL_OTHER_ tests_failed/env_variable_not_allowed.hurl
@@ -0,0 +1,5 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+$ErrorActionPreference = 'Continue'
+hurl --variables-file tests_failed/invalid_variables_file.properties tests_failed/invalid_variables_file.hurl
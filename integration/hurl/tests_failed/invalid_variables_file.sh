@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+set +e
+hurl --variables-file tests_failed/invalid_variables_file.properties tests_failed/invalid_variables_file.hurl
@@ -1,6 +1,7 @@
 from app import app
+from flask import Response
 
 
 @app.route("/error-invalid-xml")
 def error_invalid_xml():
-    return ""
+    return Response("not xml", mimetype="application/xml")
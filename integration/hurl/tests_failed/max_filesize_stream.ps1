@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --max-filesize 10000 tests_failed/max_filesize_stream.hurl
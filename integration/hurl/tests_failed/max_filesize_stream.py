@@ -0,0 +1,11 @@
+from app import app
+from flask import Response
+
+
+@app.route("/stream-100-kilobytes")
+def stream_100_kilobytes():
+    def generate():
+        for _ in range(100):
+            yield b"x" * 1024
+
+    return Response(generate(), mimetype="application/octet-stream")
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --max-filesize 10000 tests_failed/max_filesize_stream.hurl
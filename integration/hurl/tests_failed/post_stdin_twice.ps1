@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+cmd /c 'hurl tests_failed/post_stdin_twice.hurl < tests_ok/data.bin'
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_failed/post_stdin_twice.hurl < tests_ok/data.bin
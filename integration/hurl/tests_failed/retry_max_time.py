@@ -0,0 +1,7 @@
+from app import app
+from flask import jsonify
+
+
+@app.route("/retry/max-time/running")
+def retry_max_time_running():
+    return jsonify(state="RUNNING")
@@ -21,7 +21,7 @@ def runner_errors_could_not_uncompress():
 
 @app.route("/runner_errors/invalid-xml")
 def runner_errors_invalid_xml():
-    return Response("", mimetype="application/xml")
+    return Response("not xml", mimetype="application/xml")
 
 
 @app.route("/runner_errors/invalid-charset")
@@ -0,0 +1,5 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+$ErrorActionPreference = 'Continue'
+hurl --variables-file tests_failed/variables_environment_undefined.properties --environment dev tests_failed/variables_environment_undefined.hurl
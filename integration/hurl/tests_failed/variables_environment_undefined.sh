@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+set +e
+hurl --variables-file tests_failed/variables_environment_undefined.properties --environment dev tests_failed/variables_environment_undefined.hurl
@@ -0,0 +1,20 @@
+from datetime import datetime, timedelta, timezone
+
+from app import app
+from flask import Response
+
+
+@app.route("/assert-date")
+def assert_date():
+    now = datetime.now(timezone.utc)
+    headers = {
+        "Expires": (now + timedelta(days=1)).strftime("%a, %d %b %Y %H:%M:%S GMT"),
+        "Last-Modified": (now - timedelta(days=1)).strftime(
+            "%a, %d %b %Y %H:%M:%S GMT"
+        ),
+    }
+    body = '{"issued_at": "%s", "expires_at": "%s"}' % (
+        now.strftime("%Y-%m-%dT%H:%M:%SZ"),
+        (now + timedelta(hours=1)).strftime("%Y-%m-%dT%H:%M:%SZ"),
+    )
+    return Response(body, headers=headers, mimetype="application/json")
@@ -0,0 +1,15 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-json-schema")
+def assert_json_schema():
+    return Response(
+        """{
+  "id": 123,
+  "name": "Felix",
+  "tags": ["cute", "fluffy"],
+  "owner": null
+}""",
+        mimetype="application/json",
+    )
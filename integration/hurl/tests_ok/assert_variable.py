@@ -0,0 +1,18 @@
+from app import app
+from flask import Response, jsonify
+
+version = 1
+
+
+@app.route("/assert-variable/resource", methods=["GET"])
+def assert_variable_get():
+    response = jsonify(version=version)
+    response.headers["ETag"] = f'"v{version}"'
+    return response
+
+
+@app.route("/assert-variable/resource", methods=["PUT"])
+def assert_variable_put():
+    global version
+    version += 1
+    return Response(status=204)
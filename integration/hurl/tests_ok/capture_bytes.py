@@ -0,0 +1,30 @@
+from app import app
+from flask import make_response, request
+
+PNG_SIGNATURE = b"\x89PNG\r\n\x1a\n"
+
+
+@app.route("/capture-bytes/download")
+def capture_bytes_download():
+    resp = make_response(PNG_SIGNATURE)
+    resp.content_type = "application/octet-stream"
+    return resp
+
+
+@app.route("/capture-bytes/upload-body", methods=["POST"])
+def capture_bytes_upload_body():
+    assert request.data == PNG_SIGNATURE
+    return "Body received"
+
+
+@app.route("/capture-bytes/upload-multipart", methods=["POST"])
+def capture_bytes_upload_multipart():
+    image = request.files["image"]
+    assert image.filename == "image.png"
+    assert image.content_type == "image/png"
+    assert image.read() == PNG_SIGNATURE
+    raw = request.files["raw"]
+    assert raw.filename == "raw"
+    assert raw.content_type == "application/octet-stream"
+    assert raw.read() == PNG_SIGNATURE
+    return "Multipart received"
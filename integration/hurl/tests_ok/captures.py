@@ -69,3 +69,14 @@ def captures_json():
         '"a_date_like_string":"2012-04-23T18:25:43.511Z"'
         "}"
     )
+
+
+@app.route("/captures-json-body", methods=["POST"])
+def captures_json_body():
+    assert request.json == {
+        "an_integer": 1,
+        "a_float": 1.1,
+        "a_bool": True,
+        "a_string": "hello",
+    }
+    return request.data
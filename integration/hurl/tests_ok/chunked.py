@@ -0,0 +1,16 @@
+from app import app
+from flask import jsonify, request
+
+
+@app.route("/chunked", methods=["POST"])
+def chunked():
+    transfer_encoding = request.headers.get("Transfer-Encoding")
+    content_length = request.headers.get("Content-Length")
+    if transfer_encoding != "chunked" or content_length is not None:
+        return "", 411
+    body = request.get_data().decode()
+    return jsonify(
+        transfer_encoding=transfer_encoding,
+        content_length=content_length,
+        body=body,
+    )
@@ -0,0 +1,18 @@
+import gzip
+import json
+import zlib
+
+from app import app
+from flask import jsonify, request
+
+
+@app.route("/compress-body", methods=["POST"])
+def compress_body():
+    encoding = request.headers.get("Content-Encoding")
+    data = request.get_data()
+    if encoding == "gzip":
+        data = gzip.decompress(data)
+    elif encoding == "deflate":
+        data = zlib.decompress(data)
+    body = json.loads(data)
+    return jsonify(encoding=encoding, size=len(data), name=body["name"])
@@ -1,3 +1,4 @@
+import gzip
 from io import BytesIO
 
 from app import app
@@ -17,6 +18,16 @@ def compressed_gzip():
     return resp
 
 
+@app.route("/compressed/gzip_json")
+def compressed_gzip_json():
+    assert "gzip" in request.headers["Accept-Encoding"]
+    data = gzip.compress(b'{"name": "Bob", "age": 42}', mtime=0)
+    resp = make_response(data)
+    resp.headers["Content-Encoding"] = "gzip"
+    resp.headers["Content-Type"] = "application/json"
+    return resp
+
+
 @app.route("/compressed/zlib")
 def compressed_zlib():
     assert "deflate" in request.headers["Accept-Encoding"]
@@ -5,4 +5,5 @@ from flask import request
 @app.route("/cookie_file")
 def cookie_file():
     assert request.cookies["cookie1"] == "valueA"
+    assert request.cookies["cookie2"] == "valueB"
     return ""
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --verbose --variable build=42 --default-header 'X-Request-Source: ci' --default-header 'X-Build: {{build}}' tests_ok/default_header.hurl
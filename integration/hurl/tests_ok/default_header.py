@@ -0,0 +1,10 @@
+from app import app
+from flask import jsonify, request
+
+
+@app.route("/default-header")
+def default_header():
+    return jsonify(
+        source=",".join(request.headers.getlist("X-Request-Source")),
+        build=request.headers.get("X-Build"),
+    )
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --test --delay 1000ms tests_ok/delay_duration.hurl
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/dry_run.hurl --dry-run --variable name=Bob --verbose
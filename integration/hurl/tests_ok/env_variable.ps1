@@ -0,0 +1,5 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+$env:HURL_TEST_USER = 'Bob'
+Remove-Item -Path env:HURL_TEST_UNSET -ErrorAction SilentlyContinue
+hurl --env-prefix HURL_TEST_ tests_ok/env_variable.hurl
@@ -0,0 +1,9 @@
+from app import app
+from flask import request
+
+
+@app.route("/env-variable")
+def env_variable():
+    user = request.headers["User"]
+    role = request.headers["Role"]
+    return f"Hello {user}, you're a {role}"
@@ -0,0 +1,5 @@
+#!/bin/bash
+set -Eeuo pipefail
+unset HURL_TEST_UNSET
+export HURL_TEST_USER=Bob
+hurl --env-prefix HURL_TEST_ tests_ok/env_variable.hurl
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --variable credentials=bob@email.com:secret tests_ok/filter_base64_encode.hurl
@@ -0,0 +1,13 @@
+from app import app
+from flask import Response, request
+
+
+@app.route("/filter-base64-encode/bytes")
+def filter_base64_encode_bytes():
+    return Response(bytes.fromhex("3c3c3f3f3f3e3e"))
+
+
+@app.route("/filter-base64-encode/basic-authentication")
+def filter_base64_encode_basic_authentication():
+    assert request.headers["Authorization"] == "Basic Ym9iQGVtYWlsLmNvbTpzZWNyZXQ="
+    return ""
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --variable credentials=bob@email.com:secret tests_ok/filter_base64_encode.hurl
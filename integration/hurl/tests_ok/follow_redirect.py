@@ -40,6 +40,30 @@ def follow_redirect_308():
     return redirect("http://localhost:8000/followed-redirect-post", code=308)
 
 
+@app.route("/follow-redirect-307", methods=["POST"])
+def follow_redirect_307():
+    assert request.data == b"Hello with body\n"
+    return redirect("http://localhost:8000/followed-redirect-post-body", code=307)
+
+
+@app.route("/followed-redirect-post-body", methods=["POST"])
+def followed_redirect_post_body():
+    assert request.data == b"Hello with body\n"
+    return "Followed redirect POST with body!"
+
+
+@app.route("/follow-redirect-303", methods=["POST"])
+def follow_redirect_303():
+    assert request.data == b"Hello with body\n"
+    return redirect("http://localhost:8000/followed-redirect-get-no-body", code=303)
+
+
+@app.route("/followed-redirect-get-no-body")
+def followed_redirect_get_no_body():
+    assert request.data == b""
+    return "Followed redirect GET without body!"
+
+
 @app.route("/follow-redirect-basic-auth")
 def follow_redirect_basic_auth():
     assert "Authorization" in request.headers
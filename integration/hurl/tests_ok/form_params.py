@@ -11,3 +11,16 @@ def form_params():
     assert request.form["values[0]"] == "0"
     assert request.form["values[1]"] == "1"
     return ""
+
+
+@app.route("/form-params-special", methods=["GET"])
+def form_params_special_get():
+    return "a b&c=d café"
+
+
+@app.route("/form-params-special", methods=["POST"])
+def form_params_special_post():
+    assert request.get_data() == b"value=a%20b%26c%3Dd%20caf%C3%A9"
+    assert request.form["value"] == "a b&c=d café"
+    assert "c" not in request.form
+    return ""
@@ -22,6 +22,14 @@ def custom_headers():
     return ""
 
 
+@app.route("/custom-headers-order")
+def custom_headers_order():
+    names = [name for name, _ in request.headers if name in ["Zeta", "Alpha", "Mu"]]
+    assert names == ["Zeta", "Alpha", "Mu"]
+    assert request.headers["Alpha"] == "2,4"
+    return ""
+
+
 @app.route("/custom-headers-utf8")
 def custom_headers_utf8():
     assert len(request.headers["Beverage"]) == 5
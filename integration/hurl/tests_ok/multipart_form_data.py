@@ -23,3 +23,23 @@ def multipart_form_data():
     assert upload3.read() == b"Hello World!"
 
     return ""
+
+
+@app.route("/multipart-form-data-filename", methods=["POST"])
+def multipart_form_data_filename():
+    data = request.get_data()
+    assert (
+        b'Content-Disposition: form-data; name="upload1"; filename="avatar.png"\r\n'
+        b"Content-Type: image/png\r\n"
+    ) in data
+    assert (
+        b'Content-Disposition: form-data; name="upload2"; filename="hello.html"\r\n'
+        b"Content-Type: text/html\r\n"
+    ) in data
+
+    upload1 = request.files["upload1"]
+    assert upload1.filename == "avatar.png"
+    assert upload1.content_type == "image/png"
+    assert upload1.read() == b"Hello World!"
+
+    return ""
@@ -0,0 +1,13 @@
+from app import app
+from flask import Response, request
+
+
+@app.route("/ndjson", methods=["POST"])
+def ndjson():
+    s = request.data.decode("utf-8")
+    assert s == '{"id":1,"type":"created"}\n{"id":2,"type":"deleted"}\n'
+    assert request.headers["Content-Type"] == "application/x-ndjson"
+    return Response(
+        '{ "id": 1, "type": "created" }\n\n{"id":2,  "type":"deleted"}',
+        mimetype="application/x-ndjson",
+    )
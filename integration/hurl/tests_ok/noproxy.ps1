@@ -0,0 +1,12 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+# Exact host and domain suffix (with or without leading dot), as curl
+hurl --proxy localhost:1 --noproxy localhost,example.com --resolve api.example.com:8000:127.0.0.1 tests_ok/noproxy.hurl
+hurl --proxy localhost:1 --noproxy localhost,.example.com --resolve api.example.com:8000:127.0.0.1 tests_ok/noproxy.hurl
+# Wildcard disables proxy for every host
+hurl --proxy localhost:1 --noproxy '*' --resolve api.example.com:8000:127.0.0.1 tests_ok/noproxy.hurl
+# Environment variable
+$env:NO_PROXY = 'localhost,.example.com'
+hurl --proxy localhost:1 --resolve api.example.com:8000:127.0.0.1 tests_ok/noproxy.hurl
+Remove-Item Env:\NO_PROXY
@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/noproxy")
+def noproxy():
+    return "Hello World!"
@@ -0,0 +1,10 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+# Exact host and domain suffix (with or without leading dot), as curl
+hurl --proxy localhost:1 --noproxy localhost,example.com --resolve api.example.com:8000:127.0.0.1 tests_ok/noproxy.hurl
+hurl --proxy localhost:1 --noproxy localhost,.example.com --resolve api.example.com:8000:127.0.0.1 tests_ok/noproxy.hurl
+# Wildcard disables proxy for every host
+hurl --proxy localhost:1 --noproxy '*' --resolve api.example.com:8000:127.0.0.1 tests_ok/noproxy.hurl
+# Environment variable
+NO_PROXY=localhost,.example.com hurl --proxy localhost:1 --resolve api.example.com:8000:127.0.0.1 tests_ok/noproxy.hurl
@@ -1,5 +1,5 @@
 from app import app
-from flask import request
+from flask import make_response, request
 
 
 @app.route("/output/endpoint1", methods=["POST"])
@@ -18,3 +18,10 @@ def output_endpoint2():
 @app.route("/output/endpoint3")
 def output_endpoint3():
     return "Response endpoint3\n"
+
+
+@app.route("/output/binary")
+def output_binary():
+    resp = make_response(b"\x89PNG\r\n\x1a\n\x00\xff")
+    resp.content_type = "application/octet-stream"
+    return resp
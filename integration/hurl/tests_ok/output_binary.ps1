@@ -0,0 +1,11 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+
+if (Test-Path build/output_binary.bin) {
+    Remove-Item build/output_binary.bin
+}
+
+hurl --output build/output_binary.bin tests_ok/output_binary.hurl
+$bytes = [System.IO.File]::ReadAllBytes("build/output_binary.bin")
+$stdout = [Console]::OpenStandardOutput()
+$stdout.Write($bytes, 0, $bytes.Length)
@@ -0,0 +1,7 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+rm -f build/output_binary.bin
+
+hurl --output build/output_binary.bin tests_ok/output_binary.hurl
+cat build/output_binary.bin
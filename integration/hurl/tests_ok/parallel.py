@@ -1,8 +1,21 @@
 from app import app
-from flask import request
+from flask import make_response, request
 
 
 @app.route("/parallel/hello")
 def parallel_hello():
     name = request.args.get("name")
     return f"Hello {name} from a parallel world!\n"
+
+
+@app.route("/parallel/isolation/set")
+def parallel_isolation_set():
+    value = request.args.get("value")
+    response = make_response()
+    response.set_cookie("session", value)
+    return response
+
+
+@app.route("/parallel/isolation/get")
+def parallel_isolation_get():
+    return request.cookies["session"]
@@ -0,0 +1,6 @@
+#!/bin/bash
+set -Eeuo pipefail
+
+hurl --parallel --jobs 2 --no-output \
+  tests_ok/parallel_isolation_a.hurl \
+  tests_ok/parallel_isolation_b.hurl
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+[System.IO.File]::WriteAllBytes("$PWD/build/large_file.bin", (New-Object byte[] 10485760))
+hurl --file-root build/ tests_ok/post_large_file.hurl
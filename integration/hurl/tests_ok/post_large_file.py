@@ -0,0 +1,8 @@
+from app import app
+from flask import jsonify, request
+
+
+@app.route("/post-large-file", methods=["POST"])
+def post_large_file():
+    assert request.headers["Content-Length"] == "10485760"
+    return jsonify(length=len(request.data))
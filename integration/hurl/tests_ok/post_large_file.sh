@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+head -c 10485760 /dev/zero > build/large_file.bin
+hurl --file-root build/ tests_ok/post_large_file.hurl
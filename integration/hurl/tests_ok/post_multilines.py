@@ -1,3 +1,5 @@
+import json
+
 from app import app
 from flask import request
 
@@ -22,6 +24,18 @@ def post_multilines_json():
     return ""
 
 
+@app.route("/post-multilines-json-lenient", methods=["POST"])
+def post_multilines_json_lenient():
+    assert request.headers["Content-Type"] == "application/json"
+    s = request.data.decode("utf-8")
+    assert json.loads(s) == {
+        "id": 123,
+        "url": "https://example.org//home",
+        "tags": ["a", "b"],
+    }
+    return ""
+
+
 @app.route("/get-bob-age", methods=["GET"])
 def get_bob_age():
     return "10"
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+cmd /c 'hurl tests_ok/post_stdin.hurl < tests_ok/data.bin'
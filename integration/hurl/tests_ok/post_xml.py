@@ -26,3 +26,21 @@ def post_xml_large():
     s = request.data.decode("utf-8")
     assert len(s) == 22156
     return ""
+
+
+@app.route("/post-xml-soap", methods=["POST"])
+def post_xml_soap():
+    s = request.data.decode("utf-8")
+    assert (
+        s
+        == """<?xml version="1.0" encoding="utf-8"?>
+<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+  <soap:Body>
+    <GetOrder xmlns="http://example.org/orders" id="ORD-1234">
+      <OrderId>ORD-1234</OrderId>
+      <Note>Fish &amp; Chips</Note>
+    </GetOrder>
+  </soap:Body>
+</soap:Envelope>"""
+    )
+    return ""
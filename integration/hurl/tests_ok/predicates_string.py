@@ -1,4 +1,5 @@
 from app import app
+from flask import Response
 
 
 @app.route("/predicates-string")
@@ -14,3 +15,11 @@ def predicates_string_empty():
 @app.route("/predicates-string-unicode")
 def predicates_string_unicode():
     return "\u2708"
+
+
+@app.route("/predicates-string-utf8")
+def predicates_string_utf8():
+    return Response(
+        """{"title": "ÉTÉ à Paris 🦀", "tags": ["日本語", "café"]}""",
+        mimetype="application/json",
+    )
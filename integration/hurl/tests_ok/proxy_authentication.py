@@ -0,0 +1,8 @@
+from app import app
+from flask import request
+
+
+@app.route("/proxy-authentication")
+def proxy_authentication():
+    assert request.headers["Proxy-Authorization"] == "Basic Ym9iOnNlY3JldA=="
+    return ""
@@ -19,3 +19,11 @@ def querystring_params_encoded():
     assert request.args.get("value2") == "/"
     assert request.args.get("value3") == "/"
     return ""
+
+
+@app.route("/querystring-params-reserved")
+def querystring_params_reserved():
+    assert request.args.get("value1") == "a b&c=d+e"
+    assert request.args.get("value2") == "a b&c=d+e"
+    assert len(request.args) == 2
+    return ""
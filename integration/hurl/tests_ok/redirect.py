@@ -1,5 +1,5 @@
 from app import app
-from flask import Response, redirect
+from flask import Response, redirect, request
 
 
 @app.route("/redirect-absolute")
@@ -18,3 +18,21 @@ def redirect_relative():
 @app.route("/redirected")
 def redirected():
     return "Redirected"
+
+
+@app.route("/redirect-chain")
+def redirect_chain():
+    return redirect("http://localhost:8000/redirect-chain-step", code=301)
+
+
+@app.route("/redirect-chain-step")
+def redirect_chain_step():
+    response = redirect("http://localhost:8000/redirected-with-cookie")
+    response.set_cookie("step", "1")
+    return response
+
+
+@app.route("/redirected-with-cookie")
+def redirected_with_cookie():
+    assert request.cookies["step"] == "1"
+    return "Redirected with cookie"
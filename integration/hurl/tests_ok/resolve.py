@@ -1,6 +1,9 @@
 from app import app
+from flask import request
 
 
 @app.route("/resolve")
 def resolve():
+    # The Host header is not rewritten with the resolved address.
+    assert request.headers["Host"] in ["foo.com:8000", "bar.com:8000", "baz.com:8000"]
     return "Hello World!"
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --verbose --retry-interval 10ms --retry-max-interval 25ms tests_ok/retry_backoff.hurl
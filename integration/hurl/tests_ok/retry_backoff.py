@@ -0,0 +1,31 @@
+from app import app
+from flask import Response
+
+retry_after_count = 0
+backoff_count = 0
+
+
+@app.route("/retry/backoff/reset", methods=["POST"])
+def retry_backoff_reset():
+    global retry_after_count, backoff_count
+    retry_after_count = 0
+    backoff_count = 0
+    return ""
+
+
+@app.route("/retry/retry-after")
+def retry_retry_after():
+    global retry_after_count
+    retry_after_count += 1
+    if retry_after_count < 2:
+        return Response("", status=503, headers={"Retry-After": "0"})
+    return "OK"
+
+
+@app.route("/retry/backoff")
+def retry_backoff():
+    global backoff_count
+    backoff_count += 1
+    if backoff_count < 4:
+        return "", 500
+    return "OK"
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --verbose --retry-interval 10ms --retry-max-interval 25ms tests_ok/retry_backoff.hurl
@@ -0,0 +1,23 @@
+from app import app
+from flask import make_response, request
+
+count = 0
+
+
+@app.route("/retry/capture/init")
+def retry_capture_init():
+    global count
+    count = 0
+    return "0"
+
+
+# Returns the `value` query parameter incremented by one, and
+# set header `X-Ready` to true after the second call.
+@app.route("/retry/capture")
+def retry_capture():
+    global count
+    count += 1
+    value = int(request.args.get("value")) + 1
+    resp = make_response(str(value))
+    resp.headers["X-Ready"] = "true" if count >= 2 else "false"
+    return resp
@@ -0,0 +1,20 @@
+from app import app
+from flask import jsonify
+
+max_time_count = 0
+
+
+@app.route("/retry/max-time/reset", methods=["POST"])
+def retry_max_time_reset():
+    global max_time_count
+    max_time_count = 0
+    return ""
+
+
+@app.route("/retry/max-time")
+def retry_max_time():
+    global max_time_count
+    max_time_count += 1
+    if max_time_count < 3:
+        return jsonify(state="RUNNING")
+    return jsonify(state="COMPLETED")
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --retry-rate-limited --retry-interval 10ms --verbose tests_ok/retry_rate_limited.hurl
@@ -0,0 +1,23 @@
+from app import app
+from flask import Response
+
+counts = {}
+
+
+@app.route("/retry/rate-limited/reset", methods=["POST"])
+def retry_rate_limited_reset():
+    counts.clear()
+    return ""
+
+
+@app.route("/retry/rate-limited/<kind>")
+def retry_rate_limited(kind):
+    counts[kind] = counts.get(kind, 0) + 1
+    if counts[kind] > 1:
+        return "OK"
+    if kind == "seconds":
+        return Response("", status=429, headers={"Retry-After": "0"})
+    if kind == "date":
+        headers = {"Retry-After": "Wed, 21 Oct 2015 07:28:00 GMT"}
+        return Response("", status=503, headers=headers)
+    return Response("", status=503)
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --retry-rate-limited --retry-interval 10ms --verbose tests_ok/retry_rate_limited.hurl
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --verbose --secret password=s3cr3t tests_ok/secret_basic_auth.hurl
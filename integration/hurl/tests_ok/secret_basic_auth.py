@@ -0,0 +1,8 @@
+from app import app
+from flask import request
+
+
+@app.route("/secret-basic-auth")
+def secret_basic_auth():
+    assert request.headers["Authorization"] == "Basic Ym9iOnMzY3IzdA=="
+    return "You are authenticated"
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --verbose --secret password=s3cr3t tests_ok/secret_basic_auth.hurl
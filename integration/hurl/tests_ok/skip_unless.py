@@ -0,0 +1,29 @@
+from app import app
+from flask import jsonify
+
+counter = 0
+
+
+@app.route("/skip-unless/init")
+def skip_unless_init():
+    global counter
+    counter = 0
+    return str(counter)
+
+
+@app.route("/skip-unless/increment")
+def skip_unless_increment():
+    global counter
+    counter = counter + 1
+    return str(counter)
+
+
+@app.route("/skip-unless/get")
+def skip_unless_get():
+    global counter
+    return str(counter)
+
+
+@app.route("/skip-unless/features")
+def skip_unless_features():
+    return jsonify(beta=True, legacy=False)
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --variable feature_enabled=false tests_ok/skip_unless.hurl
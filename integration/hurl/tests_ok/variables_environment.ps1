@@ -0,0 +1,5 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --variables-file tests_ok/variables_environment.properties tests_ok/variables_environment.hurl
+hurl --variables-file tests_ok/variables_environment.properties --environment staging tests_ok/variables_environment.hurl
+hurl --variables-file tests_ok/variables_environment.properties --environment prod tests_ok/variables_environment.hurl
@@ -0,0 +1,9 @@
+from app import app
+from flask import request
+
+
+@app.route("/variables-environment")
+def variables_environment():
+    name = request.args.get("name")
+    retries = request.args.get("retries")
+    return f"{name} ({retries})\n"
@@ -0,0 +1,5 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+$env:HURL_x = '0'
+$env:HURL_z = 'env'
+hurl tests_ok/variables_precedence.hurl --variables-file tests_ok/variables_precedence.properties --variable x=1
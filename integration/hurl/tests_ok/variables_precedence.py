@@ -0,0 +1,12 @@
+from app import app
+from flask import jsonify, request
+
+
+@app.route("/variables-precedence")
+def variables_precedence():
+    return jsonify(
+        x=request.args.get("x"),
+        y=request.args.get("y"),
+        z=request.args.get("z"),
+        next=3,
+    )
@@ -0,0 +1,5 @@
+#!/bin/bash
+set -Eeuo pipefail
+export HURL_x=0
+export HURL_z=env
+hurl tests_ok/variables_precedence.hurl --variables-file tests_ok/variables_precedence.properties --variable x=1
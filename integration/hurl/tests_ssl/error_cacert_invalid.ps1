@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ssl/error_cacert_invalid.hurl --cacert tests_ssl/certs/ca/invalid.pem
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ssl/error_cacert_invalid.hurl --cacert tests_ssl/certs/ca/invalid.pem
@@ -137,6 +137,16 @@ pub fn curl() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn default_header() -> clap::Arg {
+    clap::Arg::new("default_header")
+        .long("default-header")
+        .value_name("HEADER")
+        .help("Add a header to each request that doesn't already have it")
+        .help_heading("HTTP options")
+        .num_args(1)
+        .action(clap::ArgAction::Append)
+}
+
 pub fn delay() -> clap::Arg {
     clap::Arg::new("delay")
         .long("delay")
@@ -147,6 +157,32 @@ pub fn delay() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn dry_run() -> clap::Arg {
+    clap::Arg::new("dry_run")
+        .long("dry-run")
+        .help("Evaluate requests without sending them")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
+pub fn env_prefix() -> clap::Arg {
+    clap::Arg::new("env_prefix")
+        .long("env-prefix")
+        .value_name("PREFIX")
+        .help("Allow templates to read environment variables starting with PREFIX")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn environment() -> clap::Arg {
+    clap::Arg::new("environment")
+        .long("environment")
+        .value_name("NAME")
+        .help("Select the environment section of the variables files")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn error_format() -> clap::Arg {
     clap::Arg::new("error_format")
         .long("error-format")
@@ -241,6 +277,14 @@ pub fn http2() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn http2_prior_knowledge() -> clap::Arg {
+    clap::Arg::new("http2_prior_knowledge")
+        .long("http2-prior-knowledge")
+        .help("Tell Hurl to use HTTP/2 without HTTP/1.1 Upgrade")
+        .help_heading("HTTP options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn http3() -> clap::Arg {
     clap::Arg::new("http3")
         .long("http3")
@@ -535,6 +579,32 @@ pub fn retry_interval() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn retry_max_interval() -> clap::Arg {
+    clap::Arg::new("retry_max_interval")
+        .long("retry-max-interval")
+        .value_name("MILLISECONDS")
+        .help("Maximum interval in milliseconds before a retry, with exponential backoff")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn retry_max_time() -> clap::Arg {
+    clap::Arg::new("retry_max_time")
+        .long("retry-max-time")
+        .value_name("SECONDS")
+        .help("Maximum time in seconds allowed for retrying an entry")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
+pub fn retry_rate_limited() -> clap::Arg {
+    clap::Arg::new("retry_rate_limited")
+        .long("retry-rate-limited")
+        .help("Retry once an entry failing with a 429 or 503 response")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn secret() -> clap::Arg {
     clap::Arg::new("secret")
         .long("secret")
@@ -16,15 +16,16 @@
  *
  */
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader, IsTerminal};
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::{env, fs, io};
 
 use clap::ArgMatches;
 use hurl::runner::Value;
+use hurl_core::ast::KeyValue;
 use hurl_core::input::Input;
+use hurl_core::parser;
 use hurl_core::typing::{BytesPerSec, Count, DurationUnit};
 
 use crate::cli::options::variables;
@@ -131,6 +132,10 @@ pub fn delay(arg_matches: &ArgMatches) -> Result<Duration, CliOptionsError> {
     get_duration(&s, DurationUnit::MilliSecond)
 }
 
+pub fn dry_run(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "dry_run")
+}
+
 pub fn error_format(arg_matches: &ArgMatches) -> ErrorFormat {
     let error_format = get::<String>(arg_matches, "error_format");
     match error_format.as_deref() {
@@ -151,6 +156,18 @@ pub fn follow_location(arg_matches: &ArgMatches) -> (bool, bool) {
     (follow_location, follow_location_trusted)
 }
 
+pub fn default_headers(arg_matches: &ArgMatches) -> Result<Vec<KeyValue>, CliOptionsError> {
+    let headers = get_strings(arg_matches, "default_header").unwrap_or_default();
+    headers
+        .iter()
+        .map(|h| {
+            parser::parse_header(h).map_err(|_| {
+                CliOptionsError::Error(format!("Invalid default header {h}, expecting NAME: VALUE"))
+            })
+        })
+        .collect()
+}
+
 pub fn from_entry(arg_matches: &ArgMatches) -> Option<usize> {
     get::<u32>(arg_matches, "from_entry").map(|x| x as usize)
 }
@@ -173,10 +190,10 @@ pub fn html_dir(arg_matches: &ArgMatches) -> Result<Option<PathBuf>, CliOptionsE
         } else if path.is_dir() {
             Ok(Some(path.to_path_buf()))
         } else {
-            return Err(CliOptionsError::Error(format!(
+            Err(CliOptionsError::Error(format!(
                 "{} is not a valid directory",
                 path.display()
-            )));
+            )))
         }
     } else {
         Ok(None)
@@ -186,6 +203,8 @@ pub fn html_dir(arg_matches: &ArgMatches) -> Result<Option<PathBuf>, CliOptionsE
 pub fn http_version(arg_matches: &ArgMatches) -> Option<HttpVersion> {
     if has_flag(arg_matches, "http3") {
         Some(HttpVersion::V3)
+    } else if has_flag(arg_matches, "http2_prior_knowledge") {
+        Some(HttpVersion::V2PriorKnowledge)
     } else if has_flag(arg_matches, "http2") {
         Some(HttpVersion::V2)
     } else if has_flag(arg_matches, "http11") {
@@ -317,10 +336,10 @@ pub fn json_report_dir(arg_matches: &ArgMatches) -> Result<Option<PathBuf>, CliO
         } else if path.is_dir() {
             Ok(Some(path.to_path_buf()))
         } else {
-            return Err(CliOptionsError::Error(format!(
+            Err(CliOptionsError::Error(format!(
                 "{} is not a valid directory",
                 path.display()
-            )));
+            )))
         }
     } else {
         Ok(None)
@@ -408,6 +427,24 @@ pub fn retry_interval(arg_matches: &ArgMatches) -> Result<Duration, CliOptionsEr
     get_duration(&s, DurationUnit::MilliSecond)
 }
 
+pub fn retry_max_interval(arg_matches: &ArgMatches) -> Result<Option<Duration>, CliOptionsError> {
+    match get::<String>(arg_matches, "retry_max_interval") {
+        Some(s) => Ok(Some(get_duration(&s, DurationUnit::MilliSecond)?)),
+        None => Ok(None),
+    }
+}
+
+pub fn retry_max_time(arg_matches: &ArgMatches) -> Result<Option<Duration>, CliOptionsError> {
+    match get::<String>(arg_matches, "retry_max_time") {
+        Some(s) => Ok(Some(get_duration(&s, DurationUnit::Second)?)),
+        None => Ok(None),
+    }
+}
+
+pub fn retry_rate_limited(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "retry_rate_limited")
+}
+
 pub fn secret(matches: &ArgMatches) -> Result<HashMap<String, String>, CliOptionsError> {
     let mut secrets = HashMap::new();
     if let Some(secret) = get_strings(matches, "secret") {
@@ -419,6 +456,15 @@ pub fn secret(matches: &ArgMatches) -> Result<HashMap<String, String>, CliOption
             }
         }
     }
+    // Environment variables are only readable in templates (with `{{env.NAME}}`) when allowed by
+    // `--env-prefix`, and their values are redacted like secrets.
+    if let Some(prefix) = get::<String>(matches, "env_prefix") {
+        for (name, value) in env::vars() {
+            if name.starts_with(&prefix) {
+                secrets.insert(format!("env.{name}"), value);
+            }
+        }
+    }
     Ok(secrets)
 }
 
@@ -467,6 +513,8 @@ pub fn variables(matches: &ArgMatches) -> Result<HashMap<String, Value>, CliOpti
         }
     }
 
+    let environment = get::<String>(matches, "environment");
+    let mut files = vec![];
     if let Some(filenames) = get_strings(matches, "variables_file") {
         for f in filenames.iter() {
             let path = Path::new(&f);
@@ -477,28 +525,20 @@ pub fn variables(matches: &ArgMatches) -> Result<HashMap<String, Value>, CliOpti
                 )));
             }
 
-            let file = File::open(path).unwrap();
-            let reader = BufReader::new(file);
-            for (index, line) in reader.lines().enumerate() {
-                let line = match line {
-                    Ok(s) => s,
-                    Err(_) => {
-                        return Err(CliOptionsError::Error(format!(
-                            "Can not parse line {} of {}",
-                            index + 1,
-                            path.display()
-                        )))
-                    }
-                };
-                let line = line.trim();
-                if line.starts_with('#') || line.is_empty() {
-                    continue;
-                }
-                let (name, value) = variables::parse(line)?;
-                variables.insert(name.to_string(), value);
-            }
+            let Ok(content) = fs::read_to_string(path) else {
+                return Err(CliOptionsError::Error(format!(
+                    "Can not read variables file {}",
+                    path.display()
+                )));
+            };
+            let filename = path.display().to_string();
+            files.push(variables::parse_file_content(&content, &filename)?);
         }
     }
+    // Variables of the selected environment override the common ones of all files.
+    for (name, value) in variables::resolve_files(&files, environment.as_deref())? {
+        variables.insert(name, value);
+    }
 
     if let Some(input) = get_strings(matches, "variable") {
         for s in input {
@@ -33,7 +33,7 @@ use hurl::http::RequestedHttpVersion;
 use hurl::runner::Output;
 use hurl::util::logger::{LoggerOptions, LoggerOptionsBuilder, Verbosity};
 use hurl::util::path::ContextDir;
-use hurl_core::ast::Entry;
+use hurl_core::ast::{Entry, KeyValue};
 use hurl_core::input::{Input, InputKind};
 use hurl_core::typing::{BytesPerSec, Count};
 
@@ -55,7 +55,9 @@ pub struct CliOptions {
     pub cookie_input_file: Option<String>,
     pub cookie_output_file: Option<PathBuf>,
     pub curl_file: Option<PathBuf>,
+    pub default_headers: Vec<KeyValue>,
     pub delay: Duration,
+    pub dry_run: bool,
     pub error_format: ErrorFormat,
     pub file_root: Option<String>,
     pub follow_location: bool,
@@ -90,6 +92,9 @@ pub struct CliOptions {
     pub resolves: Vec<String>,
     pub retry: Option<Count>,
     pub retry_interval: Duration,
+    pub retry_max_interval: Option<Duration>,
+    pub retry_max_time: Option<Duration>,
+    pub retry_rate_limited: bool,
     pub secrets: HashMap<String, String>,
     pub ssl_no_revoke: bool,
     pub tap_file: Option<PathBuf>,
@@ -126,6 +131,7 @@ pub enum HttpVersion {
     V10,
     V11,
     V2,
+    V2PriorKnowledge,
     V3,
 }
 
@@ -135,6 +141,7 @@ impl From<HttpVersion> for RequestedHttpVersion {
             HttpVersion::V10 => RequestedHttpVersion::Http10,
             HttpVersion::V11 => RequestedHttpVersion::Http11,
             HttpVersion::V2 => RequestedHttpVersion::Http2,
+            HttpVersion::V2PriorKnowledge => RequestedHttpVersion::Http2PriorKnowledge,
             HttpVersion::V3 => RequestedHttpVersion::Http3,
         }
     }
@@ -179,10 +186,12 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
         .arg(commands::compressed())
         .arg(commands::connect_timeout())
         .arg(commands::connect_to())
+        .arg(commands::default_header())
         .arg(commands::header())
         .arg(commands::http10())
         .arg(commands::http11())
         .arg(commands::http2())
+        .arg(commands::http2_prior_knowledge())
         .arg(commands::http3())
         .arg(commands::input_files())
         .arg(commands::insecure())
@@ -217,6 +226,9 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
         // Run options
         .arg(commands::continue_on_error())
         .arg(commands::delay())
+        .arg(commands::dry_run())
+        .arg(commands::env_prefix())
+        .arg(commands::environment())
         .arg(commands::from_entry())
         .arg(commands::ignore_asserts())
         .arg(commands::interactive())
@@ -225,6 +237,9 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
         .arg(commands::repeat())
         .arg(commands::retry())
         .arg(commands::retry_interval())
+        .arg(commands::retry_max_interval())
+        .arg(commands::retry_max_time())
+        .arg(commands::retry_rate_limited())
         .arg(commands::secret())
         .arg(commands::test())
         .arg(commands::to_entry())
@@ -281,7 +296,9 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
     let cookie_input_file = matches::cookie_input_file(arg_matches);
     let cookie_output_file = matches::cookie_output_file(arg_matches);
     let curl_file = matches::curl_file(arg_matches);
+    let default_headers = matches::default_headers(arg_matches)?;
     let delay = matches::delay(arg_matches)?;
+    let dry_run = matches::dry_run(arg_matches);
     let error_format = matches::error_format(arg_matches);
     let file_root = matches::file_root(arg_matches);
     let (follow_location, follow_location_trusted) = matches::follow_location(arg_matches);
@@ -315,6 +332,9 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
     let resolves = matches::resolves(arg_matches);
     let retry = matches::retry(arg_matches);
     let retry_interval = matches::retry_interval(arg_matches)?;
+    let retry_max_interval = matches::retry_max_interval(arg_matches)?;
+    let retry_max_time = matches::retry_max_time(arg_matches)?;
+    let retry_rate_limited = matches::retry_rate_limited(arg_matches);
     let secrets = matches::secret(arg_matches)?;
     let ssl_no_revoke = matches::ssl_no_revoke(arg_matches);
     let tap_file = matches::tap_file(arg_matches);
@@ -340,7 +360,9 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
         cookie_input_file,
         cookie_output_file,
         curl_file,
+        default_headers,
         delay,
+        dry_run,
         error_format,
         file_root,
         follow_location,
@@ -374,6 +396,9 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
         resolves,
         retry,
         retry_interval,
+        retry_max_interval,
+        retry_max_time,
+        retry_rate_limited,
         secrets,
         ssl_no_revoke,
         tap_file,
@@ -419,7 +444,9 @@ impl CliOptions {
         let context_dir = ContextDir::new(current_dir, file_root);
         let continue_on_error = self.continue_on_error;
         let cookie_input_file = self.cookie_input_file.clone();
+        let default_headers = &self.default_headers;
         let delay = self.delay;
+        let dry_run = self.dry_run;
         let follow_location = self.follow_location;
         let follow_location_trusted = self.follow_location_trusted;
         let from_entry = self.from_entry;
@@ -460,6 +487,9 @@ impl CliOptions {
         let resolves = self.resolves.clone();
         let retry = self.retry;
         let retry_interval = self.retry_interval;
+        let retry_max_interval = self.retry_max_interval;
+        let retry_max_time = self.retry_max_time;
+        let retry_rate_limited = self.retry_rate_limited;
         let ssl_no_revoke = self.ssl_no_revoke;
         let timeout = self.timeout;
         let to_entry = self.to_entry;
@@ -473,12 +503,14 @@ impl CliOptions {
             .client_cert_file(client_cert_file)
             .client_key_file(client_key_file)
             .delay(delay)
+            .dry_run(dry_run)
             .compressed(compressed)
             .connect_timeout(connect_timeout)
             .connects_to(&connects_to)
             .continue_on_error(continue_on_error)
             .context_dir(&context_dir)
             .cookie_input_file(cookie_input_file)
+            .default_headers(default_headers)
             .follow_location(follow_location)
             .follow_location_trusted(follow_location_trusted)
             .from_entry(from_entry)
@@ -503,6 +535,9 @@ impl CliOptions {
             .resolves(&resolves)
             .retry(retry)
             .retry_interval(retry_interval)
+            .retry_max_interval(retry_max_interval)
+            .retry_max_time(retry_max_time)
+            .retry_rate_limited(retry_rate_limited)
             .ssl_no_revoke(ssl_no_revoke)
             .timeout(timeout)
             .to_entry(to_entry)
@@ -38,6 +38,109 @@ pub fn parse(s: &str) -> Result<(String, Value), CliOptionsError> {
     }
 }
 
+/// Variables of a variables file, grouped by environment.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VariablesFile {
+    /// Variables defined outside any environment section.
+    pub variables: Vec<(String, Value)>,
+    /// Variables of each `[name]` environment section, in the file order.
+    pub environments: Vec<(String, Vec<(String, Value)>)>,
+}
+
+impl VariablesFile {
+    /// Returns the variables of the environment `name`, `None` if this environment is not defined.
+    pub fn environment(&self, name: &str) -> Option<&[(String, Value)]> {
+        self.environments
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, variables)| variables.as_slice())
+    }
+}
+
+/// Returns the variables defined by variables `files`, in the order they must be applied.
+///
+/// The common variables of all files come first, then the variables of the selected `environment`
+/// section of each file: an environment value always overrides a common value, whatever the file
+/// it comes from. Returns an error if `environment` is not defined in any file.
+pub fn resolve_files(
+    files: &[VariablesFile],
+    environment: Option<&str>,
+) -> Result<Vec<(String, Value)>, CliOptionsError> {
+    let mut variables = files
+        .iter()
+        .flat_map(|file| file.variables.iter().cloned())
+        .collect::<Vec<_>>();
+    let Some(environment) = environment else {
+        return Ok(variables);
+    };
+    let mut environment_found = false;
+    for env_variables in files
+        .iter()
+        .filter_map(|file| file.environment(environment))
+    {
+        environment_found = true;
+        variables.extend(env_variables.iter().cloned());
+    }
+    if !environment_found {
+        return Err(CliOptionsError::Error(format!(
+            "Environment {environment} is not defined in variables files"
+        )));
+    }
+    Ok(variables)
+}
+
+/// Parses the `content` of a variables file `filename`, with a `name=value` variable per line.
+///
+/// Variables can be grouped by environment: a `[name]` line starts the section of the environment
+/// `name`, until the next section. A section can only be defined once per file. Blank lines and
+/// lines starting with `#` are ignored. Errors report the line number of the malformed variable or
+/// section.
+pub fn parse_file_content(content: &str, filename: &str) -> Result<VariablesFile, CliOptionsError> {
+    let mut file = VariablesFile::default();
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+        if let Some(section) = line.strip_prefix('[') {
+            let name = section.strip_suffix(']').unwrap_or_default().trim();
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+            {
+                return Err(CliOptionsError::Error(format!(
+                    "Invalid environment section {line} (line {} of {filename})",
+                    index + 1
+                )));
+            }
+            if file.environment(name).is_some() {
+                return Err(CliOptionsError::Error(format!(
+                    "Duplicate environment section {line} (line {} of {filename})",
+                    index + 1
+                )));
+            }
+            file.environments.push((name.to_string(), vec![]));
+            continue;
+        }
+        let variable = match parse(line) {
+            Ok(variable) => variable,
+            Err(CliOptionsError::Error(message)) => {
+                return Err(CliOptionsError::Error(format!(
+                    "{message} (line {} of {filename})",
+                    index + 1
+                )));
+            }
+            Err(e) => return Err(e),
+        };
+        match file.environments.last_mut() {
+            Some((_, variables)) => variables.push(variable),
+            None => file.variables.push(variable),
+        }
+    }
+    Ok(file)
+}
+
 pub fn parse_value(s: &str) -> Result<Value, CliOptionsError> {
     if s == "true" {
         Ok(Value::Bool(true))
@@ -102,6 +205,136 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_file_content() {
+        let content = r#"# Some comments
+name=Jennifer
+
+  age=30
+female=true
+id="123"
+"#;
+        assert_eq!(
+            parse_file_content(content, "vars.env").unwrap(),
+            VariablesFile {
+                variables: vec![
+                    ("name".to_string(), Value::String("Jennifer".to_string())),
+                    ("age".to_string(), Value::Number(Number::Integer(30))),
+                    ("female".to_string(), Value::Bool(true)),
+                    ("id".to_string(), Value::String("123".to_string())),
+                ],
+                environments: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_file_content_environments() {
+        let content = r#"timeout=10
+base_url=http://localhost:8000
+
+[staging]
+base_url=https://staging.example.org
+
+# Production
+[prod]
+base_url=https://example.org
+timeout=30
+"#;
+        let file = parse_file_content(content, "vars.env").unwrap();
+        assert_eq!(
+            file.variables,
+            vec![
+                ("timeout".to_string(), Value::Number(Number::Integer(10))),
+                (
+                    "base_url".to_string(),
+                    Value::String("http://localhost:8000".to_string())
+                ),
+            ]
+        );
+        assert_eq!(
+            file.environment("staging").unwrap(),
+            &[(
+                "base_url".to_string(),
+                Value::String("https://staging.example.org".to_string())
+            )]
+        );
+        assert_eq!(
+            file.environment("prod").unwrap(),
+            &[
+                (
+                    "base_url".to_string(),
+                    Value::String("https://example.org".to_string())
+                ),
+                ("timeout".to_string(), Value::Number(Number::Integer(30))),
+            ]
+        );
+        assert_eq!(file.environment("dev"), None);
+    }
+
+    #[test]
+    fn test_parse_file_content_error() {
+        let content = "name=Jennifer\n\nage\n";
+        assert_eq!(
+            parse_file_content(content, "vars.env").err().unwrap(),
+            CliOptionsError::Error(
+                "Missing value for variable age! (line 3 of vars.env)".to_string()
+            )
+        );
+
+        let content = "name=Jennifer\n[prod\nage=30\n";
+        assert_eq!(
+            parse_file_content(content, "vars.env").err().unwrap(),
+            CliOptionsError::Error(
+                "Invalid environment section [prod (line 2 of vars.env)".to_string()
+            )
+        );
+
+        let content = "[prod]\nage=30\n[staging]\nage=20\n[prod]\nname=Jennifer\n";
+        assert_eq!(
+            parse_file_content(content, "vars.env").err().unwrap(),
+            CliOptionsError::Error(
+                "Duplicate environment section [prod] (line 5 of vars.env)".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_resolve_files() {
+        let file1 =
+            parse_file_content("base_url=local1\n[prod]\nbase_url=prod1\n", "a.env").unwrap();
+        let file2 = parse_file_content("base_url=local2\ntimeout=10\n", "b.env").unwrap();
+        let files = [file1, file2];
+
+        let variables = resolve_files(&files, None).unwrap();
+        assert_eq!(
+            variables.last().unwrap(),
+            &("timeout".to_string(), Value::Number(Number::Integer(10)))
+        );
+        assert_eq!(
+            variables
+                .iter()
+                .rfind(|(name, _)| name == "base_url")
+                .unwrap(),
+            &("base_url".to_string(), Value::String("local2".to_string()))
+        );
+
+        // The environment section of the first file wins over the common value of the second file.
+        let variables = resolve_files(&files, Some("prod")).unwrap();
+        assert_eq!(
+            variables
+                .iter()
+                .rfind(|(name, _)| name == "base_url")
+                .unwrap(),
+            &("base_url".to_string(), Value::String("prod1".to_string()))
+        );
+
+        assert_eq!(
+            resolve_files(&files, Some("dev")).err().unwrap(),
+            CliOptionsError::Error("Environment dev is not defined in variables files".to_string())
+        );
+    }
+
     #[test]
     fn test_parse_value() {
         assert_eq!(
@@ -17,12 +17,16 @@
  */
 use std::time::Duration;
 
+use hurl_core::error::DisplaySourceError;
+use hurl_core::text::{Format, Style, StyledString};
+
 use crate::HurlRun;
 
 /// Returns the text summary of this Hurl `runs`.
 ///
-/// This is used in `--test`mode.
-pub fn summary(runs: &[HurlRun], duration: Duration) -> String {
+/// This is used in `--test`mode. The summary ends with a list of failures (file, line and error
+/// description) and a `PASS` / `FAIL` banner, colored if `color` is true.
+pub fn summary(runs: &[HurlRun], duration: Duration, color: bool) -> String {
     let total_files = runs.len();
     let total_requests = requests_count(runs);
     let duration_in_ms = duration.as_millis() as f64;
@@ -32,14 +36,81 @@ pub fn summary(runs: &[HurlRun], duration: Duration) -> String {
     let success_percent = 100.0 * success_files as f32 / total_files as f32;
     let failed = total_files - success_files;
     let failed_percent = 100.0 * failed as f32 / total_files as f32;
-    format!(
+
+    let mut total_entries = 0;
+    let mut skipped_entries = 0;
+    let mut total_asserts = 0;
+    let mut failed_asserts = 0;
+    let mut ignored_asserts = 0;
+    for run in runs {
+        let run_summary = run.hurl_result.summary();
+        total_entries += run_summary.entries_count;
+        skipped_entries += run_summary.entries_skipped_count;
+        total_asserts += run_summary.asserts_count;
+        failed_asserts += run_summary.asserts_failed_count;
+        ignored_asserts += run_summary.asserts_ignored_count;
+    }
+    let success_asserts = total_asserts - failed_asserts - ignored_asserts;
+
+    let mut s = format!(
         "--------------------------------------------------------------------------------\n\
              Executed files:    {total_files}\n\
              Executed requests: {total_requests} ({requests_rate:.1}/s)\n\
-             Succeeded files:   {success_files} ({success_percent:.1}%)\n\
+             Executed entries:  {total_entries}\n"
+    );
+    if skipped_entries > 0 {
+        s.push_str(&format!("Skipped entries:   {skipped_entries}\n"));
+    }
+    s.push_str(&format!(
+        "Succeeded files:   {success_files} ({success_percent:.1}%)\n\
              Failed files:      {failed} ({failed_percent:.1}%)\n\
-             Duration:          {duration_in_ms} ms\n"
-    )
+             Succeeded asserts: {success_asserts}\n\
+             Failed asserts:    {failed_asserts}\n"
+    ));
+    // Failed asserts that have not been enforced (with `--ignore-asserts`) don't fail their file.
+    if ignored_asserts > 0 {
+        s.push_str(&format!("Ignored asserts:   {ignored_asserts}\n"));
+    }
+    s.push_str(&format!("Duration:          {duration_in_ms} ms\n"));
+
+    let failures = failures(runs);
+    if !failures.is_empty() {
+        s.push_str("Failures:\n");
+        for failure in failures {
+            s.push_str(&format!("  {failure}\n"));
+        }
+    }
+
+    let mut banner = StyledString::new();
+    if failed == 0 {
+        banner.push_with("PASS", Style::new().green().bold());
+    } else {
+        banner.push_with("FAIL", Style::new().red().bold());
+    }
+    let format = if color { Format::Ansi } else { Format::Plain };
+    s.push_str(&banner.to_string(format));
+    s.push('\n');
+    s
+}
+
+/// Returns the list of errors of this list of `runs`, one line per error, formatted as
+/// `file:line: description: source line`.
+fn failures(runs: &[HurlRun]) -> Vec<String> {
+    let mut failures = vec![];
+    for run in runs {
+        let lines = run.content.lines().collect::<Vec<_>>();
+        for (error, _) in run.hurl_result.errors() {
+            let line = error.source_info().start.line;
+            let description = error.description();
+            let source = line
+                .checked_sub(1)
+                .and_then(|i| lines.get(i))
+                .map(|l| l.trim())
+                .unwrap_or_default();
+            failures.push(format!("{}:{line}: {description}: {source}", run.filename));
+        }
+    }
+    failures
 }
 
 /// Returns the total number of executed HTTP requests in this list of `runs`.
@@ -61,7 +132,7 @@ fn requests_count(runs: &[HurlRun]) -> usize {
 #[cfg(test)]
 pub mod tests {
     use hurl::http::CurlCmd;
-    use hurl::runner::{EntryResult, HurlResult};
+    use hurl::runner::{AssertResult, EntryResult, HurlResult, RunnerError, RunnerErrorKind};
     use hurl_core::ast::SourceInfo;
     use hurl_core::input::Input;
     use hurl_core::reader::Pos;
@@ -81,6 +152,7 @@ pub mod tests {
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
                 curl_cmd: CurlCmd::default(),
+                skipped: false,
             };
             HurlRun {
                 content: String::new(),
@@ -97,28 +169,133 @@ pub mod tests {
 
         let runs = vec![new_run(true, 10), new_run(true, 20), new_run(true, 4)];
         let duration = Duration::from_millis(128);
-        let s = summary(&runs, duration);
+        let s = summary(&runs, duration, false);
         assert_eq!(
             s,
             "--------------------------------------------------------------------------------\n\
              Executed files:    3\n\
              Executed requests: 0 (0.0/s)\n\
+             Executed entries:  3\n\
              Succeeded files:   3 (100.0%)\n\
              Failed files:      0 (0.0%)\n\
-             Duration:          128 ms\n"
+             Succeeded asserts: 0\n\
+             Failed asserts:    0\n\
+             Duration:          128 ms\n\
+             PASS\n"
         );
 
         let runs = vec![new_run(true, 10), new_run(false, 10), new_run(true, 40)];
         let duration = Duration::from_millis(200);
-        let s = summary(&runs, duration);
+        let s = summary(&runs, duration, false);
         assert_eq!(
             s,
             "--------------------------------------------------------------------------------\n\
             Executed files:    3\n\
             Executed requests: 0 (0.0/s)\n\
+            Executed entries:  3\n\
             Succeeded files:   2 (66.7%)\n\
             Failed files:      1 (33.3%)\n\
-            Duration:          200 ms\n"
+            Succeeded asserts: 0\n\
+            Failed asserts:    0\n\
+            Duration:          200 ms\n\
+            FAIL\n"
+        );
+
+        let mut runs = vec![new_run(true, 1), new_run(true, 2)];
+        runs[1].hurl_result.entries[1].entry_index = 1;
+        runs[1].hurl_result.entries[1].skipped = true;
+        let duration = Duration::from_millis(100);
+        let s = summary(&runs, duration, false);
+        assert_eq!(
+            s,
+            "--------------------------------------------------------------------------------\n\
+            Executed files:    2\n\
+            Executed requests: 0 (0.0/s)\n\
+            Executed entries:  2\n\
+            Skipped entries:   1\n\
+            Succeeded files:   2 (100.0%)\n\
+            Failed files:      0 (0.0%)\n\
+            Succeeded asserts: 0\n\
+            Failed asserts:    0\n\
+            Duration:          100 ms\n\
+            PASS\n"
+        );
+    }
+
+    #[test]
+    fn summary_with_ignored_asserts() {
+        // With `--ignore-asserts`, a failed assert is not recorded as an error and its file passes.
+        let source_info = SourceInfo::new(Pos::new(2, 6), Pos::new(2, 9));
+        let entry = EntryResult {
+            entry_index: 1,
+            source_info,
+            asserts: vec![
+                AssertResult::Status {
+                    actual: 404,
+                    expected: 200,
+                    source_info,
+                },
+                AssertResult::Status {
+                    actual: 200,
+                    expected: 200,
+                    source_info,
+                },
+            ],
+            ..Default::default()
+        };
+        let run = HurlRun {
+            content: "GET http://localhost:8000/hello\nHTTP 200\n".to_string(),
+            filename: Input::new("test.hurl"),
+            hurl_result: HurlResult {
+                entries: vec![entry],
+                duration: Duration::from_millis(0),
+                success: true,
+                cookies: vec![],
+                timestamp: 1,
+            },
+        };
+        let s = summary(&[run], Duration::from_millis(100), false);
+        assert_eq!(
+            s,
+            "--------------------------------------------------------------------------------\n\
+            Executed files:    1\n\
+            Executed requests: 0 (0.0/s)\n\
+            Executed entries:  1\n\
+            Succeeded files:   1 (100.0%)\n\
+            Failed files:      0 (0.0%)\n\
+            Succeeded asserts: 1\n\
+            Failed asserts:    0\n\
+            Ignored asserts:   1\n\
+            Duration:          100 ms\n\
+            PASS\n"
+        );
+    }
+
+    #[test]
+    fn failures_without_source_line() {
+        // An error with a line 0 (or out of the content) is listed without source line.
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+        let error = RunnerError::new(source_info, RunnerErrorKind::QueryEmptyBody, false);
+        let entry = EntryResult {
+            entry_index: 1,
+            source_info,
+            errors: vec![error],
+            ..Default::default()
+        };
+        let run = HurlRun {
+            content: "GET http://localhost:8000/hello\n".to_string(),
+            filename: Input::new("test.hurl"),
+            hurl_result: HurlResult {
+                entries: vec![entry],
+                duration: Duration::from_millis(0),
+                success: false,
+                cookies: vec![],
+                timestamp: 1,
+            },
+        };
+        assert_eq!(
+            failures(&[run]),
+            vec!["test.hurl:0: Empty body: ".to_string()]
         );
     }
 }
@@ -15,7 +15,11 @@
  * limitations under the License.
  *
  */
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
 use std::str;
 use std::str::FromStr;
 use std::time::Instant;
@@ -33,13 +37,14 @@ use crate::http::certificate::Certificate;
 use crate::http::curl_cmd::CurlCmd;
 use crate::http::debug::log_body;
 use crate::http::header::{
-    HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, EXPECT, LOCATION, USER_AGENT,
+    HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, EXPECT, LOCATION, TRANSFER_ENCODING,
+    USER_AGENT,
 };
 use crate::http::options::ClientOptions;
 use crate::http::timings::Timings;
 use crate::http::url::Url;
 use crate::http::{
-    easy_ext, Call, Cookie, FileParam, Header, HttpError, HttpVersion, IpResolve, Method,
+    easy_ext, Body, Call, Cookie, FileParam, Header, HttpError, HttpVersion, IpResolve, Method,
     MultipartParam, Param, Request, RequestCookie, RequestSpec, RequestedHttpVersion, Response,
     Verbosity,
 };
@@ -144,8 +149,7 @@ impl Client {
                 }
             };
 
-            let redirect_method = redirect_method(status, request_spec.method);
-            let mut headers = request_spec.headers;
+            let mut headers = std::mem::take(&mut request_spec.headers);
 
             // When following redirection, we filter `AUTHORIZATION` header unless explicitly told
             // to trust the redirected host with `--location-trusted`.
@@ -154,12 +158,7 @@ impl Client {
                 headers.retain(|h| !h.name_eq(AUTHORIZATION));
                 options.user = None;
             }
-            request_spec = RequestSpec {
-                method: redirect_method,
-                url: redirect_url,
-                headers,
-                ..Default::default()
-            };
+            request_spec = redirect_request_spec(status, request_spec, redirect_url, headers);
         }
         Ok(calls)
     }
@@ -185,9 +184,13 @@ impl Client {
         let mut request_headers = HeaderVec::new();
         let mut status_lines = vec![];
         let mut response_headers = vec![];
-        let has_body_data = !request_spec.body.bytes().is_empty()
-            || !request_spec.form.is_empty()
-            || !request_spec.multipart.is_empty();
+        let has_body_data = request_spec.has_body_data();
+
+        // A file body is not read in memory but streamed by libcurl through a read callback.
+        let body_stream = match &request_spec.body {
+            Body::Stream(path) => Some(RefCell::new(BodyStream::open(path)?)),
+            _ => None,
+        };
 
         // `request_body` are request body bytes computed by libcurl (the real bytes sent over the wire)
         // whereas`request_spec_body` are request body bytes provided by Hurl user. For instance, if user uses
@@ -195,6 +198,7 @@ impl Client {
         // of key-value.
         let mut request_body = Vec::<u8>::new();
         let mut response_body = Vec::<u8>::new();
+        let response_size_exceeded = Cell::new(false);
 
         {
             let mut transfer = self.handle.transfer();
@@ -236,8 +240,11 @@ impl Client {
                         logger.debug_important("Request body:");
                         log_body(data, &request_headers, true, logger);
                     }
-                    // Constructs request body from libcurl debug info.
-                    request_body.extend(data);
+                    // Constructs request body from libcurl debug info. Streamed bodies can be
+                    // huge and are not kept.
+                    if body_stream.is_none() {
+                        request_body.extend(data);
+                    }
                 }
                 // Curl debug logs
                 easy::InfoType::Text => {
@@ -263,11 +270,36 @@ impl Client {
             })?;
 
             transfer.write_function(|data| {
+                // Before curl 8.4.0, libcurl doesn't check the maximum filesize when the size of
+                // the response is not known prior to download: we stop the transfer as soon as
+                // the limit is exceeded so a large response is never fully read in memory.
+                if let Some(max_filesize) = options.max_filesize {
+                    if (response_body.len() + data.len()) as u64 > max_filesize {
+                        response_size_exceeded.set(true);
+                        return Ok(0);
+                    }
+                }
                 response_body.extend(data);
                 Ok(data.len())
             })?;
 
+            if let Some(body_stream) = &body_stream {
+                transfer.read_function(|buf| body_stream.borrow_mut().read(buf))?;
+                transfer.seek_function(|from| body_stream.borrow_mut().seek(from))?;
+            }
+
             if let Err(e) = transfer.perform() {
+                if response_size_exceeded.get() {
+                    let max_filesize = options.max_filesize.unwrap_or_default();
+                    return Err(HttpError::AllowedResponseSizeExceeded(max_filesize));
+                }
+                if let Some(body_stream) = &body_stream {
+                    let body_stream = body_stream.borrow();
+                    if body_stream.has_error {
+                        let path = body_stream.path.clone();
+                        return Err(HttpError::FileReadAccess { path });
+                    }
+                }
                 let code = e.code() as i32; // due to windows build
                 let description = match e.extra_description() {
                     None => e.description().to_string(),
@@ -385,7 +417,9 @@ impl Client {
 
         // We check libcurl HTTP version support.
         let http_version = options.http_version;
-        if (http_version == RequestedHttpVersion::Http2 && !self.http2)
+        if ((http_version == RequestedHttpVersion::Http2
+            || http_version == RequestedHttpVersion::Http2PriorKnowledge)
+            && !self.http2)
             || (http_version == RequestedHttpVersion::Http3 && !self.http3)
         {
             return Err(HttpError::UnsupportedHttpVersion(http_version));
@@ -486,8 +520,7 @@ impl Client {
         self.set_cookies(&request_spec.cookies)?;
         self.set_form(&request_spec.form)?;
         self.set_multipart(&request_spec.multipart)?;
-        let request_spec_body = &request_spec.body.bytes();
-        self.set_body(request_spec_body)?;
+        self.set_body(&request_spec.body)?;
         // TODO: do we want to manage the headers with no content? There are two type of no-content
         // headers: `foo:` and `foo;`. The first one can be used to remove libcurl headers (`Host:`)
         // while the second one is used to send an empty header.
@@ -498,9 +531,11 @@ impl Client {
             .map(|h| h.as_str())
             .collect::<Vec<&str>>();
         let headers = &request_spec.headers.aggregate_raw_headers(&options_headers);
+        let chunked = request_spec.is_chunked(options);
         self.set_headers(
             headers,
             request_spec.implicit_content_type.as_deref(),
+            chunked,
             options,
         )?;
         if let Some(aws_sigv4) = &options.aws_sigv4 {
@@ -545,10 +580,14 @@ impl Client {
     }
 
     /// Sets HTTP headers.
+    ///
+    /// If `chunked` is true, the request body is sent with chunked transfer encoding: libcurl
+    /// doesn't send a `Content-Length` header when `Transfer-Encoding: chunked` is set.
     fn set_headers(
         &mut self,
         headers: &HeaderVec,
         implicit_content_type: Option<&str>,
+        chunked: bool,
         options: &ClientOptions,
     ) -> Result<(), HttpError> {
         let mut list = List::new();
@@ -611,6 +650,9 @@ impl Client {
         if options.compressed && !headers.contains_key(ACCEPT_ENCODING) {
             list.append(&format!("{}: gzip, deflate, br", ACCEPT_ENCODING))?;
         }
+        if chunked && !headers.contains_key(TRANSFER_ENCODING) {
+            list.append(&format!("{}: chunked", TRANSFER_ENCODING))?;
+        }
 
         self.handle.http_headers(list)?;
         Ok(())
@@ -654,9 +696,10 @@ impl Client {
                         filename,
                         data,
                         content_type,
+                        part_filename,
                     }) => form
                         .part(name)
-                        .buffer(filename, data.clone())
+                        .buffer(part_filename.as_ref().unwrap_or(filename), data.clone())
                         .content_type(content_type)
                         .add()
                         .unwrap(),
@@ -668,10 +711,27 @@ impl Client {
     }
 
     /// Sets request body.
-    fn set_body(&mut self, data: &[u8]) -> Result<(), HttpError> {
-        if !data.is_empty() {
-            self.handle.post(true)?;
-            self.handle.post_fields_copy(data)?;
+    ///
+    /// The data of a streamed body is not set here, but read while sending the request, only its
+    /// size is given to libcurl. When the size is not known (for a pipe for instance), libcurl
+    /// reads the body until its end.
+    fn set_body(&mut self, body: &Body) -> Result<(), HttpError> {
+        match body {
+            Body::Stream(path) => match file_size(path)? {
+                Some(0) => {}
+                Some(size) => {
+                    self.handle.post(true)?;
+                    self.handle.post_field_size(size)?;
+                }
+                None => self.handle.post(true)?,
+            },
+            _ => {
+                let data = body.data().unwrap_or_default();
+                if !data.is_empty() {
+                    self.handle.post(true)?;
+                    self.handle.post_fields_copy(data)?;
+                }
+            }
         }
         Ok(())
     }
@@ -831,6 +891,38 @@ fn redirect_method(response_status: u32, original_method: Method) -> Method {
     }
 }
 
+/// Returns the request to send to `redirect_url` after a response with `response_status` to
+/// `request_spec`, with the request `headers`.
+///
+/// When the redirected request keeps the original method (307, 308), the original body is sent
+/// again. When the redirected request is converted to a GET (301, 302, 303), the body is dropped.
+fn redirect_request_spec(
+    response_status: u32,
+    request_spec: RequestSpec,
+    redirect_url: Url,
+    headers: HeaderVec,
+) -> RequestSpec {
+    let method = redirect_method(response_status, request_spec.method.clone());
+    if matches!(response_status, 301..=303) {
+        return RequestSpec {
+            method,
+            url: redirect_url,
+            headers,
+            ..Default::default()
+        };
+    }
+    RequestSpec {
+        method,
+        url: redirect_url,
+        headers,
+        form: request_spec.form,
+        multipart: request_spec.multipart,
+        body: request_spec.body,
+        implicit_content_type: request_spec.implicit_content_type,
+        ..Default::default()
+    }
+}
+
 /// Returns cookies from both cookies from the cookie storage and the request.
 pub fn all_cookies(cookie_storage: &[Cookie], request_spec: &RequestSpec) -> Vec<RequestCookie> {
     let mut cookies = request_spec.cookies.clone();
@@ -849,7 +941,12 @@ pub fn all_cookies(cookie_storage: &[Cookie], request_spec: &RequestSpec) -> Vec
 }
 
 /// Matches cookie for a given URL.
+///
+/// A secure cookie (`https` flag set to `TRUE`) only matches an `https` URL.
 pub fn match_cookie(cookie: &Cookie, url: &Url) -> bool {
+    if cookie.https == "TRUE" && url.scheme() != "https" {
+        return false;
+    }
     if let Some(domain) = url.domain() {
         if cookie.include_subdomain == "FALSE" {
             if cookie.domain != domain {
@@ -858,6 +955,12 @@ pub fn match_cookie(cookie: &Cookie, url: &Url) -> bool {
         } else if !domain.ends_with(cookie.domain.as_str()) {
             return false;
         }
+    } else if let Some(host) = url.host() {
+        // IP addresses are matched exactly. libcurl stores IPv6 cookie domains without brackets.
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        if cookie.domain != host {
+            return false;
+        }
     }
     url.path().starts_with(cookie.path.as_str())
 }
@@ -922,7 +1025,7 @@ fn to_list(items: &[String]) -> List {
 /// > In the <certificate> portion of the argument, you must escape the character ":" as "\:" so
 /// > that it is not recognized as the password delimiter. Similarly, you must escape the character
 /// > "\" as "\\" so that it is not recognized as an escape character.
-fn parse_cert_password(cert_and_pass: &str) -> (String, Option<String>) {
+pub(crate) fn parse_cert_password(cert_and_pass: &str) -> (String, Option<String>) {
     let mut iter = cert_and_pass.chars();
     let mut cert = String::new();
     let mut password = String::new();
@@ -962,6 +1065,80 @@ fn parse_cert_password(cert_and_pass: &str) -> (String, Option<String>) {
     }
 }
 
+/// Returns the size of the file at `path`.
+/// Returns the size of the file at `path`, or `None` if it's not a regular file (a pipe for
+/// instance) and its size can't be known before reading it.
+fn file_size(path: &Path) -> Result<Option<u64>, HttpError> {
+    match std::fs::metadata(path) {
+        Ok(metadata) => Ok(metadata.is_file().then_some(metadata.len())),
+        Err(_) => Err(HttpError::FileReadAccess {
+            path: path.to_path_buf(),
+        }),
+    }
+}
+
+/// A request body read from a file while libcurl sends it.
+///
+/// The size of a regular file is announced before the transfer: if the file can not be read, or
+/// is truncated during the transfer, the transfer is aborted and `has_error` is set. Other files
+/// (like pipes) have no known size and are read until their end.
+struct BodyStream {
+    file: File,
+    path: PathBuf,
+    size: Option<u64>,
+    read_count: u64,
+    has_error: bool,
+}
+
+impl BodyStream {
+    /// Opens the file at `path` to be streamed.
+    fn open(path: &Path) -> Result<Self, HttpError> {
+        let error = || HttpError::FileReadAccess {
+            path: path.to_path_buf(),
+        };
+        let file = File::open(path).map_err(|_| error())?;
+        let metadata = file.metadata().map_err(|_| error())?;
+        let size = metadata.is_file().then_some(metadata.len());
+        Ok(BodyStream {
+            file,
+            path: path.to_path_buf(),
+            size,
+            read_count: 0,
+            has_error: false,
+        })
+    }
+
+    /// Reads the next chunk of the body into `buf`, see [`easy::Transfer::read_function`].
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, easy::ReadError> {
+        match self.file.read(buf) {
+            // The file is shorter than the size announced to the server.
+            Ok(0) if self.size.is_some_and(|size| self.read_count < size) => {
+                self.has_error = true;
+                Err(easy::ReadError::Abort)
+            }
+            Ok(count) => {
+                self.read_count += count as u64;
+                Ok(count)
+            }
+            Err(_) => {
+                self.has_error = true;
+                Err(easy::ReadError::Abort)
+            }
+        }
+    }
+
+    /// Rewinds the body, see [`easy::Transfer::seek_function`].
+    fn seek(&mut self, from: SeekFrom) -> easy::SeekResult {
+        match self.file.seek(from) {
+            Ok(position) => {
+                self.read_count = position;
+                easy::SeekResult::Ok
+            }
+            Err(_) => easy::SeekResult::Fail,
+        }
+    }
+}
+
 impl From<RequestedHttpVersion> for easy::HttpVersion {
     fn from(value: RequestedHttpVersion) -> Self {
         match value {
@@ -969,6 +1146,7 @@ impl From<RequestedHttpVersion> for easy::HttpVersion {
             RequestedHttpVersion::Http10 => easy::HttpVersion::V10,
             RequestedHttpVersion::Http11 => easy::HttpVersion::V11,
             RequestedHttpVersion::Http2 => easy::HttpVersion::V2,
+            RequestedHttpVersion::Http2PriorKnowledge => easy::HttpVersion::V2PriorKnowledge,
             RequestedHttpVersion::Http3 => easy::HttpVersion::V3,
         }
     }
@@ -990,7 +1168,6 @@ mod tests {
     use crate::util::logger::ErrorFormat;
     use crate::util::term::{Stderr, WriteMode};
     use std::default::Default;
-    use std::path::PathBuf;
 
     #[test]
     fn test_parse_header() {
@@ -1064,6 +1241,148 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_match_cookie_ip() {
+        let cookie = Cookie {
+            domain: "::1".to_string(),
+            include_subdomain: "FALSE".to_string(),
+            path: "/".to_string(),
+            https: "FALSE".to_string(),
+            expires: String::new(),
+            name: String::new(),
+            value: String::new(),
+            http_only: false,
+        };
+        assert!(match_cookie(
+            &cookie,
+            &Url::from_str("http://[::1]:8000/toto").unwrap()
+        ));
+        assert!(!match_cookie(
+            &cookie,
+            &Url::from_str("http://[::2]:8000/toto").unwrap()
+        ));
+        assert!(!match_cookie(
+            &cookie,
+            &Url::from_str("http://127.0.0.1:8000/toto").unwrap()
+        ));
+
+        let cookie = Cookie {
+            domain: "example.com".to_string(),
+            ..cookie
+        };
+        assert!(!match_cookie(
+            &cookie,
+            &Url::from_str("http://[::1]:8000/toto").unwrap()
+        ));
+
+        let cookie = Cookie {
+            domain: "127.0.0.1".to_string(),
+            ..cookie
+        };
+        assert!(match_cookie(
+            &cookie,
+            &Url::from_str("http://127.0.0.1:8000/toto").unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_match_secure_cookie() {
+        let cookie = Cookie {
+            domain: "example.com".to_string(),
+            include_subdomain: "FALSE".to_string(),
+            path: "/".to_string(),
+            https: "TRUE".to_string(),
+            expires: String::new(),
+            name: String::new(),
+            value: String::new(),
+            http_only: false,
+        };
+        assert!(match_cookie(
+            &cookie,
+            &Url::from_str("https://example.com/toto").unwrap()
+        ));
+        assert!(!match_cookie(
+            &cookie,
+            &Url::from_str("http://example.com/toto").unwrap()
+        ));
+
+        let cookie = Cookie {
+            https: "FALSE".to_string(),
+            ..cookie
+        };
+        assert!(match_cookie(
+            &cookie,
+            &Url::from_str("https://example.com/toto").unwrap()
+        ));
+        assert!(match_cookie(
+            &cookie,
+            &Url::from_str("http://example.com/toto").unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_url_encode_params() {
+        let mut client = Client::new();
+        let params = vec![
+            Param {
+                name: "param1".to_string(),
+                value: "a b&c=d".to_string(),
+            },
+            Param {
+                name: "param2".to_string(),
+                value: "café".to_string(),
+            },
+            Param {
+                name: "param3".to_string(),
+                value: "-._~".to_string(),
+            },
+        ];
+        assert_eq!(
+            client.url_encode_params(&params),
+            "param1=a%20b%26c%3Dd&param2=caf%C3%A9&param3=-._~"
+        );
+    }
+
+    #[test]
+    fn test_generate_url() {
+        let mut client = Client::new();
+        let url = Url::from_str("http://localhost:8000/querystring").unwrap();
+        assert_eq!(
+            client.generate_url(&url, &[]),
+            "http://localhost:8000/querystring"
+        );
+
+        let params = vec![Param::new("value", "a b&c=d+e")];
+        assert_eq!(
+            client.generate_url(&url, &params),
+            "http://localhost:8000/querystring?value=a%20b%26c%3Dd%2Be"
+        );
+
+        // Values already present in the URL are kept as is, and not encoded twice.
+        let url = Url::from_str("http://localhost:8000/querystring?raw=a%20b").unwrap();
+        assert_eq!(
+            client.generate_url(&url, &params),
+            "http://localhost:8000/querystring?raw=a%20b&value=a%20b%26c%3Dd%2Be"
+        );
+        let params = vec![Param::new("value", "a%20b")];
+        assert_eq!(
+            client.generate_url(&url, &params),
+            "http://localhost:8000/querystring?raw=a%20b&value=a%2520b"
+        );
+
+        // Duplicated keys are repeated, after the ones already present in the URL.
+        let url = Url::from_str("http://localhost:8000/querystring?id=0").unwrap();
+        let params = vec![
+            Param::new("id", "1"),
+            Param::new("name", "Bob"),
+            Param::new("id", "2"),
+        ];
+        assert_eq!(
+            client.generate_url(&url, &params),
+            "http://localhost:8000/querystring?id=0&id=1&name=Bob&id=2"
+        );
+    }
+
     #[test]
     fn test_redirect_method() {
         // Status of the response to be redirected | method of the original request | method of the new request
@@ -1080,6 +1399,9 @@ mod tests {
             (304, "GET", "GET"),
             (304, "POST", "POST"),
             (304, "DELETE", "DELETE"),
+            (307, "GET", "GET"),
+            (307, "POST", "POST"),
+            (307, "DELETE", "DELETE"),
             (308, "GET", "GET"),
             (308, "POST", "POST"),
             (308, "DELETE", "DELETE"),
@@ -1092,6 +1414,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_redirect_request_spec() {
+        let request_spec = RequestSpec {
+            method: Method("POST".to_string()),
+            url: Url::from_str("http://localhost:8000/login").unwrap(),
+            body: Body::Binary(b"user=bob".to_vec()),
+            implicit_content_type: Some("application/x-www-form-urlencoded".to_string()),
+            ..Default::default()
+        };
+        let redirect_url = Url::from_str("http://localhost:8000/welcome").unwrap();
+
+        // 307 and 308 resend the same method and body.
+        for status in [307, 308] {
+            let redirected = redirect_request_spec(
+                status,
+                request_spec.clone(),
+                redirect_url.clone(),
+                HeaderVec::new(),
+            );
+            assert_eq!(redirected.method, Method("POST".to_string()));
+            assert_eq!(redirected.url, redirect_url);
+            assert_eq!(redirected.body, Body::Binary(b"user=bob".to_vec()));
+            assert_eq!(
+                redirected.implicit_content_type,
+                Some("application/x-www-form-urlencoded".to_string())
+            );
+        }
+
+        // 301, 302 and 303 switch to a GET without body.
+        for status in [301, 302, 303] {
+            let redirected = redirect_request_spec(
+                status,
+                request_spec.clone(),
+                redirect_url.clone(),
+                HeaderVec::new(),
+            );
+            assert_eq!(redirected.method, Method("GET".to_string()));
+            assert_eq!(redirected.url, redirect_url);
+            assert!(redirected.body.is_empty());
+            assert_eq!(redirected.implicit_content_type, None);
+        }
+    }
+
     #[test]
     fn http_client_state_always_http2() {
         let mut state = ClientState::default();
@@ -1239,4 +1604,46 @@ mod tests {
             ("foo\\".to_string(), Some("toto\\:tata:tutu".to_string()))
         );
     }
+
+    #[test]
+    fn test_body_stream() {
+        let path = std::env::temp_dir().join(format!("hurl-{}.bin", uuid::Uuid::new_v4()));
+        std::fs::write(&path, b"Hello World!").unwrap();
+        let mut stream = BodyStream::open(&path).unwrap();
+        assert_eq!(stream.size, Some(12));
+
+        let mut buf = [0; 5];
+        assert_eq!(stream.read(&mut buf).unwrap(), 5);
+        assert_eq!(&buf, b"Hello");
+        assert!(matches!(
+            stream.seek(SeekFrom::Start(0)),
+            easy::SeekResult::Ok
+        ));
+        assert_eq!(stream.read_count, 0);
+
+        // The file is truncated during the transfer.
+        std::fs::write(&path, b"Hello").unwrap();
+        assert_eq!(stream.read(&mut buf).unwrap(), 5);
+        assert!(stream.read(&mut buf).is_err());
+        assert!(stream.has_error);
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            BodyStream::open(&path).err().unwrap(),
+            HttpError::FileReadAccess { path }
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_body_stream_unknown_size() {
+        // A device (like a pipe) has no known size: it's read until its end.
+        let path = Path::new("/dev/null");
+        assert_eq!(file_size(path).unwrap(), None);
+        let mut stream = BodyStream::open(path).unwrap();
+        assert_eq!(stream.size, None);
+        let mut buf = [0; 5];
+        assert_eq!(stream.read(&mut buf).unwrap(), 0);
+        assert!(!stream.has_error);
+    }
 }
@@ -119,7 +119,15 @@ pub struct ParseCookieError;
 impl FromStr for Cookie {
     type Err = ParseCookieError;
 
+    /// Parses a cookie line in Netscape cookie format.
+    ///
+    /// Lines starting with `#` are comments and can not be parsed as cookie, except for the
+    /// `#HttpOnly_` prefix which flags an HttpOnly cookie.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim_start();
+        if s.starts_with('#') && !s.starts_with("#HttpOnly_") {
+            return Err(ParseCookieError);
+        }
         let tokens = s.split_ascii_whitespace().collect::<Vec<&str>>();
         let (http_only, domain) = if let Some(&v) = tokens.first() {
             if let Some(domain) = v.strip_prefix("#HttpOnly_") {
@@ -206,6 +214,51 @@ mod tests {
             }
         );
 
+        assert_eq!(
+            Cookie::from_str("#HttpOnly_localhost\tFALSE\t/\tFALSE\t0\tcookie3\tvalueB").unwrap(),
+            Cookie {
+                domain: "localhost".to_string(),
+                include_subdomain: "FALSE".to_string(),
+                path: "/".to_string(),
+                https: "FALSE".to_string(),
+                expires: "0".to_string(),
+                name: "cookie3".to_string(),
+                value: "valueB".to_string(),
+                http_only: true,
+            }
+        );
+
         assert_eq!(Cookie::from_str("xxx").err().unwrap(), ParseCookieError);
+        assert_eq!(Cookie::from_str("").err().unwrap(), ParseCookieError);
+    }
+
+    #[test]
+    pub fn parse_cookie_from_comment() {
+        assert_eq!(
+            Cookie::from_str("# Netscape HTTP Cookie File")
+                .err()
+                .unwrap(),
+            ParseCookieError
+        );
+        assert_eq!(
+            Cookie::from_str("# localhost\tFALSE\t/\tFALSE\t0\tcookie1\tvalueA")
+                .err()
+                .unwrap(),
+            ParseCookieError
+        );
+    }
+
+    #[test]
+    pub fn cookie_round_trip() {
+        let lines = [
+            "httpbin.org\tFALSE\t/\tFALSE\t0\tcookie1\tvalueA",
+            ".example.org\tTRUE\t/api\tTRUE\t2145916800\tsession\tabc123",
+            "#HttpOnly_localhost\tFALSE\t/\tFALSE\t0\tcookie3\tvalueB",
+        ];
+        for line in lines {
+            let cookie = Cookie::from_str(line).unwrap();
+            assert_eq!(cookie.to_string(), line);
+            assert_eq!(Cookie::from_str(&cookie.to_string()).unwrap(), cookie);
+        }
     }
 }
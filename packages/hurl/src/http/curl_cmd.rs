@@ -18,7 +18,7 @@
 use crate::http::client::all_cookies;
 use crate::http::{
     Body, ClientOptions, Cookie, FileParam, Header, HeaderVec, IpResolve, Method, MultipartParam,
-    Param, RequestSpec, RequestedHttpVersion, CONTENT_TYPE,
+    Param, RequestSpec, RequestedHttpVersion, CONTENT_TYPE, TRANSFER_ENCODING,
 };
 use crate::runner::Output;
 use crate::util::path::ContextDir;
@@ -73,6 +73,7 @@ impl CurlCmd {
             headers,
             request_spec.implicit_content_type.as_deref(),
             &request_spec.body,
+            request_spec.is_chunked(options),
         );
         args.append(&mut params);
 
@@ -96,16 +97,17 @@ impl CurlCmd {
 fn method_params(request_spec: &RequestSpec) -> Vec<String> {
     let has_body = !request_spec.multipart.is_empty()
         || !request_spec.form.is_empty()
-        || !request_spec.body.bytes().is_empty();
+        || !request_spec.body.is_empty();
     request_spec.method.curl_args(has_body)
 }
 
 /// Returns the curl args corresponding to the HTTP headers, from a list of headers,
-/// an optional implicit content type, and the request body.
+/// an optional implicit content type, the request body and its transfer encoding.
 fn headers_params(
     headers: &HeaderVec,
     implicit_content_type: Option<&str>,
     body: &Body,
+    chunked: bool,
 ) -> Vec<String> {
     let mut args = vec![];
 
@@ -113,6 +115,11 @@ fn headers_params(
         args.append(&mut header.curl_args());
     }
 
+    if chunked && !headers.contains_key(TRANSFER_ENCODING) {
+        args.push("--header".to_string());
+        args.push(format!("'{}: chunked'", TRANSFER_ENCODING));
+    }
+
     let has_explicit_content_type = headers.contains_key(CONTENT_TYPE);
     if has_explicit_content_type {
         return args;
@@ -125,7 +132,7 @@ fn headers_params(
             args.push("--header".to_string());
             args.push(format!("'{}: {content_type}'", CONTENT_TYPE));
         }
-    } else if !body.bytes().is_empty() {
+    } else if !body.is_empty() {
         match body {
             Body::Text(_) => {
                 args.push("--header".to_string());
@@ -135,7 +142,7 @@ fn headers_params(
                 args.push("--header".to_string());
                 args.push(format!("'{}: application/octet-stream'", CONTENT_TYPE));
             }
-            Body::File(_, _) => {
+            Body::File(_, _) | Body::Stream(_) => {
                 args.push("--header".to_string());
                 args.push(format!("'{}:'", CONTENT_TYPE));
             }
@@ -157,7 +164,7 @@ fn body_params(request_spec: &RequestSpec, context_dir: &ContextDir) -> Vec<Stri
         args.push(format!("'{}'", param.curl_arg(context_dir)));
     }
 
-    if request_spec.body.bytes().is_empty() {
+    if request_spec.body.is_empty() {
         return args;
     }
 
@@ -179,7 +186,7 @@ fn body_params(request_spec: &RequestSpec, context_dir: &ContextDir) -> Vec<Stri
     // In summary: if the payload is a file (@foo.bin), we must use --data-binary option in
     // order to curl to not process the data sent.
     let param = match request_spec.body {
-        Body::File(_, _) => "--data-binary",
+        Body::File(_, _) | Body::Stream(_) => "--data-binary",
         _ => "--data",
     };
     args.push(param.to_string());
@@ -333,10 +340,14 @@ impl MultipartParam {
                 name,
                 filename,
                 content_type,
+                part_filename,
                 ..
             }) => {
                 let path = context_dir.resolved_path(Path::new(filename));
-                let value = format!("@{};type={}", path.to_string_lossy(), content_type);
+                let mut value = format!("@{};type={}", path.to_string_lossy(), content_type);
+                if let Some(part_filename) = part_filename {
+                    value.push_str(&format!(";filename={part_filename}"));
+                }
                 format!("{name}={value}")
             }
         }
@@ -348,10 +359,12 @@ impl Body {
         match self {
             Body::Text(s) => encode_shell_string(s),
             Body::Binary(bytes) => format!("$'{}'", encode_bytes(bytes)),
+            Body::File(_, filename) if filename == "-" => "'@-'".to_string(),
             Body::File(_, filename) => {
                 let path = context_dir.resolved_path(Path::new(filename));
                 format!("'@{}'", path.to_string_lossy())
             }
+            Body::Stream(path) => format!("'@{}'", path.to_string_lossy()),
         }
     }
 }
@@ -397,6 +410,9 @@ impl ClientOptions {
             RequestedHttpVersion::Http10 => arguments.push("--http1.0".to_string()),
             RequestedHttpVersion::Http11 => arguments.push("--http1.1".to_string()),
             RequestedHttpVersion::Http2 => arguments.push("--http2".to_string()),
+            RequestedHttpVersion::Http2PriorKnowledge => {
+                arguments.push("--http2-prior-knowledge".to_string());
+            }
             RequestedHttpVersion::Http3 => arguments.push("--http3".to_string()),
         }
         if self.insecure {
@@ -534,7 +550,7 @@ mod tests {
             ..Default::default()
         };
 
-        let context_dir = &ContextDir::default();
+        let context_dir = ContextDir::default();
         let cookies = vec![];
         let options = ClientOptions::default();
         let output = None;
@@ -610,11 +626,12 @@ mod tests {
             ..Default::default()
         };
 
-        let context_dir = &ContextDir::default();
+        let context_dir = ContextDir::default();
         let cookies = vec![];
         let options = ClientOptions {
             aws_sigv4: None,
             cacert_file: None,
+            chunked: false,
             client_cert_file: None,
             client_key_file: None,
             compressed: true,
@@ -682,6 +699,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hello_request_with_http2_prior_knowledge() {
+        let request = RequestSpec {
+            method: Method("GET".to_string()),
+            url: Url::from_str("http://localhost:8000/hello").unwrap(),
+            ..Default::default()
+        };
+        let context_dir = ContextDir::default();
+        let options = ClientOptions {
+            http_version: RequestedHttpVersion::Http2PriorKnowledge,
+            ..Default::default()
+        };
+
+        let cmd = CurlCmd::new(&request, &[], &context_dir, None, &options);
+        assert_eq!(
+            cmd.to_string(),
+            "curl --http2-prior-knowledge 'http://localhost:8000/hello'"
+        );
+    }
+
     #[test]
     fn url_with_dot() {
         let request = RequestSpec {
@@ -690,7 +727,7 @@ mod tests {
             ..Default::default()
         };
 
-        let context_dir = &ContextDir::default();
+        let context_dir = ContextDir::default();
         let cookies = vec![];
         let options = ClientOptions::default();
         let output = None;
@@ -710,7 +747,7 @@ mod tests {
             ..Default::default()
         };
 
-        let context_dir = &ContextDir::default();
+        let context_dir = ContextDir::default();
         let cookies = vec![];
         let options = ClientOptions::default();
         let output = None;
@@ -742,7 +779,7 @@ mod tests {
             ..Default::default()
         };
 
-        let context_dir = &ContextDir::default();
+        let context_dir = ContextDir::default();
         let cookies = vec![];
         let options = ClientOptions::default();
         let output = None;
@@ -781,7 +818,7 @@ mod tests {
             ..Default::default()
         };
 
-        let context_dir = &ContextDir::default();
+        let context_dir = ContextDir::default();
         let cookies = vec![];
         let options = ClientOptions::default();
         let output = None;
@@ -805,12 +842,12 @@ mod tests {
             method: Method("POST".to_string()),
             url: Url::from_str("http://localhost/json").unwrap(),
             headers,
-            body: Body::Text("".to_string()),
+            body: Body::Text(String::new()),
             implicit_content_type: Some("application/json".to_string()),
             ..Default::default()
         };
 
-        let context_dir = &ContextDir::default();
+        let context_dir = ContextDir::default();
         let cookies = vec![];
         let options = ClientOptions::default();
         let output = None;
@@ -857,7 +894,7 @@ mod tests {
             ..Default::default()
         };
 
-        let context_dir = &ContextDir::default();
+        let context_dir = ContextDir::default();
         let cookies = vec![];
         let options = ClientOptions::default();
         let output = None;
@@ -872,6 +909,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn chunked_request() {
+        let mut request = RequestSpec {
+            method: Method("POST".to_string()),
+            url: Url::from_str("http://localhost:8000/hello").unwrap(),
+            body: Body::Text("Hello World!".to_string()),
+            ..Default::default()
+        };
+
+        let context_dir = ContextDir::default();
+        let cookies = vec![];
+        let options = ClientOptions {
+            chunked: true,
+            ..Default::default()
+        };
+        let output = None;
+
+        let cmd = CurlCmd::new(&request, &cookies, &context_dir, output.as_ref(), &options);
+        assert_eq!(
+            cmd.to_string(),
+            "curl \
+            --header 'Transfer-Encoding: chunked' \
+            --header 'Content-Type:' \
+            --data 'Hello World!' \
+            'http://localhost:8000/hello'"
+        );
+
+        // Chunked transfer encoding doesn't exist in HTTP/2.
+        let http2_options = ClientOptions {
+            chunked: true,
+            http_version: RequestedHttpVersion::Http2,
+            ..Default::default()
+        };
+        let cmd = CurlCmd::new(
+            &request,
+            &cookies,
+            &context_dir,
+            output.as_ref(),
+            &http2_options,
+        );
+        assert_eq!(
+            cmd.to_string(),
+            "curl \
+            --header 'Content-Type:' \
+            --data 'Hello World!' \
+            --http2 \
+            'http://localhost:8000/hello'"
+        );
+
+        // Without body, the request is not chunked.
+        request.body = Body::Text(String::new());
+        let cmd = CurlCmd::new(&request, &cookies, &context_dir, output.as_ref(), &options);
+        assert_eq!(
+            cmd.to_string(),
+            "curl \
+            --request POST \
+            'http://localhost:8000/hello'"
+        );
+    }
+
     #[test]
     fn test_encode_byte() {
         assert_eq!(encode_byte(1), "\\x01".to_string());
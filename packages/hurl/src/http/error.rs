@@ -16,14 +16,23 @@
 *
 */
 
+use std::path::PathBuf;
+
 use crate::http::RequestedHttpVersion;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HttpError {
+    CouldNotCompressRequest {
+        description: String,
+    },
     CouldNotParseResponse,
     CouldNotUncompressResponse {
         description: String,
     },
+    /// I/O read error on `path`, while sending a request body.
+    FileReadAccess {
+        path: PathBuf,
+    },
     InvalidCharset {
         charset: String,
     },
@@ -39,6 +48,9 @@ pub enum HttpError {
         minimum_version: String,
     },
     TooManyRedirect,
+    UnsupportedBodyCompression {
+        description: String,
+    },
     UnsupportedContentEncoding {
         description: String,
     },
@@ -63,14 +75,17 @@ impl HttpError {
     pub fn description(&self) -> String {
         match self {
             HttpError::AllowedResponseSizeExceeded(_) => "HTTP connection".to_string(),
+            HttpError::CouldNotCompressRequest { .. } => "Compression error".to_string(),
             HttpError::CouldNotParseResponse => "HTTP connection".to_string(),
             HttpError::CouldNotUncompressResponse { .. } => "Decompression error".to_string(),
+            HttpError::FileReadAccess { .. } => "File read access".to_string(),
             HttpError::InvalidCharset { .. } => "Invalid charset".to_string(),
             HttpError::InvalidDecoding { .. } => "Invalid decoding".to_string(),
             HttpError::InvalidUrl(..) => "Invalid URL".to_string(),
             HttpError::Libcurl { .. } => "HTTP connection".to_string(),
             HttpError::LibcurlUnknownOption { .. } => "HTTP connection".to_string(),
             HttpError::TooManyRedirect => "HTTP connection".to_string(),
+            HttpError::UnsupportedBodyCompression { .. } => "Compression error".to_string(),
             HttpError::UnsupportedContentEncoding { .. } => "Decompression error".to_string(),
             HttpError::UnsupportedHttpVersion(_) => "Unsupported HTTP version".to_string(),
         }
@@ -81,10 +96,16 @@ impl HttpError {
             HttpError::AllowedResponseSizeExceeded(max_size) => {
                 format!("exceeded the maximum allowed file size ({max_size} bytes)")
             }
+            HttpError::CouldNotCompressRequest { description } => {
+                format!("could not compress request with {description}")
+            }
             HttpError::CouldNotParseResponse => "could not parse Response".to_string(),
             HttpError::CouldNotUncompressResponse { description } => {
                 format!("could not uncompress response with {description}")
             }
+            HttpError::FileReadAccess { path } => {
+                format!("file {} can not be read", path.to_string_lossy())
+            }
             HttpError::InvalidCharset { charset } => {
                 format!("the charset '{charset}' is not valid")
             }
@@ -103,6 +124,9 @@ impl HttpError {
             HttpError::UnsupportedHttpVersion(version) => {
                 format!("{version} is not supported, check --version").to_string()
             }
+            HttpError::UnsupportedBodyCompression { description } => {
+                format!("compression {description} is not supported, use gzip or deflate")
+            }
             HttpError::UnsupportedContentEncoding { description } => {
                 format!("compression {description} is not supported").to_string()
             }
@@ -32,8 +32,12 @@ pub const CONTENT_TYPE: &str = "Content-Type";
 pub const EXPECT: &str = "Expect";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Location>
 pub const LOCATION: &str = "Location";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Retry-After>
+pub const RETRY_AFTER: &str = "Retry-After";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Set-Cookie>
 pub const SET_COOKIE: &str = "Set-Cookie";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Transfer-Encoding>
+pub const TRANSFER_ENCODING: &str = "Transfer-Encoding";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/User-Agent>
 pub const USER_AGENT: &str = "User-Agent";
 
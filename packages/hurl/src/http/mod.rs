@@ -21,17 +21,19 @@
 //! returned by an HTTP exchange are exposed.
 pub use self::call::Call;
 pub use self::certificate::Certificate;
-pub(crate) use self::client::Client;
+pub(crate) use self::client::{parse_cert_password, Client};
 pub use self::cookie::{CookieAttribute, ResponseCookie};
 pub use self::core::Cookie;
 pub(crate) use self::core::{Param, RequestCookie};
 pub use self::curl_cmd::CurlCmd;
 pub(crate) use self::error::HttpError;
 pub use self::header::{
-    Header, HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, COOKIE, EXPECT, USER_AGENT,
+    Header, HeaderVec, ACCEPT_ENCODING, AUTHORIZATION, CONTENT_TYPE, COOKIE, EXPECT, LOCATION,
+    RETRY_AFTER, TRANSFER_ENCODING, USER_AGENT,
 };
-pub(crate) use self::options::{ClientOptions, Verbosity};
+pub(crate) use self::options::{proxy_secrets, ClientOptions, Verbosity};
 pub use self::request::{IpResolve, Request, RequestedHttpVersion};
+pub use self::request_encoding::BodyCompression;
 pub(crate) use self::request_spec::{Body, FileParam, Method, MultipartParam, RequestSpec};
 pub use self::response::{HttpVersion, Response};
 #[cfg(test)]
@@ -54,6 +56,7 @@ mod headers_helper;
 mod mimetype;
 mod options;
 mod request;
+mod request_encoding;
 mod request_spec;
 mod response;
 mod response_cookie;
@@ -46,6 +46,8 @@ pub enum RequestedHttpVersion {
     Http10,
     Http11,
     Http2,
+    /// HTTP/2 without HTTP/1.1 Upgrade for non-TLS requests (h2c).
+    Http2PriorKnowledge,
     Http3,
 }
 
@@ -56,6 +58,7 @@ impl fmt::Display for RequestedHttpVersion {
             RequestedHttpVersion::Http10 => "HTTP/1.0",
             RequestedHttpVersion::Http11 => "HTTP/1.1",
             RequestedHttpVersion::Http2 => "HTTP/2",
+            RequestedHttpVersion::Http2PriorKnowledge => "HTTP/2 (prior knowledge)",
             RequestedHttpVersion::Http3 => "HTTP/3",
         };
         write!(f, "{value}")
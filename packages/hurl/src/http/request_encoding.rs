@@ -0,0 +1,185 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Compresses request body, setting the `Content-Encoding` request header accordingly.
+use std::io::prelude::*;
+
+use crate::http::header::CONTENT_ENCODING;
+use crate::http::{Body, Header, HttpError, RequestSpec};
+
+/// Compression algorithm applied to a request body before sending it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BodyCompression {
+    /// A format using the Lempel-Ziv coding (LZ77), with a 32-bit CRC.
+    Gzip,
+    /// Using the zlib structure (defined in RFC 1950) with the deflate compression algorithm.
+    Deflate,
+}
+
+impl BodyCompression {
+    /// Returns a body compression from an option value `s`.
+    pub fn parse(s: &str) -> Result<BodyCompression, HttpError> {
+        match s {
+            "gzip" => Ok(BodyCompression::Gzip),
+            "deflate" => Ok(BodyCompression::Deflate),
+            v => Err(HttpError::UnsupportedBodyCompression {
+                description: v.to_string(),
+            }),
+        }
+    }
+
+    /// Returns the `Content-Encoding` header value of this compression.
+    pub fn header_value(&self) -> &'static str {
+        match self {
+            BodyCompression::Gzip => "gzip",
+            BodyCompression::Deflate => "deflate",
+        }
+    }
+
+    /// Compresses `data` bytes.
+    pub fn encode(&self, data: &[u8]) -> Result<Vec<u8>, HttpError> {
+        match self {
+            BodyCompression::Gzip => compress_gzip(data),
+            BodyCompression::Deflate => compress_zlib(data),
+        }
+    }
+}
+
+impl RequestSpec {
+    /// Compresses the body of this request with `compression`.
+    ///
+    /// A `Content-Encoding` header is added, unless the user has already provided one. Requests
+    /// without body (or using form params and multipart form data) are left untouched.
+    pub fn compress_body(&mut self, compression: BodyCompression) -> Result<(), HttpError> {
+        // A streamed body has to be read in memory to be compressed.
+        let data = self.body.read_bytes()?;
+        if data.is_empty() {
+            return Ok(());
+        }
+        self.body = Body::Binary(compression.encode(&data)?);
+        if !self.headers.contains_key(CONTENT_ENCODING) {
+            let header = Header::new(CONTENT_ENCODING, compression.header_value());
+            self.headers.push(header);
+        }
+        Ok(())
+    }
+}
+
+/// Compresses `data` with GZip.
+fn compress_gzip(data: &[u8]) -> Result<Vec<u8>, HttpError> {
+    let error = |_| HttpError::CouldNotCompressRequest {
+        description: "gzip".to_string(),
+    };
+    let mut encoder = libflate::gzip::Encoder::new(Vec::new()).map_err(error)?;
+    encoder.write_all(data).map_err(error)?;
+    encoder.finish().into_result().map_err(error)
+}
+
+/// Compresses `data` with Zlib.
+fn compress_zlib(data: &[u8]) -> Result<Vec<u8>, HttpError> {
+    let error = |_| HttpError::CouldNotCompressRequest {
+        description: "zlib".to_string(),
+    };
+    let mut encoder = libflate::zlib::Encoder::new(Vec::new()).map_err(error)?;
+    encoder.write_all(data).map_err(error)?;
+    encoder.finish().into_result().map_err(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::http::response_decoding::ContentEncoding;
+    use crate::http::HeaderVec;
+
+    #[test]
+    fn test_parse_body_compression() {
+        assert_eq!(
+            BodyCompression::parse("gzip").unwrap(),
+            BodyCompression::Gzip
+        );
+        assert_eq!(
+            BodyCompression::parse("deflate").unwrap(),
+            BodyCompression::Deflate
+        );
+        assert_eq!(
+            BodyCompression::parse("br").err().unwrap(),
+            HttpError::UnsupportedBodyCompression {
+                description: "br".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_compress_body() {
+        let data = b"{\"name\": \"Bob\", \"age\": 42}";
+        for (compression, encoding) in [
+            (BodyCompression::Gzip, ContentEncoding::Gzip),
+            (BodyCompression::Deflate, ContentEncoding::Deflate),
+        ] {
+            let mut request = RequestSpec {
+                body: Body::Text(String::from_utf8(data.to_vec()).unwrap()),
+                ..Default::default()
+            };
+            request.compress_body(compression).unwrap();
+            assert_eq!(
+                encoding
+                    .decode(&request.body.read_bytes().unwrap())
+                    .unwrap(),
+                data
+            );
+            assert_eq!(
+                request.headers.get(CONTENT_ENCODING).unwrap().value,
+                compression.header_value()
+            );
+        }
+    }
+
+    #[test]
+    fn test_compress_body_keeps_user_header() {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("content-encoding", "gzip"));
+        let mut request = RequestSpec {
+            headers,
+            body: Body::Text("Hello".to_string()),
+            ..Default::default()
+        };
+        request.compress_body(BodyCompression::Gzip).unwrap();
+        assert_eq!(request.headers.get_all(CONTENT_ENCODING).len(), 1);
+
+        // Empty body is not compressed.
+        let mut request = RequestSpec::default();
+        request.compress_body(BodyCompression::Gzip).unwrap();
+        assert_eq!(request.body, Body::Binary(vec![]));
+        assert!(!request.headers.contains_key(CONTENT_ENCODING));
+    }
+
+    #[test]
+    fn test_compress_body_unreadable_stream() {
+        let path = PathBuf::from("does_not_exist.bin");
+        let mut request = RequestSpec {
+            body: Body::Stream(path.clone()),
+            ..Default::default()
+        };
+        assert_eq!(
+            request.compress_body(BodyCompression::Gzip).err().unwrap(),
+            HttpError::FileReadAccess { path }
+        );
+    }
+}
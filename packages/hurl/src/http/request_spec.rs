@@ -16,9 +16,10 @@
  *
  */
 use core::fmt;
+use std::path::PathBuf;
 
 use crate::http::header::HeaderVec;
-use crate::http::{Param, RequestCookie, Url};
+use crate::http::{ClientOptions, HttpError, Param, RequestCookie, RequestedHttpVersion, Url};
 
 /// Represents the HTTP request asked to be executed by our user (different from the runtime
 /// executed HTTP request [`crate::http::Request`].
@@ -55,6 +56,27 @@ impl Default for RequestSpec {
     }
 }
 
+impl RequestSpec {
+    /// Returns `true` if this request sends data: a body, form params or multipart params.
+    pub fn has_body_data(&self) -> bool {
+        !self.body.is_empty() || !self.form.is_empty() || !self.multipart.is_empty()
+    }
+
+    /// Returns `true` if this request data is sent with chunked transfer encoding, given client
+    /// `options`.
+    ///
+    /// Chunked transfer encoding only exists in HTTP/1.1: it's used when HTTP/1.1 is requested, or
+    /// with the default HTTP version on a non TLS URL (libcurl negotiates HTTP/2 only over TLS).
+    pub(crate) fn is_chunked(&self, options: &ClientOptions) -> bool {
+        let http11 = match options.http_version {
+            RequestedHttpVersion::Http11 => true,
+            RequestedHttpVersion::Default => self.url.scheme() == "http",
+            _ => false,
+        };
+        options.chunked && http11 && self.has_body_data()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Method(pub String);
 
@@ -70,6 +92,8 @@ pub struct FileParam {
     pub filename: String,
     pub data: Vec<u8>,
     pub content_type: String,
+    /// Filename sent in the part, overriding `filename` when set.
+    pub part_filename: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -77,14 +101,46 @@ pub enum Body {
     Text(String),
     Binary(Vec<u8>),
     File(Vec<u8>, String),
+    /// A file body (given by its resolved path) that is not read in memory but streamed when the
+    /// request is sent.
+    Stream(PathBuf),
 }
 
 impl Body {
-    pub fn bytes(&self) -> Vec<u8> {
+    /// Returns the bytes of this body, reading the whole file of a streamed body.
+    ///
+    /// To send a body, prefer [`Body::data`] that doesn't read streamed bodies in memory.
+    pub fn read_bytes(&self) -> Result<Vec<u8>, HttpError> {
+        match self {
+            Body::Text(s) => Ok(s.as_bytes().to_vec()),
+            Body::Binary(bs) => Ok(bs.clone()),
+            Body::File(bs, _) => Ok(bs.clone()),
+            Body::Stream(path) => {
+                std::fs::read(path).map_err(|_| HttpError::FileReadAccess { path: path.clone() })
+            }
+        }
+    }
+
+    /// Returns the bytes of this body if they're in memory, or `None` for a streamed body.
+    pub fn data(&self) -> Option<&[u8]> {
         match self {
-            Body::Text(s) => s.as_bytes().to_vec(),
-            Body::Binary(bs) => bs.clone(),
-            Body::File(bs, _) => bs.clone(),
+            Body::Text(s) => Some(s.as_bytes()),
+            Body::Binary(bs) | Body::File(bs, _) => Some(bs),
+            Body::Stream(_) => None,
+        }
+    }
+
+    /// Returns `true` if this body has no data.
+    ///
+    /// A streamed body is not read: it's only empty if it's a regular empty file. Other files
+    /// (like pipes) and files that can't be accessed are considered as non-empty, any error is
+    /// raised when the body is sent.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Body::Stream(path) => {
+                std::fs::metadata(path).is_ok_and(|m| m.is_file() && m.len() == 0)
+            }
+            _ => self.data().is_some_and(|data| data.is_empty()),
         }
     }
 }
@@ -110,6 +166,82 @@ impl fmt::Display for FileParam {
             f,
             "{}: file,{}; {}",
             self.name, self.filename, self.content_type
-        )
+        )?;
+        if let Some(part_filename) = &self.part_filename {
+            write!(f, "; filename={part_filename}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_body_is_empty() {
+        assert!(Body::Text(String::new()).is_empty());
+        assert!(!Body::Binary(vec![0]).is_empty());
+
+        let path = std::env::temp_dir().join(format!("hurl-{}.bin", uuid::Uuid::new_v4()));
+        std::fs::write(&path, b"").unwrap();
+        let body = Body::Stream(path.clone());
+        assert!(body.is_empty());
+        assert_eq!(body.data(), None);
+        assert_eq!(body.read_bytes().unwrap(), Vec::<u8>::new());
+
+        // A file that can't be accessed is not empty, the error is raised when it's read.
+        std::fs::remove_file(&path).unwrap();
+        assert!(!body.is_empty());
+        assert_eq!(
+            body.read_bytes().unwrap_err(),
+            HttpError::FileReadAccess { path }
+        );
+    }
+
+    #[test]
+    fn test_is_chunked() {
+        let request = |url: &str| RequestSpec {
+            method: Method("POST".to_string()),
+            url: Url::from_str(url).unwrap(),
+            body: Body::Text("Hello World!".to_string()),
+            ..Default::default()
+        };
+        let options = |http_version: RequestedHttpVersion| ClientOptions {
+            chunked: true,
+            http_version,
+            ..Default::default()
+        };
+
+        let http = request("http://localhost:8000/hello");
+        let https = request("https://localhost:8000/hello");
+        assert!(http.is_chunked(&options(RequestedHttpVersion::Default)));
+        assert!(http.is_chunked(&options(RequestedHttpVersion::Http11)));
+        assert!(https.is_chunked(&options(RequestedHttpVersion::Http11)));
+
+        // Chunked transfer encoding is not used with other HTTP versions.
+        assert!(!https.is_chunked(&options(RequestedHttpVersion::Default)));
+        assert!(!http.is_chunked(&options(RequestedHttpVersion::Http10)));
+        assert!(!http.is_chunked(&options(RequestedHttpVersion::Http2)));
+        assert!(!https.is_chunked(&options(RequestedHttpVersion::Http2)));
+        assert!(!https.is_chunked(&options(RequestedHttpVersion::Http3)));
+
+        // Requests without data are never chunked.
+        let get = RequestSpec {
+            url: Url::from_str("http://localhost:8000/hello").unwrap(),
+            ..Default::default()
+        };
+        assert!(!get.is_chunked(&options(RequestedHttpVersion::Http11)));
+        assert!(!http.is_chunked(&ClientOptions::default()));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_body_stream_not_regular_file_is_not_empty() {
+        // The size of a pipe or a device is not known before reading it.
+        let body = Body::Stream(PathBuf::from("/dev/null"));
+        assert!(!body.is_empty());
     }
 }
@@ -50,11 +50,14 @@ impl Url {
             .collect()
     }
 
-    pub fn host(&self) -> String {
-        self.inner
-            .host()
-            .expect("HTTP and HTTPS URL must have a domain")
-            .to_string()
+    /// Returns the scheme of this URL, lower-cased, as an ASCII string without the ':' delimiter.
+    pub fn scheme(&self) -> String {
+        self.inner.scheme().to_string()
+    }
+
+    /// Returns the host of this URL, `None` if the URL has no host.
+    pub fn host(&self) -> Option<String> {
+        self.inner.host().map(|h| h.to_string())
     }
 
     pub fn domain(&self) -> Option<String> {
@@ -96,6 +99,23 @@ fn scheme(url: &str) -> Option<String> {
     }
 }
 
+/// Removes the zone identifier of an IPv6 literal host from `url` (ex: `http://[fe80::1%25eth0]:8000`
+/// is returned as `http://[fe80::1]:8000`).
+///
+/// See <https://datatracker.ietf.org/doc/html/rfc6874>
+fn strip_zone_id(url: &str) -> String {
+    let Some(start) = url.find("://[").map(|index| index + 4) else {
+        return url.to_string();
+    };
+    let Some(end) = url[start..].find(']').map(|index| index + start) else {
+        return url.to_string();
+    };
+    match url[start..end].find("%25") {
+        Some(index) => format!("{}{}", &url[..start + index], &url[end..]),
+        None => url.to_string(),
+    }
+}
+
 impl FromStr for Url {
     type Err = HttpError;
 
@@ -119,7 +139,12 @@ impl FromStr for Url {
         }
 
         let raw = value.to_string();
-        let inner = url::Url::parse(value)
+        // IPv6 zone identifiers are not supported by the url crate, but are supported by libcurl:
+        // we keep the raw URL as is and parse the URL without the zone identifier.
+        let value = strip_zone_id(value);
+        // HTTP and HTTPS URLs without host (ex: `http://:8000`) are rejected by the parser with an
+        // "empty host" error.
+        let inner = url::Url::parse(&value)
             .map_err(|e| HttpError::InvalidUrl(raw.to_string(), e.to_string()))?;
         Ok(Url { raw, inner })
     }
@@ -136,7 +161,7 @@ mod tests {
     use std::str::FromStr;
 
     use super::Url;
-    use crate::http::url::scheme;
+    use crate::http::url::{scheme, strip_zone_id};
     use crate::http::{HttpError, Param};
 
     #[test]
@@ -215,6 +240,35 @@ mod tests {
                 "Only <http://> and <https://> schemes are supported".to_string()
             )
         );
+        assert_eq!(
+            Url::from_str("http://:8000/hello").err().unwrap(),
+            HttpError::InvalidUrl("http://:8000/hello".to_string(), "empty host".to_string())
+        );
+    }
+
+    #[test]
+    fn test_host() {
+        let url: Url = "http://localhost:8000/hello".parse().unwrap();
+        assert_eq!(url.host(), Some("localhost".to_string()));
+        let url: Url = "http://[::1]:8000/hello".parse().unwrap();
+        assert_eq!(url.host(), Some("[::1]".to_string()));
+        assert_eq!(url.domain(), None);
+        let url: Url = "http://[fe80::1%25eth0]:8000/hello".parse().unwrap();
+        assert_eq!(url.host(), Some("[fe80::1]".to_string()));
+        assert_eq!(url.raw(), "http://[fe80::1%25eth0]:8000/hello");
+    }
+
+    #[test]
+    fn test_strip_zone_id() {
+        assert_eq!(
+            strip_zone_id("http://[fe80::1%25eth0]:8000/hello"),
+            "http://[fe80::1]:8000/hello"
+        );
+        assert_eq!(strip_zone_id("http://[::1]/a%25b"), "http://[::1]/a%25b");
+        assert_eq!(
+            strip_zone_id("http://localhost:8000/a%25b"),
+            "http://localhost:8000/a%25b"
+        );
     }
 
     #[test]
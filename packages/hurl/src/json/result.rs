@@ -31,7 +31,7 @@ use crate::http::{
     Call, Certificate, Cookie, Header, HttpVersion, Param, Request, RequestCookie, Response,
     ResponseCookie, Timings,
 };
-use crate::runner::{AssertResult, CaptureResult, EntryResult, HurlResult};
+use crate::runner::{AssertResult, CaptureResult, EntryResult, HurlResult, RunSummary};
 
 impl HurlResult {
     /// Serializes an [`HurlResult`] to a JSON representation.
@@ -67,6 +67,9 @@ struct HurlResultJson {
     success: bool,
     time: u64,
     cookies: Vec<CookieJson>,
+    // Reports written by previous versions have no summary.
+    #[serde(default)]
+    summary: RunSummary,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -78,6 +81,8 @@ struct EntryResultJson {
     asserts: Vec<AssertJson>,
     time: u64,
     curl_cmd: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    skipped: bool,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -216,6 +221,7 @@ impl HurlResultJson {
             success: result.success,
             time: result.duration.as_millis() as u64,
             cookies,
+            summary: result.summary(),
         })
     }
 }
@@ -250,6 +256,7 @@ impl EntryResultJson {
             asserts,
             time: entry.transfer_duration.as_millis() as u64,
             curl_cmd: entry.curl_cmd.to_string(),
+            skipped: entry.skipped,
         })
     }
 }
@@ -348,7 +355,7 @@ impl ResponseJson {
                 // we want the `body` field to reference the relative path of a response compared
                 // to `report.json`.
                 let file = write_response(response, response_dir)?;
-                let parent = response_dir.components().last().unwrap();
+                let parent = response_dir.components().next_back().unwrap();
                 let parent: &Path = parent.as_ref();
                 Some(format!("{}/{}", parent.display(), file.display()))
             }
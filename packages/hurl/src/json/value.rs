@@ -87,13 +87,15 @@ impl Number {
     /// Serializes a number to JSON.
     ///
     /// Numbers that are representable in JSON use the number JSON type, while big number
-    /// will be serialized as string.
+    /// will be serialized as string. Non-finite floats (NaN, infinity) have no JSON number
+    /// representation and are serialized as string.
     pub fn to_json(&self) -> serde_json::Value {
         match self {
             Number::Integer(v) => serde_json::Value::Number(serde_json::Number::from(*v)),
-            Number::Float(f) => {
-                serde_json::Value::Number(serde_json::Number::from_f64(*f).unwrap())
-            }
+            Number::Float(f) => match serde_json::Number::from_f64(*f) {
+                Some(number) => serde_json::Value::Number(number),
+                None => serde_json::Value::String(f.to_string()),
+            },
             Number::BigInteger(s) => {
                 let number = serde_json::Number::from_str(s).unwrap();
                 serde_json::Value::Number(number)
@@ -101,3 +103,19 @@ impl Number {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::Number;
+
+    #[test]
+    fn test_number_to_json() {
+        assert_eq!(Number::Integer(1).to_json(), serde_json::json!(1));
+        assert_eq!(Number::Float(1.5).to_json(), serde_json::json!(1.5));
+        assert_eq!(Number::Float(f64::NAN).to_json(), serde_json::json!("NaN"));
+        assert_eq!(
+            Number::Float(f64::NEG_INFINITY).to_json(),
+            serde_json::json!("-inf")
+        );
+    }
+}
@@ -139,16 +139,8 @@ pub fn key_name(reader: &mut Reader) -> Result<String, ParseError> {
     // The key must not be empty and must not start with a digit
 
     let first_char = match reader.read() {
-        Some(c) => {
-            if c.is_alphabetic() || c == '_' {
-                c
-            } else {
-                let kind = ParseErrorKind::Expecting("key".to_string());
-                let error = ParseError::new(reader.cursor().pos, false, kind);
-                return Err(error);
-            }
-        }
-        None => {
+        Some(c) if c.is_alphabetic() || c == '_' => c,
+        _ => {
             let kind = ParseErrorKind::Expecting("key".to_string());
             let error = ParseError::new(reader.cursor().pos, false, kind);
             return Err(error);
@@ -106,7 +106,7 @@ fn main() {
     unwrap_or_exit(ret, EXIT_ERROR_UNDEFINED, &base_logger);
 
     if opts.test {
-        let summary = cli::summary(&runs, duration);
+        let summary = cli::summary(&runs, duration, opts.color);
         base_logger.info(summary.as_str());
     }
 
@@ -192,7 +192,7 @@ fn create_junit_report(runs: &[HurlRun], filename: &Path) -> Result<(), CliError
 fn create_tap_report(runs: &[HurlRun], filename: &Path) -> Result<(), CliError> {
     let testcases = runs
         .iter()
-        .map(|r| tap::Testcase::from(&r.hurl_result, &r.filename))
+        .map(|r| tap::Testcase::from(&r.hurl_result, &r.content, &r.filename))
         .collect::<Vec<_>>();
     tap::write_report(filename, &testcases)?;
     Ok(())
@@ -36,8 +36,8 @@ pub fn write_last_body(
     stdout: &mut Stdout,
     append: bool,
 ) -> Result<(), OutputError> {
-    // Get the last call of the Hurl result.
-    let Some(last_entry) = &hurl_result.entries.last() else {
+    // Get the last call of the Hurl result, skipped entries are ignored.
+    let Some(last_entry) = &hurl_result.entries.iter().rev().find(|e| !e.skipped) else {
         return Ok(());
     };
     let Some(call) = &last_entry.calls.last() else {
@@ -169,6 +169,7 @@ mod tests {
                     transfer_duration: Duration::from_millis(0),
                     compressed: false,
                     curl_cmd: CurlCmd::default(),
+                    skipped: false,
                 },
                 EntryResult {
                     entry_index: 2,
@@ -189,6 +190,7 @@ mod tests {
                     transfer_duration: Duration::from_millis(0),
                     compressed: false,
                     curl_cmd: CurlCmd::default(),
+                    skipped: false,
                 },
                 EntryResult {
                     entry_index: 3,
@@ -217,6 +219,7 @@ mod tests {
                     transfer_duration: Duration::from_millis(0),
                     compressed: false,
                     curl_cmd: CurlCmd::default(),
+                    skipped: false,
                 },
             ],
             duration: Duration::from_millis(100),
@@ -130,7 +130,7 @@ impl Worker {
                 &mut logger,
             );
 
-            if result.success && result.entries.last().is_none() {
+            if result.success && result.entries.iter().all(|e| e.skipped) {
                 logger.warning(&format!(
                     "No entry have been executed for file {}",
                     job.filename
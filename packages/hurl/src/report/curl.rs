@@ -39,6 +39,7 @@ pub fn write_curl(hurl_results: &[&HurlResult], filename: &Path) -> Result<(), R
     let mut cmds = hurl_results
         .iter()
         .flat_map(|h| &h.entries)
+        .filter(|e| !e.skipped)
         .map(|e| e.curl_cmd.to_string())
         .collect::<Vec<_>>()
         .join("\n");
@@ -77,6 +77,11 @@ impl Testcase {
 /// Returns an HTML view of an `entry` information as HTML (title, `entry_index` and captures).
 fn get_entry_html(entry: &EntryResult, entry_index: usize, secrets: &[&str]) -> String {
     let mut text = String::new();
+    // A skipped entry has not been run: it has no command, captures or calls.
+    if entry.skipped {
+        text.push_str(&format!("<summary>Entry {entry_index} (skipped)</summary>"));
+        return text;
+    }
     text.push_str(&format!("<summary>Entry {entry_index}</summary>"));
 
     let cmd = entry.curl_cmd.to_string();
@@ -89,7 +94,7 @@ fn get_entry_html(entry: &EntryResult, entry_index: usize, secrets: &[&str]) ->
             .iter()
             .map(|c| (&c.name, c.value.to_string()))
             .collect::<Vec<(&String, String)>>();
-        values.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+        values.sort_by_key(|a| a.0.to_lowercase());
         let table = new_table("Captures", &values, secrets);
         text.push_str(&table);
     }
@@ -148,7 +153,7 @@ fn get_call_html(
         .iter()
         .map(|h| (h.name.as_str(), h.value.as_str()))
         .collect::<Vec<(&str, &str)>>();
-    values.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+    values.sort_by_key(|a| a.0.to_lowercase());
     let table = new_table("Request Headers", &values, secrets);
     text.push_str(&table);
 
@@ -158,7 +163,7 @@ fn get_call_html(
         .iter()
         .map(|h| (h.name.as_str(), h.value.as_str()))
         .collect::<Vec<(&str, &str)>>();
-    values.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+    values.sort_by_key(|a| a.0.to_lowercase());
     let table = new_table("Response Headers", &values, secrets);
     text.push_str(&table);
 
@@ -33,8 +33,8 @@
 //! <?xml version="1.0"?>
 //! <testsuites>
 //!   <testsuite>
-//!     <testcase id="tests/hello.hurl" name="tests/hello.hurl" time="0.029"/>
-//!     <testcase id="tests/error_assert_status.hurl" name="tests/error_assert_status.hurl" time="0.008">
+//!     <testcase id="tests/hello.hurl" name="tests/hello.hurl" time="0.029" assertions="3"/>
+//!     <testcase id="tests/error_assert_status.hurl" name="tests/error_assert_status.hurl" time="0.008" assertions="1">
 //!       <failure>Assert Status
 //!   --> tests/error_assert_status.hurl:2:10
 //!    |
@@ -42,7 +42,7 @@
 //!    |          ^^^ actual value is <404>
 //!    |</failure>
 //!     </testcase>
-//!     <testcase id="tests/error_body_json.hurl" time="0.000">
+//!     <testcase id="tests/error_body_json.hurl" time="0.000" assertions="0">
 //!       <error>Undefined Variable
 //!   --> tests/error_body_json.hurl:3:18
 //!    |
@@ -190,6 +190,7 @@ mod tests {
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
                 curl_cmd: CurlCmd::default(),
+                skipped: false,
             }],
             duration: Duration::from_millis(230),
             success: true,
@@ -217,6 +218,7 @@ mod tests {
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
                 curl_cmd: CurlCmd::default(),
+                skipped: false,
             }],
             duration: Duration::from_millis(230),
             success: true,
@@ -232,8 +234,8 @@ mod tests {
             doc.to_string().unwrap(),
             "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
             <testsuite tests=\"3\" errors=\"1\" failures=\"1\">\
-                <testcase id=\"test.hurl\" name=\"test.hurl\" time=\"0.230\" />\
-                <testcase id=\"test.hurl\" name=\"test.hurl\" time=\"0.230\">\
+                <testcase id=\"test.hurl\" name=\"test.hurl\" time=\"0.230\" assertions=\"0\" />\
+                <testcase id=\"test.hurl\" name=\"test.hurl\" time=\"0.230\" assertions=\"0\">\
                     <failure>Assert status code\n  \
                     --&gt; test.hurl:2:10\n   \
                       |\n   \
@@ -243,7 +245,7 @@ mod tests {
                       |\
                     </failure>\
                 </testcase>\
-                <testcase id=\"test.hurl\" name=\"test.hurl\" time=\"0.230\">\
+                <testcase id=\"test.hurl\" name=\"test.hurl\" time=\"0.230\" assertions=\"0\">\
                     <error>HTTP connection\n  --&gt; test.hurl:1:5\n   |\n 1 | GET http://localhost:8000/not_found\n   |     ^^^^^^^^^^^^^^ (6) Could not resolve host: unknown\n   |\
                     </error>\
                 </testcase>\
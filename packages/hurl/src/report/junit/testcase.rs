@@ -26,6 +26,7 @@ pub struct Testcase {
     id: String,
     name: String,
     time_in_ms: u128,
+    assertions: usize,
     failures: Vec<String>,
     errors: Vec<String>,
 }
@@ -35,7 +36,9 @@ impl Testcase {
     pub fn from(hurl_result: &HurlResult, content: &str, filename: &Input) -> Testcase {
         let id = filename.to_string();
         let name = filename.to_string();
-        let time_in_ms = hurl_result.duration.as_millis();
+        let summary = hurl_result.summary();
+        let time_in_ms = summary.duration.as_millis();
+        let assertions = summary.asserts_count;
         let mut failures = vec![];
         let mut errors = vec![];
 
@@ -56,6 +59,7 @@ impl Testcase {
             id,
             name,
             time_in_ms,
+            assertions,
             failures,
             errors,
         }
@@ -68,7 +72,8 @@ impl Testcase {
         let mut element = Element::new("testcase")
             .attr("id", &self.id)
             .attr("name", &self.name)
-            .attr("time", &time_in_seconds);
+            .attr("time", &time_in_seconds)
+            .attr("assertions", &self.assertions.to_string());
 
         for failure in self.failures.iter() {
             element = element.add_child(Element::new("failure").text(failure));
@@ -118,7 +123,7 @@ mod test {
         let doc = XmlDocument::new(element);
         assert_eq!(
             doc.to_string().unwrap(),
-            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl" name="test.hurl" time="0.230" />"#
+            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl" name="test.hurl" time="0.230" assertions="0" />"#
         );
     }
 
@@ -145,6 +150,7 @@ HTTP/1.0 200
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
                 curl_cmd: CurlCmd::default(),
+                skipped: false,
             }],
             duration: Duration::from_millis(230),
             success: true,
@@ -156,7 +162,7 @@ HTTP/1.0 200
         let doc = XmlDocument::new(element);
         assert_eq!(
             doc.to_string().unwrap(),
-            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl" name="test.hurl" time="0.230"><failure>Assert status code
+            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl" name="test.hurl" time="0.230" assertions="0"><failure>Assert status code
   --&gt; test.hurl:2:10
    |
    | GET http://localhost:8000/not_found
@@ -188,6 +194,7 @@ HTTP/1.0 200
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
                 curl_cmd: CurlCmd::default(),
+                skipped: false,
             }],
             duration: Duration::from_millis(230),
             success: true,
@@ -198,7 +205,7 @@ HTTP/1.0 200
         let doc = XmlDocument::new(element);
         assert_eq!(
             doc.to_string().unwrap(),
-            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl" name="test.hurl" time="0.230"><error>HTTP connection
+            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl" name="test.hurl" time="0.230" assertions="0"><error>HTTP connection
   --&gt; test.hurl:1:5
    |
  1 | GET http://unknown
@@ -206,4 +213,78 @@ HTTP/1.0 200
    |</error></testcase>"#
         );
     }
+
+    #[test]
+    fn test_create_testcase_multiple_failures() {
+        let content = r#"GET http://localhost:8000/not_found
+HTTP 404
+[Asserts]
+header "Content-Type" == "application/json"
+body == "Not Found"
+"#;
+        let filename = Input::new("test.hurl");
+        let hurl_result = HurlResult {
+            entries: vec![EntryResult {
+                entry_index: 1,
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 35)),
+                calls: vec![],
+                captures: vec![],
+                asserts: vec![],
+                errors: vec![
+                    RunnerError::new(
+                        SourceInfo::new(Pos::new(4, 0), Pos::new(4, 0)),
+                        RunnerErrorKind::AssertFailure {
+                            actual: "string <text/html>".to_string(),
+                            expected: "string <application/json>".to_string(),
+                            type_mismatch: false,
+                        },
+                        true,
+                    ),
+                    RunnerError::new(
+                        SourceInfo::new(Pos::new(5, 0), Pos::new(5, 0)),
+                        RunnerErrorKind::AssertFailure {
+                            actual: "string <>".to_string(),
+                            expected: "string <Not Found>".to_string(),
+                            type_mismatch: false,
+                        },
+                        true,
+                    ),
+                ],
+                transfer_duration: Duration::from_millis(0),
+                compressed: false,
+                curl_cmd: CurlCmd::default(),
+                skipped: false,
+            }],
+            duration: Duration::from_millis(15),
+            success: false,
+            cookies: vec![],
+            timestamp: 1,
+        };
+
+        let testcase = Testcase::from(&hurl_result, content, &filename);
+        assert_eq!(testcase.get_fail_count(), 2);
+        assert_eq!(testcase.get_error_count(), 0);
+
+        let doc = XmlDocument::new(testcase.to_xml());
+        assert_eq!(
+            doc.to_string().unwrap(),
+            r#"<?xml version="1.0" encoding="UTF-8"?><testcase id="test.hurl" name="test.hurl" time="0.015" assertions="0"><failure>Assert failure
+  --&gt; test.hurl:4:0
+   |
+   | GET http://localhost:8000/not_found
+   | ...
+ 4 | header "Content-Type" == "application/json"
+   |   actual:   string &lt;text/html&gt;
+   |   expected: string &lt;application/json&gt;
+   |</failure><failure>Assert failure
+  --&gt; test.hurl:5:0
+   |
+   | GET http://localhost:8000/not_found
+   | ...
+ 5 | body == "Not Found"
+   |   actual:   string &lt;&gt;
+   |   expected: string &lt;Not Found&gt;
+   |</failure></testcase>"#
+        );
+    }
 }
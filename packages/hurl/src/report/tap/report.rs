@@ -78,6 +78,9 @@ fn write_tap_file(filename: &Path, testcases: &[&Testcase]) -> Result<(), Report
         let number = i + 1;
         let description = &testcase.description;
         s.push_str(format!("{state} {number} - {description}\n").as_str());
+        if let Some(message) = &testcase.message {
+            s.push_str(&diagnostic_block(message));
+        }
     }
     match file.write_all(s.as_bytes()) {
         Ok(_) => Ok(()),
@@ -89,6 +92,28 @@ fn write_tap_file(filename: &Path, testcases: &[&Testcase]) -> Result<(), Report
     }
 }
 
+/// Returns a YAML diagnostic block for a failed testcase `message`.
+///
+/// ```text
+///   ---
+///   message: |
+///     error: Assert status code
+///     ...
+///   ...
+/// ```
+fn diagnostic_block(message: &str) -> String {
+    let mut s = "  ---\n  message: |\n".to_string();
+    for line in message.lines() {
+        if line.is_empty() {
+            s.push('\n');
+        } else {
+            s.push_str(&format!("    {line}\n"));
+        }
+    }
+    s.push_str("  ...\n");
+    s
+}
+
 /// Parse Tap report file
 fn parse_tap_file(filename: &Path) -> Result<Vec<Testcase>, ReportError> {
     if !filename.exists() {
@@ -109,7 +134,7 @@ fn parse_tap_file(filename: &Path) -> Result<Vec<Testcase>, ReportError> {
 
 /// Parse Tap report
 fn parse_tap_report(s: &str) -> Result<Vec<Testcase>, ReportError> {
-    let mut testcases = vec![];
+    let mut testcases: Vec<Testcase> = vec![];
     let mut lines: Vec<&str> = s.lines().collect::<Vec<&str>>();
     if !lines.is_empty() {
         let mut header = lines.remove(0);
@@ -123,17 +148,46 @@ fn parse_tap_report(s: &str) -> Result<Vec<Testcase>, ReportError> {
                 "Invalid TAP Header <{header}>"
             )));
         }
-        for line in lines {
+        let mut lines = lines.into_iter();
+        while let Some(line) = lines.next() {
             let line = line.trim();
-            if !line.is_empty() {
-                let testcase = Testcase::parse(line)?;
-                testcases.push(testcase);
+            if line.is_empty() {
+                continue;
             }
+            if line == "---" {
+                // A YAML diagnostic block attached to the previous testcase.
+                let message = parse_diagnostic_block(&mut lines);
+                if let Some(testcase) = testcases.last_mut() {
+                    testcase.message = message;
+                }
+                continue;
+            }
+            let testcase = Testcase::parse(line)?;
+            testcases.push(testcase);
         }
     }
     Ok(testcases)
 }
 
+/// Parses the lines of a YAML diagnostic block, up to the `...` end marker, and returns its
+/// message if any.
+fn parse_diagnostic_block<'a>(lines: &mut impl Iterator<Item = &'a str>) -> Option<String> {
+    let mut message: Option<Vec<&str>> = None;
+    for line in lines.by_ref() {
+        if line.trim() == "..." {
+            break;
+        }
+        if line.trim() == "message: |" {
+            message = Some(vec![]);
+            continue;
+        }
+        if let Some(message) = message.as_mut() {
+            message.push(line.strip_prefix("    ").unwrap_or(line.trim()));
+        }
+    }
+    message.map(|lines| lines.join("\n").trim_end().to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,15 +205,18 @@ not ok 3 - tests_ok/test.3.hurl
             vec![
                 Testcase {
                     description: "tests_ok/test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    message: None,
                 },
                 Testcase {
                     description: "tests_ok/test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    message: None,
                 },
                 Testcase {
                     description: "tests_ok/test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    message: None,
                 }
             ]
         );
@@ -179,15 +236,18 @@ not ok 3 - tests_ok/test.3.hurl
             vec![
                 Testcase {
                     description: "tests_ok/test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    message: None,
                 },
                 Testcase {
                     description: "tests_ok/test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    message: None,
                 },
                 Testcase {
                     description: "tests_ok/test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    message: None,
                 }
             ]
         );
@@ -205,23 +265,28 @@ ok 5 - test.5.hurl
             vec![
                 Testcase {
                     description: "test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    message: None,
                 },
                 Testcase {
                     description: "test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    message: None,
                 },
                 Testcase {
                     description: "test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    message: None,
                 },
                 Testcase {
                     description: "test.4.hurl".to_string(),
-                    success: false
+                    success: false,
+                    message: None,
                 },
                 Testcase {
                     description: "test.5.hurl".to_string(),
-                    success: true
+                    success: true,
+                    message: None,
                 }
             ]
         );
@@ -236,4 +301,42 @@ not ok 3 - test.3.hurl
 "#;
         assert!(parse_tap_report(s).is_err());
     }
+
+    #[test]
+    fn test_diagnostic_block_round_trip() {
+        let testcases = [
+            Testcase {
+                description: "test.1.hurl".to_string(),
+                success: true,
+                message: None,
+            },
+            Testcase {
+                description: "test.2.hurl".to_string(),
+                success: false,
+                message: Some(
+                    "error: Assert status code\n  --> test.2.hurl:2:6\n\n  actual:   int <404>"
+                        .to_string(),
+                ),
+            },
+        ];
+        let s = format!(
+            "1..2\nok 1 - test.1.hurl\nnot ok 2 - test.2.hurl\n{}",
+            diagnostic_block(testcases[1].message.as_ref().unwrap())
+        );
+        assert_eq!(
+            s,
+            r#"1..2
+ok 1 - test.1.hurl
+not ok 2 - test.2.hurl
+  ---
+  message: |
+    error: Assert status code
+      --> test.2.hurl:2:6
+
+      actual:   int <404>
+  ...
+"#
+        );
+        assert_eq!(parse_tap_report(&s).unwrap(), testcases);
+    }
 }
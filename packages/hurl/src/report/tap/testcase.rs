@@ -15,6 +15,7 @@
  * limitations under the License.
  *
  */
+use hurl_core::error::{DisplaySourceError, OutputFormat};
 use hurl_core::input::Input;
 
 use crate::report::ReportError;
@@ -24,16 +25,36 @@ use crate::runner::HurlResult;
 pub struct Testcase {
     pub(crate) description: String,
     pub(crate) success: bool,
+    /// Errors of a failed testcase, written as a YAML diagnostic block.
+    pub(crate) message: Option<String>,
 }
 
 impl Testcase {
     /// Creates an Tap &lt;testcase&gt; from an [`HurlResult`].
-    pub fn from(hurl_result: &HurlResult, filename: &Input) -> Testcase {
+    pub fn from(hurl_result: &HurlResult, content: &str, filename: &Input) -> Testcase {
         let description = filename.to_string();
-        let success = hurl_result.errors().is_empty();
+        let errors = hurl_result.errors();
+        let success = errors.is_empty();
+        let message = if success {
+            None
+        } else {
+            let messages = errors
+                .iter()
+                .map(|(error, entry_src_info)| {
+                    error.to_string(
+                        &description,
+                        content,
+                        Some(*entry_src_info),
+                        OutputFormat::Terminal(false),
+                    )
+                })
+                .collect::<Vec<_>>();
+            Some(messages.join("\n\n"))
+        };
         Testcase {
             description,
             success,
+            message,
         }
     }
 
@@ -72,6 +93,7 @@ impl Testcase {
         Ok(Testcase {
             description,
             success,
+            message: None,
         })
     }
 }
@@ -91,7 +113,8 @@ mod tests {
             Testcase::parse("ok 1 - tests_ok/test.1.hurl").unwrap(),
             Testcase {
                 description: "tests_ok/test.1.hurl".to_string(),
-                success: true
+                success: true,
+                message: None,
             }
         );
     }
@@ -20,7 +20,7 @@ use hurl_core::reader::Pos;
 
 use crate::http;
 use crate::runner::cache::BodyCache;
-use crate::runner::diff::diff;
+use crate::runner::diff::{diff, json_like};
 use crate::runner::error::{RunnerError, RunnerErrorKind};
 use crate::runner::filter::eval_filters;
 use crate::runner::predicate::eval_predicate;
@@ -90,9 +90,24 @@ impl AssertResult {
                     Ok(actual) => {
                         if actual == expected {
                             None
+                        } else if let (Value::Bytes(expected), Value::Bytes(actual)) =
+                            (expected, actual)
+                        {
+                            let kind = RunnerErrorKind::AssertBodyBytesError {
+                                offset: first_difference(expected, actual),
+                                actual_size: actual.len(),
+                                expected_size: expected.len(),
+                            };
+                            Some(RunnerError::new(*source_info, kind, false))
                         } else if use_diff(expected, actual) {
                             let actual = actual.to_string();
                             let expected = expected.to_string();
+                            // A compact JSON response is re-indented like the expected body
+                            // to get a meaningful diff, unless they only differ by whitespaces.
+                            let actual = match json_like(&expected, &actual) {
+                                Some(json) if json != expected => json,
+                                _ => actual,
+                            };
                             let hunks = diff(&expected, &actual);
                             let source_line = hunks
                                 .clone()
@@ -136,6 +151,17 @@ impl AssertResult {
     }
 }
 
+/// Returns the offset of the first byte that differs between `expected` and `actual`.
+///
+/// If one is a prefix of the other, the offset is the length of the shortest one.
+fn first_difference(expected: &[u8], actual: &[u8]) -> usize {
+    expected
+        .iter()
+        .zip(actual)
+        .position(|(e, a)| e != a)
+        .unwrap_or(expected.len().min(actual.len()))
+}
+
 fn use_diff(expected: &Value, actual: &Value) -> bool {
     if let (Value::String(expected), Value::String(actual)) = (actual, expected) {
         expected.contains('\n') || actual.contains('\n')
@@ -153,10 +179,11 @@ pub fn eval_explicit_assert(
     assert: &Assert,
     variables: &VariableSet,
     http_response: &http::Response,
+    redirects: &[http::Call],
     cache: &mut BodyCache,
     context_dir: &ContextDir,
 ) -> AssertResult {
-    let query_result = eval_query(&assert.query, variables, http_response, cache);
+    let query_result = eval_query(&assert.query, variables, http_response, redirects, cache);
 
     let actual = if assert.filters.is_empty() {
         query_result
@@ -277,6 +304,7 @@ pub mod tests {
                 &assert_count_user(),
                 &variables,
                 &xml_three_users_http_response(),
+                &[],
                 &mut cache,
                 &context_dir
             ),
@@ -288,6 +316,37 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_first_difference() {
+        assert_eq!(first_difference(b"Hello World!", b"Hello World!"), 12);
+        assert_eq!(first_difference(b"Hello World!", b"Hello"), 5);
+        assert_eq!(first_difference(b"Hello", b"Hello World!"), 5);
+        assert_eq!(first_difference(b"Hello World!", b"Hello world!"), 6);
+        assert_eq!(first_difference(b"", b"Hello"), 0);
+    }
+
+    #[test]
+    fn test_body_bytes_error() {
+        let source_info = SourceInfo::new(Pos::new(8, 1), Pos::new(8, 15));
+        let assert_result = AssertResult::Body {
+            actual: Ok(Value::Bytes(b"Hello".to_vec())),
+            expected: Ok(Value::Bytes(b"Hello World!".to_vec())),
+            source_info,
+        };
+        assert_eq!(
+            assert_result.error().unwrap(),
+            RunnerError::new(
+                source_info,
+                RunnerErrorKind::AssertBodyBytesError {
+                    offset: 5,
+                    actual_size: 5,
+                    expected_size: 12,
+                },
+                false
+            )
+        );
+    }
+
     #[test]
     pub fn test_use_diff() {
         assert!(!use_diff(&Value::Bool(true), &Value::Bool(false)));
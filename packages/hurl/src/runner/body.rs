@@ -15,30 +15,94 @@
  * limitations under the License.
  *
  */
+use std::io::Read;
 use std::path::PathBuf;
 
-use hurl_core::ast::{Base64, Body, Bytes, File, Hex, Template};
+use hurl_core::ast::{Base64, Body, Bytes, File, Hex, SourceInfo, Template};
 
 use crate::http;
 use crate::runner::error::{RunnerError, RunnerErrorKind};
-use crate::runner::json::eval_json_value;
+use crate::runner::json::{eval_json_value, eval_ndjson};
 use crate::runner::multiline::eval_multiline;
-use crate::runner::template::eval_template;
+use crate::runner::template::{eval_template, eval_template_bytes};
 use crate::runner::VariableSet;
 use crate::util::path::ContextDir;
 
+/// Filename used in a file body to read the request body from standard input.
+pub const STDIN_FILENAME: &str = "-";
+
+/// Standard input of a run, used by `file,-;` bodies.
+///
+/// Standard input is read once, by the first body using it, and its content is kept for the rest
+/// of the run: this body can be sent again when its entry is retried or repeated, but any other
+/// body reading standard input raises an error.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StdinCache {
+    /// `true` if standard input has been consumed to read the Hurl file itself.
+    consumed: bool,
+    /// The body that has read standard input, with the content read.
+    read: Option<(SourceInfo, Vec<u8>)>,
+}
+
+impl StdinCache {
+    /// Creates a new cache, `consumed` is `true` if standard input has already been read (for
+    /// instance, to get the Hurl file content).
+    pub fn new(consumed: bool) -> Self {
+        StdinCache {
+            consumed,
+            read: None,
+        }
+    }
+
+    /// Returns the content of standard input for the body `filename`, reading it with `read` if
+    /// it hasn't been read yet.
+    fn read<F>(&mut self, filename: &Template, read: F) -> Result<Vec<u8>, RunnerError>
+    where
+        F: FnOnce() -> std::io::Result<Vec<u8>>,
+    {
+        match &self.read {
+            Some((source_info, value)) if *source_info == filename.source_info => {
+                return Ok(value.clone());
+            }
+            Some(_) => {
+                let kind = RunnerErrorKind::StdinAlreadyRead;
+                return Err(RunnerError::new(filename.source_info, kind, false));
+            }
+            None if self.consumed => {
+                let kind = RunnerErrorKind::StdinAlreadyRead;
+                return Err(RunnerError::new(filename.source_info, kind, false));
+            }
+            None => {}
+        }
+        match read() {
+            Ok(value) => {
+                self.read = Some((filename.source_info, value.clone()));
+                Ok(value)
+            }
+            Err(_) => {
+                let kind = RunnerErrorKind::FileReadAccess {
+                    path: PathBuf::from(STDIN_FILENAME),
+                };
+                Err(RunnerError::new(filename.source_info, kind, false))
+            }
+        }
+    }
+}
+
 pub fn eval_body(
     body: &Body,
     variables: &VariableSet,
     context_dir: &ContextDir,
+    stdin: &mut StdinCache,
 ) -> Result<http::Body, RunnerError> {
-    eval_bytes(&body.value, variables, context_dir)
+    eval_bytes(&body.value, variables, context_dir, stdin)
 }
 
 pub fn eval_bytes(
     bytes: &Bytes,
     variables: &VariableSet,
     context_dir: &ContextDir,
+    stdin: &mut StdinCache,
 ) -> Result<http::Body, RunnerError> {
     match bytes {
         Bytes::OnelineString(value) => {
@@ -49,17 +113,33 @@ pub fn eval_bytes(
             let value = eval_multiline(value, variables)?;
             Ok(http::Body::Text(value))
         }
-        Bytes::Xml(value) => Ok(http::Body::Text(value.clone())),
+        Bytes::Xml(value) => {
+            let value = eval_template(value, variables)?;
+            Ok(http::Body::Text(value))
+        }
         Bytes::Json(value) => {
             let value = eval_json_value(value, variables, true)?;
             Ok(http::Body::Text(value))
         }
+        Bytes::NdJson(value) => {
+            let value = eval_ndjson(value, variables)?;
+            Ok(http::Body::Text(value))
+        }
         Bytes::Base64(Base64 { value, .. }) => Ok(http::Body::Binary(value.clone())),
         Bytes::Hex(Hex { value, .. }) => Ok(http::Body::Binary(value.clone())),
         Bytes::File(File { filename, .. }) => {
-            let value = eval_file(filename, variables, context_dir)?;
-            let filename = eval_template(filename, variables)?;
-            Ok(http::Body::File(value, filename))
+            if let Some(value) = eval_template_bytes(filename, variables) {
+                return Ok(http::Body::Binary(value));
+            }
+            let file = eval_template(filename, variables)
+                .map_err(|e| RunnerError::new(filename.source_info, e.kind, e.assert))?;
+            if file == STDIN_FILENAME {
+                let value = eval_stdin(filename, stdin)?;
+                return Ok(http::Body::File(value, file));
+            }
+            // The file is not read in memory, it's streamed when the request is sent.
+            let path = eval_file_path(filename, file, context_dir)?;
+            Ok(http::Body::Stream(path))
         }
     }
 }
@@ -69,7 +149,32 @@ pub fn eval_file(
     variables: &VariableSet,
     context_dir: &ContextDir,
 ) -> Result<Vec<u8>, RunnerError> {
-    let file = eval_template(filename, variables)?;
+    // The file content can be taken from a variable holding bytes instead of a file on disk.
+    if let Some(value) = eval_template_bytes(filename, variables) {
+        return Ok(value);
+    }
+    // Errors in the filename template (a missing variable for instance) are reported on the
+    // whole filename.
+    let file = eval_template(filename, variables)
+        .map_err(|e| RunnerError::new(filename.source_info, e.kind, e.assert))?;
+    let resolved_file = eval_file_path(filename, file.clone(), context_dir)?;
+    match std::fs::read(resolved_file) {
+        Ok(value) => Ok(value),
+        Err(_) => {
+            let kind = RunnerErrorKind::FileReadAccess {
+                path: PathBuf::from(file),
+            };
+            Err(RunnerError::new(filename.source_info, kind, false))
+        }
+    }
+}
+
+/// Returns the resolved path of a readable `file`, evaluated from the template `filename`.
+fn eval_file_path(
+    filename: &Template,
+    file: String,
+    context_dir: &ContextDir,
+) -> Result<PathBuf, RunnerError> {
     // In order not to leak any private date, we check that the user provided file
     // is a child of the context directory.
     let path = PathBuf::from(file);
@@ -77,24 +182,37 @@ pub fn eval_file(
         let kind = RunnerErrorKind::UnauthorizedFileAccess { path };
         return Err(RunnerError::new(filename.source_info, kind, false));
     }
+    // Any file that can be read is accepted: not only regular files, but also pipes like `<(cmd)`.
     let resolved_file = context_dir.resolved_path(&path);
-    match std::fs::read(resolved_file) {
-        Ok(value) => Ok(value),
-        Err(_) => {
-            let kind = RunnerErrorKind::FileReadAccess { path };
-            Err(RunnerError::new(filename.source_info, kind, false))
-        }
+    if !resolved_file.exists() || resolved_file.is_dir() {
+        let kind = RunnerErrorKind::FileReadAccess { path };
+        return Err(RunnerError::new(filename.source_info, kind, false));
     }
+    Ok(resolved_file)
+}
+
+/// Reads the whole standard input, returning an error if it has already been consumed by another
+/// body.
+fn eval_stdin(filename: &Template, stdin: &mut StdinCache) -> Result<Vec<u8>, RunnerError> {
+    stdin.read(filename, || {
+        let mut value = vec![];
+        std::io::stdin().read_to_end(&mut value)?;
+        Ok(value)
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use std::path::Path;
 
-    use hurl_core::ast::{SourceInfo, TemplateElement, Whitespace};
-    use hurl_core::reader::Pos;
+    use hurl_core::ast::{
+        Expr, ExprKind, Placeholder, SourceInfo, TemplateElement, Variable, Whitespace,
+    };
+    use hurl_core::parser::parse_json;
+    use hurl_core::reader::{Pos, Reader};
 
     use super::*;
+    use crate::runner::Value;
 
     #[test]
     pub fn test_body_file() {
@@ -122,8 +240,109 @@ mod tests {
         let file_root = Path::new("");
         let context_dir = ContextDir::new(current_dir.as_path(), file_root);
         assert_eq!(
-            eval_bytes(&bytes, &variables, &context_dir).unwrap(),
-            http::Body::File(b"Hello World!".to_vec(), "tests/data.bin".to_string())
+            eval_bytes(&bytes, &variables, &context_dir, &mut StdinCache::default()).unwrap(),
+            http::Body::Stream(PathBuf::from("tests/data.bin"))
+        );
+    }
+
+    #[test]
+    pub fn test_body_file_templated() {
+        // file, {{dir}}/data.bin;
+        let whitespace = Whitespace {
+            value: String::from(" "),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let bytes = Bytes::File(File {
+            space0: whitespace.clone(),
+            filename: Template {
+                delimiter: None,
+                source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 23)),
+                elements: vec![
+                    TemplateElement::Placeholder(Placeholder {
+                        space0: whitespace.clone(),
+                        expr: Expr {
+                            kind: ExprKind::Variable(Variable {
+                                name: "dir".to_string(),
+                                source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 12)),
+                            }),
+                            source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 12)),
+                        },
+                        space1: whitespace.clone(),
+                    }),
+                    TemplateElement::String {
+                        value: "/data.bin".to_string(),
+                        encoded: "/data.bin".to_string(),
+                    },
+                ],
+            },
+            space1: whitespace,
+        });
+
+        let mut variables = VariableSet::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+
+        let error = eval_bytes(&bytes, &variables, &context_dir, &mut StdinCache::default())
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::TemplateVariableNotDefined {
+                name: "dir".to_string()
+            }
+        );
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 7), Pos::new(1, 23))
+        );
+
+        variables
+            .insert("dir".to_string(), Value::String("tests".to_string()))
+            .unwrap();
+        assert_eq!(
+            eval_bytes(&bytes, &variables, &context_dir, &mut StdinCache::default()).unwrap(),
+            http::Body::Stream(PathBuf::from("tests/data.bin"))
+        );
+    }
+
+    #[test]
+    pub fn test_body_file_bytes_variable() {
+        // file, {{data}};
+        let whitespace = Whitespace {
+            value: String::from(" "),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let bytes = Bytes::File(File {
+            space0: whitespace.clone(),
+            filename: Template {
+                delimiter: None,
+                source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 15)),
+                elements: vec![TemplateElement::Placeholder(Placeholder {
+                    space0: whitespace.clone(),
+                    expr: Expr {
+                        kind: ExprKind::Variable(Variable {
+                            name: "data".to_string(),
+                            source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 13)),
+                        }),
+                        source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 13)),
+                    },
+                    space1: whitespace.clone(),
+                })],
+            },
+            space1: whitespace,
+        });
+
+        let mut variables = VariableSet::new();
+        variables
+            .insert("data".to_string(), Value::Bytes(vec![0xff, 0x00, 0xfe]))
+            .unwrap();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+        assert_eq!(
+            eval_bytes(&bytes, &variables, &context_dir, &mut StdinCache::default()).unwrap(),
+            http::Body::Binary(vec![0xff, 0x00, 0xfe])
         );
     }
 
@@ -153,7 +372,9 @@ mod tests {
         let current_dir = std::env::current_dir().unwrap();
         let file_root = Path::new("file_root");
         let context_dir = ContextDir::new(current_dir.as_path(), file_root);
-        let error = eval_bytes(&bytes, &variables, &context_dir).err().unwrap();
+        let error = eval_bytes(&bytes, &variables, &context_dir, &mut StdinCache::default())
+            .err()
+            .unwrap();
         assert_eq!(
             error.kind,
             RunnerErrorKind::FileReadAccess {
@@ -165,4 +386,139 @@ mod tests {
             SourceInfo::new(Pos::new(1, 7), Pos::new(1, 15))
         );
     }
+
+    #[test]
+    #[cfg(unix)]
+    pub fn test_body_file_not_regular() {
+        // file, null; (a device, like a pipe `<(cmd)`, is streamed)
+        let whitespace = Whitespace {
+            value: String::from(" "),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let bytes = Bytes::File(File {
+            space0: whitespace.clone(),
+            filename: Template {
+                delimiter: None,
+                source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 11)),
+                elements: vec![TemplateElement::String {
+                    value: "null".to_string(),
+                    encoded: "null".to_string(),
+                }],
+            },
+            space1: whitespace,
+        });
+
+        let variables = VariableSet::new();
+        let context_dir = ContextDir::new(Path::new("/dev"), Path::new("/dev"));
+        assert_eq!(
+            eval_bytes(&bytes, &variables, &context_dir, &mut StdinCache::default()).unwrap(),
+            http::Body::Stream(PathBuf::from("/dev/null"))
+        );
+    }
+
+    #[test]
+    pub fn test_body_file_unauthorized_access() {
+        // file, ../../etc/passwd;
+        let whitespace = Whitespace {
+            value: String::from(" "),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let bytes = Bytes::File(File {
+            space0: whitespace.clone(),
+            filename: Template {
+                delimiter: None,
+                source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 23)),
+                elements: vec![TemplateElement::String {
+                    value: "../../etc/passwd".to_string(),
+                    encoded: "../../etc/passwd".to_string(),
+                }],
+            },
+            space1: whitespace,
+        });
+
+        let variables = VariableSet::new();
+        let current_dir = Path::new("/tmp/a/b");
+        let file_root = Path::new("");
+        let context_dir = ContextDir::new(current_dir, file_root);
+        let error = eval_bytes(&bytes, &variables, &context_dir, &mut StdinCache::default())
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::UnauthorizedFileAccess {
+                path: PathBuf::from("../../etc/passwd")
+            }
+        );
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 7), Pos::new(1, 23))
+        );
+    }
+
+    #[test]
+    pub fn test_body_json_sent_verbatim() {
+        // JSON body with unusual whitespace, escapes and number formats must be sent unchanged.
+        let source = "{  \"a\\\"b\"\t:\n[1.50 ,  -0e+3,\"\\u00e9\\n\"  ] ,\"c\":{ }\r\n}";
+        let mut reader = Reader::new(source);
+        let value = parse_json(&mut reader).unwrap();
+        let bytes = Bytes::Json(value);
+
+        let variables = VariableSet::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+        assert_eq!(
+            eval_bytes(&bytes, &variables, &context_dir, &mut StdinCache::default()).unwrap(),
+            http::Body::Text(source.to_string())
+        );
+    }
+
+    #[test]
+    pub fn test_stdin_read_once() {
+        let filename = |line| Template {
+            delimiter: None,
+            source_info: SourceInfo::new(Pos::new(line, 7), Pos::new(line, 8)),
+            elements: vec![TemplateElement::String {
+                value: "-".to_string(),
+                encoded: "-".to_string(),
+            }],
+        };
+        let mut stdin = StdinCache::new(false);
+        assert_eq!(
+            stdin.read(&filename(2), || Ok(b"Hello".to_vec())).unwrap(),
+            b"Hello".to_vec()
+        );
+
+        // A retried or repeated body reuses the content already read.
+        assert_eq!(
+            stdin
+                .read(&filename(2), || panic!("stdin must be read once"))
+                .unwrap(),
+            b"Hello".to_vec()
+        );
+
+        // Another body can't read standard input.
+        let error = stdin
+            .read(&filename(5), || panic!("stdin must be read once"))
+            .unwrap_err();
+        assert_eq!(error.kind, RunnerErrorKind::StdinAlreadyRead);
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(5, 7), Pos::new(5, 8))
+        );
+
+        // Standard input has been consumed by the Hurl file.
+        let mut stdin = StdinCache::new(true);
+        let error = stdin
+            .read(&filename(2), || panic!("stdin must not be read"))
+            .unwrap_err();
+        assert_eq!(error.kind, RunnerErrorKind::StdinAlreadyRead);
+
+        // Each run has its own standard input cache.
+        let mut stdin = StdinCache::new(false);
+        assert_eq!(
+            stdin.read(&filename(5), || Ok(vec![])).unwrap(),
+            Vec::<u8>::new()
+        );
+    }
 }
@@ -35,10 +35,11 @@ pub fn eval_capture(
     capture: &Capture,
     variables: &VariableSet,
     http_response: &http::Response,
+    redirects: &[http::Call],
     cache: &mut BodyCache,
 ) -> Result<CaptureResult, RunnerError> {
     let name = eval_template(&capture.name, variables)?;
-    let value = eval_query(&capture.query, variables, http_response, cache)?;
+    let value = eval_query(&capture.query, variables, http_response, redirects, cache)?;
     let value = match value {
         None => {
             return Err(RunnerError::new(
@@ -75,7 +76,8 @@ pub fn eval_capture(
 #[cfg(test)]
 pub mod tests {
     use hurl_core::ast::{
-        LineTerminator, Query, QueryValue, SourceInfo, Template, TemplateElement, Whitespace,
+        CookieAttribute, CookieAttributeName, CookiePath, LineTerminator, Query, QueryValue,
+        SourceInfo, Template, TemplateElement, Whitespace,
     };
     use hurl_core::reader::Pos;
 
@@ -180,6 +182,7 @@ pub mod tests {
             &capture,
             &variables,
             &http::xml_three_users_http_response(),
+            &[],
             &mut cache,
         )
         .err()
@@ -243,6 +246,7 @@ pub mod tests {
                 &user_count_capture(),
                 &variables,
                 &http::xml_three_users_http_response(),
+                &[],
                 &mut cache,
             )
             .unwrap(),
@@ -257,6 +261,7 @@ pub mod tests {
                 &duration_capture(),
                 &variables,
                 &http::json_http_response(),
+                &[],
                 &mut cache
             )
             .unwrap(),
@@ -266,4 +271,71 @@ pub mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_capture_missing_cookie() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let whitespace = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        // session: cookie "session_id[Max-Age]"
+        let capture = Capture {
+            line_terminators: vec![],
+            space0: whitespace.clone(),
+            name: Template {
+                delimiter: None,
+                elements: vec![TemplateElement::String {
+                    value: "session".to_string(),
+                    encoded: "session".to_string(),
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 8)),
+            },
+            space1: whitespace.clone(),
+            space2: whitespace.clone(),
+            query: Query {
+                source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 38)),
+                value: QueryValue::Cookie {
+                    space0: whitespace.clone(),
+                    expr: CookiePath {
+                        name: Template {
+                            delimiter: Some('"'),
+                            elements: vec![TemplateElement::String {
+                                value: "session_id".to_string(),
+                                encoded: "session_id".to_string(),
+                            }],
+                            source_info: SourceInfo::new(Pos::new(1, 18), Pos::new(1, 28)),
+                        },
+                        attribute: Some(CookieAttribute {
+                            space0: whitespace.clone(),
+                            name: CookieAttributeName::MaxAge("Max-Age".to_string()),
+                            space1: whitespace.clone(),
+                        }),
+                    },
+                },
+            },
+            filters: vec![],
+            line_terminator0: LineTerminator {
+                space0: whitespace.clone(),
+                comment: None,
+                newline: whitespace,
+            },
+        };
+
+        let error = eval_capture(
+            &capture,
+            &variables,
+            &http::json_http_response(),
+            &[],
+            &mut cache,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.kind, RunnerErrorKind::NoQueryResult);
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 10), Pos::new(1, 38))
+        );
+    }
 }
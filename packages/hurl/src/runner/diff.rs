@@ -59,6 +59,89 @@ pub fn diff(old: &str, new: &str) -> Vec<DiffHunk> {
     hunks
 }
 
+/// Returns `actual` re-indented like `expected`, if both are JSON texts and `actual` is on a single
+/// line, so that a compact JSON response can be diffed line by line against a multiline JSON body.
+///
+/// Keys order and values are kept as in `actual`, only whitespaces are changed. The indentation
+/// is inferred from the first indented line of `expected`.
+pub fn json_like(expected: &str, actual: &str) -> Option<String> {
+    if actual.trim().contains('\n') || !expected.trim().contains('\n') {
+        return None;
+    }
+    if serde_json::from_str::<serde_json::Value>(expected).is_err()
+        || serde_json::from_str::<serde_json::Value>(actual).is_err()
+    {
+        return None;
+    }
+    let indent = expected
+        .lines()
+        .map(|l| &l[..l.len() - l.trim_start().len()])
+        .find(|i| !i.is_empty())
+        .unwrap_or("  ");
+    let mut json = reindent_json(actual.trim(), indent);
+    if expected.ends_with('\n') {
+        json.push('\n');
+    }
+    Some(json)
+}
+
+/// Pretty prints a valid JSON text `s`, using `indent` for each nesting level.
+fn reindent_json(s: &str, indent: &str) -> String {
+    let mut out = String::new();
+    let mut level = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match c {
+            '"' => {
+                in_string = true;
+                out.push(c);
+            }
+            '{' | '[' => {
+                out.push(c);
+                while chars.peek().is_some_and(|c| c.is_whitespace()) {
+                    chars.next();
+                }
+                // Empty objects and arrays are kept on one line.
+                if chars.peek() == Some(&'}') || chars.peek() == Some(&']') {
+                    out.push(chars.next().unwrap());
+                } else {
+                    level += 1;
+                    out.push('\n');
+                    out.push_str(&indent.repeat(level));
+                }
+            }
+            '}' | ']' => {
+                level -= 1;
+                out.push('\n');
+                out.push_str(&indent.repeat(level));
+                out.push(c);
+            }
+            ',' => {
+                out.push(c);
+                out.push('\n');
+                out.push_str(&indent.repeat(level));
+            }
+            ':' => out.push_str(": "),
+            c if c.is_whitespace() => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
 /// get start of the hunk and first change in the input source string
 /// Both are 0-based line number
 fn get_hunk_lines(ops: &[DiffOp]) -> (usize, usize) {
@@ -511,4 +594,29 @@ mod tests {
         assert_eq!(first_hunk.start, 1);
         assert_eq!(first_hunk.source_line, 0);
     }
+
+    #[test]
+    fn test_json_like() {
+        assert_eq!(
+            json_like(
+                "{\n    \"id\": 1,\n    \"tags\": [\"a\", \"b\"],\n    \"extra\": {}\n}\n",
+                r#"{"id":2,"tags":["a","b, c"],"extra":{ },"name":"x\"y"}"#
+            )
+            .unwrap(),
+            r#"{
+    "id": 2,
+    "tags": [
+        "a",
+        "b, c"
+    ],
+    "extra": {},
+    "name": "x\"y"
+}
+"#
+        );
+        // Not JSON:
+        assert_eq!(json_like("a\nb\n", "{}"), None);
+        // Already multiline:
+        assert_eq!(json_like("{\n}", "{\n}\n"), None);
+    }
 }
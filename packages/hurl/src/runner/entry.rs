@@ -15,10 +15,13 @@
  * limitations under the License.
  *
  */
+use std::time::Instant;
+
 use hurl_core::ast::{Entry, SourceInfo};
 
 use crate::http;
 use crate::http::{ClientOptions, CurlCmd};
+use crate::runner::body::StdinCache;
 use crate::runner::cache::BodyCache;
 use crate::runner::error::RunnerError;
 use crate::runner::result::{AssertResult, EntryResult};
@@ -32,11 +35,13 @@ use crate::util::logger::{Logger, Verbosity};
 /// been executed. If `http_client` has been configured to follow redirection, the `calls` list contains
 /// every step of the redirection for the first to the last.
 /// `variables` are used to render values at runtime, and can be updated by captures.
+/// `stdin` holds the standard input of the run, for bodies read from it.
 pub fn run(
     entry: &Entry,
     entry_index: usize,
     http_client: &mut http::Client,
     variables: &mut VariableSet,
+    stdin: &mut StdinCache,
     runner_options: &RunnerOptions,
     logger: &mut Logger,
 ) -> EntryResult {
@@ -45,9 +50,55 @@ pub fn run(
     let context_dir = &runner_options.context_dir;
 
     // Evaluates our source requests given our set of variables
-    let http_request = match request::eval_request(&entry.request, variables, context_dir) {
-        Ok(r) => r,
-        Err(error) => {
+    let mut http_request =
+        match request::eval_request(&entry.request, variables, context_dir, stdin) {
+            Ok(r) => r,
+            Err(RunnerError {
+                kind: RunnerErrorKind::TemplateVariableNotDefined { name },
+                ..
+            }) if runner_options.dry_run => {
+                // Captures are not computed in dry run, so variables set by captures are undefined.
+                logger.warning(&format!(
+                    "Variable <{name}> is not defined, request can not be evaluated in dry run"
+                ));
+                return EntryResult {
+                    entry_index,
+                    source_info,
+                    compressed,
+                    ..Default::default()
+                };
+            }
+            Err(error) => {
+                return EntryResult {
+                    entry_index,
+                    source_info,
+                    errors: vec![error],
+                    compressed,
+                    ..Default::default()
+                };
+            }
+        };
+    // Default headers come from the command line: their errors are reported on the entry.
+    if let Err(error) = request::add_default_headers(
+        &mut http_request,
+        &runner_options.default_headers,
+        variables,
+    ) {
+        let error = RunnerError::new(source_info, error.kind, false);
+        return EntryResult {
+            entry_index,
+            source_info,
+            errors: vec![error],
+            compressed,
+            ..Default::default()
+        };
+    }
+    if let Some(secret) = request::basic_auth_secret(&http_request, &logger.secrets) {
+        logger.add_secret(secret);
+    }
+    if let Some(compression) = runner_options.compress_body {
+        if let Err(error) = http_request.compress_body(compression) {
+            let error = RunnerError::new(source_info, RunnerErrorKind::Http(error), false);
             return EntryResult {
                 entry_index,
                 source_info,
@@ -56,7 +107,7 @@ pub fn run(
                 ..Default::default()
             };
         }
-    };
+    }
     let client_options = ClientOptions::from(runner_options, logger.verbosity);
 
     // Experimental features with cookie storage
@@ -82,10 +133,25 @@ pub fn run(
 
     log_request(http_client, &curl_cmd, &http_request, logger);
 
+    if runner_options.dry_run {
+        logger.debug("");
+        return EntryResult {
+            entry_index,
+            source_info,
+            compressed,
+            curl_cmd,
+            ..Default::default()
+        };
+    }
+
     // Run the HTTP requests (optionally follow redirection)
+    let transfer_start = Instant::now();
     let calls = match http_client.execute_with_redirect(&http_request, &client_options, logger) {
         Ok(calls) => calls,
         Err(http_error) => {
+            // No timings are available from a failed transfer (a timeout for instance), so we
+            // report the time spent until the failure.
+            let transfer_duration = transfer_start.elapsed();
             let start = entry.request.url.source_info.start;
             let end = entry.request.url.source_info.end;
             let error_source_info = SourceInfo::new(start, end);
@@ -95,6 +161,7 @@ pub fn run(
                 entry_index,
                 source_info,
                 errors: vec![error],
+                transfer_duration,
                 compressed,
                 curl_cmd,
                 ..Default::default()
@@ -103,7 +170,7 @@ pub fn run(
     };
 
     // Now, we can compute capture and asserts on the last HTTP request/response chains.
-    let call = calls.last().unwrap();
+    let (call, redirects) = calls.split_last().unwrap();
     let http_response = &call.response;
 
     // `transfer_duration` represent the network time of calls, not including assert processing.
@@ -116,33 +183,40 @@ pub fn run(
     let mut cache = BodyCache::new();
     let mut asserts = vec![];
 
-    if !runner_options.ignore_asserts {
-        if let Some(response_spec) = &entry.response {
-            let mut status_asserts =
-                response::eval_version_status_asserts(response_spec, http_response);
-            let errors = asserts_to_errors(&status_asserts);
-            asserts.append(&mut status_asserts);
-            if !errors.is_empty() {
-                logger.debug("");
-                return EntryResult {
-                    entry_index,
-                    source_info,
-                    calls,
-                    captures: vec![],
-                    asserts,
-                    errors,
-                    transfer_duration,
-                    compressed,
-                    curl_cmd,
-                };
-            }
+    // With `ignore_asserts`, asserts are still evaluated and recorded but their failures are not
+    // reported as errors.
+    if let Some(response_spec) = &entry.response {
+        let mut status_asserts =
+            response::eval_version_status_asserts(response_spec, http_response);
+        let errors = asserts_to_errors(&status_asserts);
+        asserts.append(&mut status_asserts);
+        if !errors.is_empty() && !runner_options.ignore_asserts {
+            logger.debug("");
+            return EntryResult {
+                entry_index,
+                source_info,
+                calls,
+                captures: vec![],
+                asserts,
+                errors,
+                transfer_duration,
+                compressed,
+                curl_cmd,
+                skipped: false,
+            };
         }
-    };
+    }
 
     let captures = match &entry.response {
         None => vec![],
         Some(response_spec) => {
-            match response::eval_captures(response_spec, http_response, &mut cache, variables) {
+            match response::eval_captures(
+                response_spec,
+                http_response,
+                redirects,
+                &mut cache,
+                variables,
+            ) {
                 Ok(captures) => captures,
                 Err(e) => {
                     return EntryResult {
@@ -155,6 +229,7 @@ pub fn run(
                         transfer_duration,
                         compressed,
                         curl_cmd,
+                        skipped: false,
                     };
                 }
             }
@@ -164,20 +239,31 @@ pub fn run(
     logger.debug("");
 
     // Compute asserts
-    if !runner_options.ignore_asserts {
-        if let Some(response_spec) = &entry.response {
-            let mut other_asserts = response::eval_asserts(
-                response_spec,
-                variables,
-                http_response,
-                &mut cache,
-                context_dir,
-            );
-            asserts.append(&mut other_asserts);
-        }
-    };
+    if let Some(response_spec) = &entry.response {
+        let mut other_asserts = response::eval_asserts(
+            response_spec,
+            variables,
+            http_response,
+            redirects,
+            &mut cache,
+            context_dir,
+            stdin,
+        );
+        asserts.append(&mut other_asserts);
+    }
 
     let errors = asserts_to_errors(&asserts);
+    let errors = if runner_options.ignore_asserts {
+        if !errors.is_empty() {
+            logger.warning(&format!(
+                "{} assert(s) failed but were not enforced (--ignore-asserts)",
+                errors.len()
+            ));
+        }
+        vec![]
+    } else {
+        errors
+    };
 
     EntryResult {
         entry_index,
@@ -189,6 +275,7 @@ pub fn run(
         transfer_duration,
         compressed,
         curl_cmd,
+        skipped: false,
     }
 }
 
@@ -212,6 +299,7 @@ impl ClientOptions {
         ClientOptions {
             aws_sigv4: runner_options.aws_sigv4.clone(),
             cacert_file: runner_options.cacert_file.clone(),
+            chunked: runner_options.chunked,
             client_cert_file: runner_options.client_cert_file.clone(),
             client_key_file: runner_options.client_key_file.clone(),
             compressed: runner_options.compressed,
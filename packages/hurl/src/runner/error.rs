@@ -17,6 +17,7 @@
  */
 use std::cmp::max;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use hurl_core::ast::SourceInfo;
 use hurl_core::error;
@@ -57,6 +58,12 @@ pub enum RunnerErrorKind {
         actual: String,
         expected: String,
     },
+    /// Bytes bodies differ, starting at byte `offset`.
+    AssertBodyBytesError {
+        offset: usize,
+        actual_size: usize,
+        expected_size: usize,
+    },
     AssertFailure {
         actual: String,
         expected: String,
@@ -71,6 +78,8 @@ pub enum RunnerErrorKind {
     AssertVersion {
         actual: String,
     },
+    /// An explicit `Authorization` header is used with a `[BasicAuth]` section.
+    AuthorizationConflict,
     ExpressionInvalidType {
         value: String,
         expecting: String,
@@ -87,17 +96,32 @@ pub enum RunnerErrorKind {
     FilterDecode(String),
     FilterInvalidEncoding(String),
     FilterInvalidInput(String),
+    /// A JSON Web Token can not be decoded, because of `reason`.
+    FilterInvalidJwt {
+        reason: String,
+    },
     FilterMissingInput,
     Http(HttpError),
     InvalidJson {
         value: String,
     },
+    InvalidJsonSchema {
+        message: String,
+    },
     InvalidRegex,
     InvalidUrl {
         url: String,
         message: String,
     },
     NoQueryResult,
+    /// An entry is still failing when its retries are stopped by the retry max time, after
+    /// `attempts` attempts.
+    PollTimeout {
+        attempts: usize,
+        max_time: Duration,
+    },
+    /// The HTTP response body is empty and can not be parsed (ex: response to a `HEAD` request).
+    QueryEmptyBody,
     QueryHeaderNotFound,
     QueryInvalidJsonpathExpression {
         value: String,
@@ -108,9 +132,14 @@ pub enum RunnerErrorKind {
     ReadOnlySecret {
         name: String,
     },
+    /// Standard input has already been consumed in this run.
+    StdinAlreadyRead,
     TemplateVariableNotDefined {
         name: String,
     },
+    EnvVariableNotDefined {
+        name: String,
+    },
     UnrenderableExpression {
         value: String,
     },
@@ -130,22 +159,28 @@ impl DisplaySourceError for RunnerError {
         match &self.kind {
             RunnerErrorKind::AssertBodyDiffError { .. } => "Assert body value".to_string(),
             RunnerErrorKind::AssertBodyValueError { .. } => "Assert body value".to_string(),
+            RunnerErrorKind::AssertBodyBytesError { .. } => "Assert body value".to_string(),
             RunnerErrorKind::AssertFailure { .. } => "Assert failure".to_string(),
             RunnerErrorKind::AssertHeaderValueError { .. } => "Assert header value".to_string(),
             RunnerErrorKind::AssertStatus { .. } => "Assert status code".to_string(),
             RunnerErrorKind::AssertVersion { .. } => "Assert HTTP version".to_string(),
+            RunnerErrorKind::AuthorizationConflict => "Authorization conflict".to_string(),
             RunnerErrorKind::ExpressionInvalidType { .. } => "Invalid expression type".to_string(),
             RunnerErrorKind::FileReadAccess { .. } => "File read access".to_string(),
             RunnerErrorKind::FileWriteAccess { .. } => "File write access".to_string(),
             RunnerErrorKind::FilterDecode { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterInvalidEncoding { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterInvalidInput { .. } => "Filter error".to_string(),
+            RunnerErrorKind::FilterInvalidJwt { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterMissingInput => "Filter error".to_string(),
             RunnerErrorKind::Http(http_error) => http_error.description(),
             RunnerErrorKind::InvalidJson { .. } => "Invalid JSON".to_string(),
+            RunnerErrorKind::InvalidJsonSchema { .. } => "Invalid JSON schema".to_string(),
             RunnerErrorKind::InvalidUrl { .. } => "Invalid URL".to_string(),
             RunnerErrorKind::InvalidRegex => "Invalid regex".to_string(),
             RunnerErrorKind::NoQueryResult => "No query result".to_string(),
+            RunnerErrorKind::PollTimeout { .. } => "Poll timeout".to_string(),
+            RunnerErrorKind::QueryEmptyBody => "Empty body".to_string(),
             RunnerErrorKind::QueryHeaderNotFound => "Header not found".to_string(),
             RunnerErrorKind::QueryInvalidJson => "Invalid JSON".to_string(),
             RunnerErrorKind::QueryInvalidJsonpathExpression { .. } => {
@@ -154,7 +189,11 @@ impl DisplaySourceError for RunnerError {
             RunnerErrorKind::QueryInvalidXml => "Invalid XML".to_string(),
             RunnerErrorKind::QueryInvalidXpathEval => "Invalid XPath expression".to_string(),
             RunnerErrorKind::ReadOnlySecret { .. } => "Readonly secret".to_string(),
+            RunnerErrorKind::StdinAlreadyRead => "Standard input".to_string(),
             RunnerErrorKind::TemplateVariableNotDefined { .. } => "Undefined variable".to_string(),
+            RunnerErrorKind::EnvVariableNotDefined { .. } => {
+                "Undefined environment variable".to_string()
+            }
             RunnerErrorKind::UnauthorizedFileAccess { .. } => {
                 "Unauthorized file access".to_string()
             }
@@ -174,10 +213,22 @@ impl DisplaySourceError for RunnerError {
                 message
             }
             RunnerErrorKind::AssertBodyValueError { actual, .. } => {
+                let actual = truncate(actual);
                 let message = &format!("actual value is <{actual}>");
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::AssertBodyBytesError {
+                offset,
+                actual_size,
+                expected_size,
+            } => {
+                let message = &format!(
+                    "actual body differs at byte offset {offset} (actual size {actual_size} bytes, expected size {expected_size} bytes)"
+                );
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::AssertFailure {
                 actual,
                 expected,
@@ -189,6 +240,7 @@ impl DisplaySourceError for RunnerError {
                 } else {
                     ""
                 };
+                let actual = truncate(actual);
                 let message = format!("   actual:   {actual}\n   expected: {expected}{additional}");
                 color_red_multiline_string(&message)
             }
@@ -207,6 +259,11 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::AuthorizationConflict => {
+                let message = "Authorization header can not be used with a [BasicAuth] section";
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::ExpressionInvalidType {
                 value, expecting, ..
             } => {
@@ -239,6 +296,11 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::FilterInvalidJwt { reason } => {
+                let message = &format!("invalid JWT: {reason}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::FilterMissingInput => {
                 let message = "missing value to apply filter";
                 let message = error::add_carets(message, self.source_info, content);
@@ -254,6 +316,11 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::InvalidJsonSchema { message } => {
+                let message = &format!("JSON schema is not valid ({message})");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::InvalidUrl { url, message } => {
                 let message = &format!("invalid URL <{url}> ({message})");
                 let message = error::add_carets(message, self.source_info, content);
@@ -269,6 +336,20 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::PollTimeout { attempts, max_time } => {
+                let plural = if *attempts > 1 { "s" } else { "" };
+                let message = &format!(
+                    "entry still failing after {attempts} attempt{plural}, retry max time of {} ms reached",
+                    max_time.as_millis()
+                );
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::QueryEmptyBody => {
+                let message = "the HTTP response body is empty";
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::QueryHeaderNotFound => {
                 let message = "this header has not been found in the response";
                 let message = error::add_carets(message, self.source_info, content);
@@ -299,11 +380,24 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::StdinAlreadyRead => {
+                let message =
+                    "standard input has already been read, only one file body can use <->";
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::TemplateVariableNotDefined { name } => {
                 let message = &format!("you must set the variable {name}");
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::EnvVariableNotDefined { name } => {
+                let message = &format!(
+                    "environment variable {name} is not set or not allowed, check --env-prefix option"
+                );
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::UnauthorizedFileAccess { path } => {
                 let message = &format!(
                     "unauthorized access to file {}, check --file-root option",
@@ -351,6 +445,20 @@ impl DisplaySourceError for RunnerError {
     }
 }
 
+/// Maximum number of chars of an actual value displayed in an assert error.
+const MAX_ACTUAL_CHARS: usize = 1024;
+
+/// Truncates a (possibly large) actual `value` to [`MAX_ACTUAL_CHARS`], indicating how many chars
+/// have been omitted.
+fn truncate(value: &str) -> String {
+    let count = value.chars().count();
+    if count <= MAX_ACTUAL_CHARS {
+        return value.to_string();
+    }
+    let head = value.chars().take(MAX_ACTUAL_CHARS).collect::<String>();
+    format!("{head}... ({} more chars)", count - MAX_ACTUAL_CHARS)
+}
+
 /// Color each line separately
 fn color_red_multiline_string(s: &str) -> StyledString {
     let lines = s.split('\n');
@@ -409,7 +517,7 @@ mod tests {
 
     use crate::http::HttpError;
     use crate::runner::diff::diff;
-    use crate::runner::{RunnerError, RunnerErrorKind};
+    use crate::runner::{AssertResult, RunnerError, RunnerErrorKind, Value};
 
     #[test]
     fn test_error_timeout() {
@@ -614,4 +722,68 @@ HTTP/1.0 200
    |"#
         );
     }
+
+    #[test]
+    fn test_assert_error_string_truncated() {
+        let content = r#"GET http://localhost
+HTTP/1.0 200
+`Hello World!`
+"#;
+        let lines = content.lines().collect::<Vec<_>>();
+        let kind = RunnerErrorKind::AssertBodyValueError {
+            actual: "Hello".repeat(300),
+            expected: "Hello World!".to_string(),
+        };
+        let error_source_info = SourceInfo::new(Pos::new(3, 1), Pos::new(3, 15));
+        let error = RunnerError::new(error_source_info, kind, true);
+        let expected_message = format!(
+            "\n 3 | `Hello World!`\n   | ^^^^^^^^^^^^^^ actual value is <{}... (476 more chars)>\n   |",
+            &"Hello".repeat(300)[..1024]
+        );
+        assert_eq!(
+            error.message(&lines).to_string(Format::Plain),
+            expected_message
+        );
+    }
+
+    #[test]
+    fn test_assert_error_json_diff() {
+        let content = r#"GET http://localhost
+HTTP/1.0 200
+{
+  "id": 1,
+  "name": "Bob",
+  "tags": [
+    "a"
+  ]
+}
+"#;
+        let filename = "test.hurl";
+        let expected = "{\n  \"id\": 1,\n  \"name\": \"Bob\",\n  \"tags\": [\n    \"a\"\n  ]\n}\n";
+        let actual = r#"{"id":1,"name":"Bill","tags":["a"]}"#;
+        let assert_result = AssertResult::Body {
+            actual: Ok(Value::String(actual.to_string())),
+            expected: Ok(Value::String(expected.to_string())),
+            source_info: SourceInfo::new(Pos::new(3, 1), Pos::new(3, 1)),
+        };
+        let error = assert_result.error().unwrap();
+        let entry_source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20));
+        assert_eq!(
+            error.to_string(
+                filename,
+                content,
+                Some(entry_source_info),
+                OutputFormat::Terminal(false)
+            ),
+            r#"Assert body value
+  --> test.hurl:5:1
+   |
+   | GET http://localhost
+   | ...
+ 5 |   "name": "Bob",
+   |   -  "name": "Bob",
+   |   +  "name": "Bill",
+   |"#
+        );
+    }
 }
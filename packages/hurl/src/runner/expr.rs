@@ -21,7 +21,7 @@ use crate::runner::error::{RunnerError, RunnerErrorKind};
 use crate::runner::value::Value;
 use crate::runner::VariableSet;
 
-use super::function;
+use super::{function, template};
 
 /// Evaluates the expression `expr` with `variables` map, returns a [`Value`] on success or an [`RunnerError`] .
 pub fn eval(expr: &Expr, variables: &VariableSet) -> Result<Value, RunnerError> {
@@ -29,6 +29,11 @@ pub fn eval(expr: &Expr, variables: &VariableSet) -> Result<Value, RunnerError>
         ExprKind::Variable(variable) => {
             if let Some(variable) = variables.get(variable.name.as_str()) {
                 Ok(variable.value().clone())
+            } else if let Some(name) = variable.name.strip_prefix(ENV_PREFIX) {
+                let kind = RunnerErrorKind::EnvVariableNotDefined {
+                    name: name.to_string(),
+                };
+                Err(RunnerError::new(variable.source_info, kind, false))
             } else {
                 let kind = RunnerErrorKind::TemplateVariableNotDefined {
                     name: variable.name.clone(),
@@ -36,10 +41,24 @@ pub fn eval(expr: &Expr, variables: &VariableSet) -> Result<Value, RunnerError>
                 Err(RunnerError::new(variable.source_info, kind, false))
             }
         }
+        ExprKind::VariableDefault(default) => {
+            if let Some(variable) = variables.get(default.variable.name.as_str()) {
+                Ok(variable.value().clone())
+            } else {
+                let value = template::eval_template(&default.value, variables)?;
+                Ok(Value::String(value))
+            }
+        }
         ExprKind::Function(fct) => function::eval(fct),
     }
 }
 
+/// Prefix of variables names holding process environment variables: `{{env.HOME}}`.
+///
+/// Environment variables are not read by the runner: they're injected in the variables (as secrets)
+/// by the caller, see `--env-prefix` option.
+const ENV_PREFIX: &str = "env.";
+
 /// Render the expression `expr` with `variables` map, returns a [`String`] on success or an [`RunnerError`] .
 pub fn render(expr: &Expr, variables: &VariableSet) -> Result<String, RunnerError> {
     let source_info = expr.source_info;
@@ -57,8 +76,11 @@ pub fn render(expr: &Expr, variables: &VariableSet) -> Result<String, RunnerErro
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::runner::Number;
     use hurl_core::{
-        ast::{ExprKind, SourceInfo, Variable},
+        ast::{
+            ExprKind, SourceInfo, Template, TemplateElement, Variable, VariableDefault, Whitespace,
+        },
         reader::Pos,
     };
 
@@ -97,4 +119,75 @@ mod tests {
             "2023-01-10T08:29:52.000000Z"
         );
     }
+
+    #[test]
+    fn test_eval_variable_default() {
+        let mut variables = VariableSet::new();
+        variables
+            .insert("age".to_string(), Value::Number(Number::Integer(30)))
+            .unwrap();
+        let whitespace = Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let variable_default = |name: &str| Expr {
+            kind: ExprKind::VariableDefault(Box::new(VariableDefault {
+                variable: Variable {
+                    name: name.to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                space0: whitespace.clone(),
+                space1: whitespace.clone(),
+                space2: whitespace.clone(),
+                value: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "18".to_string(),
+                        encoded: "18".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            })),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        assert_eq!(
+            eval(&variable_default("age"), &variables).unwrap(),
+            Value::Number(Number::Integer(30))
+        );
+        assert_eq!(
+            eval(&variable_default("unknown"), &variables).unwrap(),
+            Value::String("18".to_string())
+        );
+    }
+
+    #[test]
+    fn test_eval_env_variable() {
+        let mut variables = VariableSet::new();
+        variables.insert_secret("env.HURL_TEST_ENV_SET".to_string(), "foo".to_string());
+        let variable = |name: &str| Expr {
+            kind: ExprKind::Variable(Variable {
+                name: name.to_string(),
+                source_info: SourceInfo::new(Pos::new(1, 3), Pos::new(1, 25)),
+            }),
+            source_info: SourceInfo::new(Pos::new(1, 3), Pos::new(1, 25)),
+        };
+
+        assert_eq!(
+            eval(&variable("env.HURL_TEST_ENV_SET"), &variables).unwrap(),
+            Value::String("foo".to_string())
+        );
+
+        assert_eq!(
+            eval(&variable("env.HURL_TEST_ENV_UNSET"), &variables)
+                .err()
+                .unwrap(),
+            RunnerError::new(
+                SourceInfo::new(Pos::new(1, 3), Pos::new(1, 25)),
+                RunnerErrorKind::EnvVariableNotDefined {
+                    name: "HURL_TEST_ENV_UNSET".to_string()
+                },
+                false
+            )
+        );
+    }
 }
@@ -0,0 +1,128 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use base64::alphabet;
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::Engine;
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Decodes a Base64 encoded string `value` to bytes.
+///
+/// Both the standard and the URL-safe alphabets are accepted, with or without padding.
+pub fn eval_base64_decode(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => match decode(value) {
+            Some(decoded) => Ok(Some(Value::Bytes(decoded))),
+            None => {
+                let kind = RunnerErrorKind::FilterInvalidInput("Invalid Base64 string".to_string());
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Decoding configuration, where padding is optional.
+const CONFIG: GeneralPurposeConfig =
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+const STANDARD: GeneralPurpose = GeneralPurpose::new(&alphabet::STANDARD, CONFIG);
+const URL_SAFE: GeneralPurpose = GeneralPurpose::new(&alphabet::URL_SAFE, CONFIG);
+
+/// Decodes a Base64 string `value`, encoded with the standard or the URL-safe alphabet (as in JSON
+/// Web Tokens), with or without padding. Returns `None` if `value` is not valid Base64.
+pub fn decode(value: &str) -> Option<Vec<u8>> {
+    STANDARD
+        .decode(value)
+        .or_else(|_| URL_SAFE.decode(value))
+        .ok()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_base64_decode() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 13)),
+            value: FilterValue::Base64Decode,
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("SGVsbG8gV29ybGQh".to_string()),
+                &variables,
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(b"Hello World!".to_vec())
+        );
+
+        // URL-safe alphabet, without padding.
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("-_8".to_string()),
+                &variables,
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(vec![0xfb, 0xff])
+        );
+
+        let error = eval_filter(
+            &filter,
+            &Value::String("not base64!".to_string()),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 13))
+        );
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("Invalid Base64 string".to_string())
+        );
+
+        let error = eval_filter(&filter, &Value::Bool(true), &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+}
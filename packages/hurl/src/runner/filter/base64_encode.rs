@@ -0,0 +1,146 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use base64::engine::general_purpose;
+use base64::Engine;
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Encodes bytes or a string `value` (as UTF-8 bytes) to a Base64 string, with the standard
+/// alphabet and padding.
+pub fn eval_base64_encode(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    eval_encode(value, &general_purpose::STANDARD, source_info, assert)
+}
+
+/// Encodes bytes or a string `value` (as UTF-8 bytes) to a Base64 string, with the URL-safe
+/// alphabet and without padding.
+pub fn eval_base64_url_safe_encode(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    eval_encode(
+        value,
+        &general_purpose::URL_SAFE_NO_PAD,
+        source_info,
+        assert,
+    )
+}
+
+fn eval_encode(
+    value: &Value,
+    engine: &impl Engine,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Bytes(value) => Ok(Some(Value::String(engine.encode(value)))),
+        Value::String(value) => Ok(Some(Value::String(engine.encode(value.as_bytes())))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_base64_encode() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 13)),
+            value: FilterValue::Base64Encode,
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("bob:secret".to_string()),
+                &variables,
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("Ym9iOnNlY3JldA==".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Bytes(vec![0xfb, 0xff, 0xbf]),
+                &variables,
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("+/+/".to_string())
+        );
+
+        let error = eval_filter(&filter, &Value::Bool(true), &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 13))
+        );
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_base64_url_safe_encode() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20)),
+            value: FilterValue::Base64UrlSafeEncode,
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("bob:secret".to_string()),
+                &variables,
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("Ym9iOnNlY3JldA".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Bytes(vec![0xfb, 0xff, 0xbf]),
+                &variables,
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("-_-_".to_string())
+        );
+    }
+}
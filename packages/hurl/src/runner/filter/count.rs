@@ -68,6 +68,18 @@ pub mod tests {
             .unwrap(),
             Value::Number(Number::Integer(3))
         );
+        assert_eq!(
+            eval_filter(&filter, &Value::Bytes(vec![0xca, 0xfe]), &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::Number(Number::Integer(2))
+        );
+        assert_eq!(
+            eval_filter(&filter, &Value::Nodeset(4), &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::Number(Number::Integer(4))
+        );
 
         let error = eval_filter(&filter, &Value::Bool(true), &variables, false)
             .err()
@@ -80,5 +92,18 @@ pub mod tests {
             error.kind,
             RunnerErrorKind::FilterInvalidInput("boolean".to_string())
         );
+
+        let error = eval_filter(
+            &filter,
+            &Value::String("abc".to_string()),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("string".to_string())
+        );
     }
 }
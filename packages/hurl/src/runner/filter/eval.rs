@@ -18,6 +18,8 @@
 use hurl_core::ast::{Filter, FilterValue};
 
 use super::count::eval_count;
+use crate::runner::filter::base64_decode::eval_base64_decode;
+use crate::runner::filter::base64_encode::{eval_base64_encode, eval_base64_url_safe_encode};
 use crate::runner::filter::days_after_now::eval_days_after_now;
 use crate::runner::filter::days_before_now::eval_days_before_now;
 use crate::runner::filter::decode::eval_decode;
@@ -25,13 +27,17 @@ use crate::runner::filter::format::eval_format;
 use crate::runner::filter::html_escape::eval_html_escape;
 use crate::runner::filter::html_unescape::eval_html_unescape;
 use crate::runner::filter::jsonpath::eval_jsonpath;
+use crate::runner::filter::jwt_payload::eval_jwt_payload;
+use crate::runner::filter::length::eval_length;
 use crate::runner::filter::nth::eval_nth;
 use crate::runner::filter::regex::eval_regex;
 use crate::runner::filter::replace::eval_replace;
 use crate::runner::filter::split::eval_split;
 use crate::runner::filter::to_date::eval_to_date;
 use crate::runner::filter::to_float::eval_to_float;
+use crate::runner::filter::to_hex::eval_to_hex;
 use crate::runner::filter::to_int::eval_to_int;
+use crate::runner::filter::to_lower::eval_to_lower;
 use crate::runner::filter::url_decode::eval_url_decode;
 use crate::runner::filter::url_encode::eval_url_encode;
 use crate::runner::filter::xpath::eval_xpath;
@@ -68,6 +74,11 @@ pub fn eval_filter(
     in_assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
     match &filter.value {
+        FilterValue::Base64Decode => eval_base64_decode(value, filter.source_info, in_assert),
+        FilterValue::Base64Encode => eval_base64_encode(value, filter.source_info, in_assert),
+        FilterValue::Base64UrlSafeEncode => {
+            eval_base64_url_safe_encode(value, filter.source_info, in_assert)
+        }
         FilterValue::Count => eval_count(value, filter.source_info, in_assert),
         FilterValue::DaysAfterNow => eval_days_after_now(value, filter.source_info, in_assert),
         FilterValue::DaysBeforeNow => eval_days_before_now(value, filter.source_info, in_assert),
@@ -82,6 +93,8 @@ pub fn eval_filter(
         FilterValue::JsonPath { expr, .. } => {
             eval_jsonpath(value, expr, variables, filter.source_info, in_assert)
         }
+        FilterValue::JwtPayload => eval_jwt_payload(value, filter.source_info, in_assert),
+        FilterValue::Length => eval_length(value, filter.source_info, in_assert),
         FilterValue::Regex {
             value: regex_value, ..
         } => eval_regex(value, regex_value, variables, filter.source_info, in_assert),
@@ -105,7 +118,9 @@ pub fn eval_filter(
             eval_to_date(value, fmt, variables, filter.source_info, in_assert)
         }
         FilterValue::ToFloat => eval_to_float(value, filter.source_info, in_assert),
+        FilterValue::ToHex => eval_to_hex(value, filter.source_info, in_assert),
         FilterValue::ToInt => eval_to_int(value, filter.source_info, in_assert),
+        FilterValue::ToLower => eval_to_lower(value, filter.source_info, in_assert),
         FilterValue::UrlDecode => eval_url_decode(value, filter.source_info, in_assert),
         FilterValue::UrlEncode => eval_url_encode(value, filter.source_info, in_assert),
         FilterValue::XPath { expr, .. } => {
@@ -42,6 +42,7 @@ pub fn eval_jsonpath(
             };
             eval_jsonpath_json(&json, expr, variables)
         }
+        Value::List(_) | Value::Object(_) => eval_jsonpath_json(&value.to_json(), expr, variables),
         v => {
             let kind = RunnerErrorKind::FilterInvalidInput(v._type());
             Err(RunnerError::new(source_info, kind, assert))
@@ -114,5 +115,19 @@ pub mod tests {
             .unwrap(),
             Value::String("Hello".to_string())
         );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Object(vec![(
+                    "message".to_string(),
+                    Value::String("Hello".to_string())
+                )]),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("Hello".to_string())
+        );
     }
 }
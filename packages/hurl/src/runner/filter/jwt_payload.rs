@@ -0,0 +1,156 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::filter::base64_decode;
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Decodes the payload of a JSON Web Token `value` to an object.
+///
+/// The token is not verified: its payload segment is only Base64url decoded and parsed as JSON.
+pub fn eval_jwt_payload(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => match decode_payload(value) {
+            Ok(payload) => Ok(Some(Value::from_json(&payload))),
+            Err(reason) => {
+                let kind = RunnerErrorKind::FilterInvalidJwt {
+                    reason: reason.to_string(),
+                };
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Returns the JSON object payload of a `token`, or the reason why the token is malformed.
+fn decode_payload(token: &str) -> Result<serde_json::Value, &'static str> {
+    let segments = token.trim().split('.').collect::<Vec<_>>();
+    if segments.len() != 3 {
+        return Err("expecting three segments separated by dots");
+    }
+    let Some(payload) = base64_decode::decode(segments[1]) else {
+        return Err("payload is not Base64url encoded");
+    };
+    match serde_json::from_slice(&payload) {
+        Ok(payload @ serde_json::Value::Object(_)) => Ok(payload),
+        _ => Err("payload is not a JSON object"),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_jwt_payload() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 11)),
+            value: FilterValue::JwtPayload,
+        };
+        let token = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.\
+                     eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIiwiaWF0IjoxNTE2MjM5MDIyfQ.\
+                     SflKxwRJSMeKKF2QT4fwpMeJf36POk6yJV_adQssw5c";
+        let Value::Object(claims) = eval_filter(
+            &filter,
+            &Value::String(token.to_string()),
+            &variables,
+            false,
+        )
+        .unwrap()
+        .unwrap() else {
+            panic!("expecting an object");
+        };
+        assert!(claims.contains(&("name".to_string(), Value::String("John Doe".to_string()))));
+        assert!(claims.contains(&(
+            "iat".to_string(),
+            Value::Number(Number::Integer(1516239022))
+        )));
+        assert!(claims.contains(&("sub".to_string(), Value::String("1234567890".to_string()))));
+
+        let error = eval_filter(
+            &filter,
+            &Value::String("not.a-jwt".to_string()),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 11))
+        );
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidJwt {
+                reason: "expecting three segments separated by dots".to_string()
+            }
+        );
+
+        let error = eval_filter(
+            &filter,
+            &Value::String("a.b!.c".to_string()),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidJwt {
+                reason: "payload is not Base64url encoded".to_string()
+            }
+        );
+
+        // "WzFd" is the JSON array `[1]`.
+        let error = eval_filter(
+            &filter,
+            &Value::String("a.WzFd.c".to_string()),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidJwt {
+                reason: "payload is not a JSON object".to_string()
+            }
+        );
+
+        let error = eval_filter(&filter, &Value::Bool(true), &variables, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+}
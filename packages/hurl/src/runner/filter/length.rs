@@ -0,0 +1,114 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+/// Returns the length of a `value`: the number of characters of a string, the number of bytes
+/// of a buffer, or the number of elements of a list.
+pub fn eval_length(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let length = match value {
+        Value::String(value) => value.chars().count(),
+        Value::Bytes(values) => values.len(),
+        Value::List(values) => values.len(),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    Ok(Some(Value::Number(Number::Integer(length as i64))))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::VariableSet;
+
+    fn new_length_filter() -> Filter {
+        Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 7)),
+            value: FilterValue::Length,
+        }
+    }
+
+    #[test]
+    pub fn eval_filter_length() {
+        let variables = VariableSet::new();
+        let filter = new_length_filter();
+
+        let values = [
+            (Value::String("café".to_string()), 4),
+            (Value::String(String::new()), 0),
+            (Value::Bytes("café".as_bytes().to_vec()), 5),
+            (
+                Value::List(vec![
+                    Value::Number(Number::Integer(1)),
+                    Value::String("two".to_string()),
+                ]),
+                2,
+            ),
+        ];
+        for (value, expected) in values {
+            assert_eq!(
+                eval_filter(&filter, &value, &variables, false)
+                    .unwrap()
+                    .unwrap(),
+                Value::Number(Number::Integer(expected))
+            );
+        }
+    }
+
+    #[test]
+    pub fn eval_filter_length_error() {
+        let variables = VariableSet::new();
+        let filter = new_length_filter();
+
+        let values = [
+            (Value::Bool(true), "boolean"),
+            (Value::Date(chrono::DateTime::UNIX_EPOCH), "date"),
+            (Value::Nodeset(2), "nodeset"),
+            (Value::Null, "null"),
+            (Value::Number(Number::Float(1.5)), "float"),
+            (Value::Object(vec![]), "object"),
+            (Value::Unit, "unit"),
+        ];
+        for (value, type_) in values {
+            let error = eval_filter(&filter, &value, &variables, true)
+                .err()
+                .unwrap();
+            assert_eq!(
+                error.source_info,
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 7))
+            );
+            assert_eq!(
+                error.kind,
+                RunnerErrorKind::FilterInvalidInput(type_.to_string())
+            );
+            assert!(error.assert);
+        }
+    }
+}
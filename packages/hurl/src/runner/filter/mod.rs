@@ -20,6 +20,8 @@ pub use eval::eval_filters;
 pub use jsonpath::eval_jsonpath_json;
 pub use xpath::eval_xpath_doc;
 
+mod base64_decode;
+mod base64_encode;
 mod count;
 mod days_after_now;
 mod days_before_now;
@@ -29,13 +31,17 @@ mod format;
 mod html_escape;
 mod html_unescape;
 mod jsonpath;
+mod jwt_payload;
+mod length;
 mod nth;
 mod regex;
 mod replace;
 mod split;
 mod to_date;
 mod to_float;
+mod to_hex;
 mod to_int;
+mod to_lower;
 mod url_decode;
 mod url_encode;
 mod xpath;
@@ -46,7 +46,7 @@ pub fn eval_regex(
 #[cfg(test)]
 pub mod tests {
     use hurl_core::ast::{
-        Filter, FilterValue, RegexValue, SourceInfo, Template, TemplateElement, Whitespace,
+        Filter, FilterValue, Regex, RegexValue, SourceInfo, Template, TemplateElement, Whitespace,
     };
     use hurl_core::reader::Pos;
 
@@ -100,6 +100,46 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn eval_filter_regex_named_group() {
+        // regex /token=(?P<token>[A-Za-z0-9]+)/
+        let variables = VariableSet::new();
+        let whitespace = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 38)),
+            value: FilterValue::Regex {
+                space0: whitespace,
+                value: RegexValue::Regex(Regex {
+                    inner: regex::Regex::new("token=(?P<token>[A-Za-z0-9]+)").unwrap(),
+                }),
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("id=1&token=abc123&page=2".to_string()),
+                &variables,
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("abc123".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("id=1&page=2".to_string()),
+                &variables,
+                false,
+            )
+            .unwrap(),
+            None
+        );
+    }
+
     #[test]
     fn eval_filter_invalid_regex() {
         let variables = VariableSet::new();
@@ -51,6 +51,7 @@ pub mod tests {
     use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
 
     #[test]
+    #[allow(clippy::approx_constant)]
     pub fn eval_filter_to_float() {
         let variable = VariableSet::new();
         let filter = Filter {
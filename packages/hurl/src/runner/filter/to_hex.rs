@@ -0,0 +1,85 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Converts bytes `value` to a lowercase hexadecimal string.
+pub fn eval_to_hex(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Bytes(value) => Ok(Some(Value::String(hex::encode(value)))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::VariableSet;
+
+    #[test]
+    pub fn eval_filter_to_hex() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 6)),
+            value: FilterValue::ToHex,
+        };
+
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Bytes(vec![0xc3, 0xa9, 0x00, 0xff]),
+                &variables,
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("c3a900ff".to_string())
+        );
+        assert_eq!(
+            eval_filter(&filter, &Value::Bytes(vec![]), &variables, false)
+                .unwrap()
+                .unwrap(),
+            Value::String(String::new())
+        );
+
+        let error = eval_filter(
+            &filter,
+            &Value::String("café".to_string()),
+            &variables,
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("string".to_string())
+        );
+    }
+}
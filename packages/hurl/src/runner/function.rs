@@ -35,3 +35,15 @@ pub fn eval(function: &Function) -> Result<Value, RunnerError> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_uuid_is_evaluated_each_time() {
+        let uuid1 = eval(&Function::NewUuid).unwrap();
+        let uuid2 = eval(&Function::NewUuid).unwrap();
+        assert_ne!(uuid1, uuid2);
+    }
+}
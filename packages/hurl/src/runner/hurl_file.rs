@@ -16,20 +16,23 @@
  *
  */
 use std::thread;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use hurl_core::ast::VersionValue::VersionAnyLegacy;
 use hurl_core::ast::{Entry, OptionKind, SourceInfo};
 use hurl_core::error::DisplaySourceError;
-use hurl_core::input::Input;
+use hurl_core::input::{Input, InputKind};
 use hurl_core::parser;
 use hurl_core::typing::Count;
 
-use crate::http::{Call, Client};
+use crate::http::{proxy_secrets, Call, Client, Response, RETRY_AFTER};
+use crate::runner::body::StdinCache;
 use crate::runner::event::EventListener;
 use crate::runner::runner_options::RunnerOptions;
-use crate::runner::{entry, options, EntryResult, HurlResult, VariableSet};
+use crate::runner::{
+    entry, options, result, EntryResult, HurlResult, RunnerError, RunnerErrorKind, VariableSet,
+};
 use crate::util::logger::{ErrorFormat, Logger, LoggerOptions};
 use crate::util::term::{Stderr, Stdout, WriteMode};
 
@@ -124,7 +127,7 @@ pub fn run(
         &mut logger,
     );
 
-    if result.success && result.entries.last().is_none() {
+    if result.success && result.entries.iter().all(|e| e.skipped) {
         let filename = filename.map_or(String::new(), |f| f.to_string());
         logger.warning(&format!("No entry have been executed for file {filename}"));
     }
@@ -152,12 +155,24 @@ pub fn run_entries(
     let mut http_client = Client::new();
     let mut entries_result = vec![];
     let mut variables = variables.clone();
+    // Standard input can't be used by request bodies if the Hurl file has been read from it.
+    let stdin_consumed = filename.is_some_and(|f| matches!(f.kind(), InputKind::Stdin(_)));
+    let mut stdin = StdinCache::new(stdin_consumed);
     let mut entry_index = runner_options.from_entry.unwrap_or(1);
     let mut repeat_count = 0;
     let n = runner_options.to_entry.unwrap_or(entries.len());
     let default_verbosity = logger.verbosity;
     let start = Instant::now();
     let timestamp = Utc::now().timestamp();
+    // Total time spent pausing between entries, not accounted in the run duration.
+    let mut total_delay = Duration::ZERO;
+
+    // Credentials of the proxy are redacted from logs, like secrets.
+    if let Some(proxy) = &runner_options.proxy {
+        proxy_secrets(proxy)
+            .into_iter()
+            .for_each(|s| logger.add_secret(s));
+    }
 
     log_run_info(entries, runner_options, &variables, logger);
 
@@ -191,6 +206,7 @@ pub fn run_entries(
         log_run_entry(entry_index, logger);
 
         warn_deprecated(entry, filename, logger);
+        warn_unknown_options(entry, filename, logger);
 
         // We can report the progression of the run for --test mode.
         if let Some(listener) = listener {
@@ -225,6 +241,7 @@ pub fn run_entries(
         if options.skip {
             logger.debug("");
             logger.debug_important(&format!("Entry {entry_index} has been skipped"));
+            entries_result.push(skipped_entry_result(entry, entry_index));
             entry_index += 1;
             continue;
         }
@@ -233,6 +250,7 @@ pub fn run_entries(
         if options.repeat == Some(Count::Finite(0)) {
             logger.debug("");
             logger.debug_important(&format!("Entry {entry_index} is skipped (repeat 0 times)"));
+            entries_result.push(skipped_entry_result(entry, entry_index));
             entry_index += 1;
             continue;
         }
@@ -244,6 +262,7 @@ pub fn run_entries(
             logger.debug("");
             logger.debug_important(&format!("Delay entry {entry_index} (pause {delay_ms} ms)"));
             thread::sleep(delay);
+            total_delay += delay;
         };
 
         // Loop for executing HTTP run requests, with optional retry. Only "HTTP" errors in options
@@ -257,11 +276,12 @@ pub fn run_entries(
             &mut http_client,
             &options,
             &mut variables,
+            &mut stdin,
             stdout,
             logger,
         );
 
-        let has_error = results.last().map_or(false, |r| !r.errors.is_empty());
+        let has_error = results.last().is_some_and(|r| !r.errors.is_empty());
 
         entries_result.extend(results);
 
@@ -298,7 +318,21 @@ pub fn run_entries(
         }
     }
 
-    let duration = start.elapsed();
+    // With continue on error, errors are logged along the run: we recap the failed entries so
+    // they can be spotted at a glance.
+    if runner_options.continue_on_error {
+        let failed = failed_entries(&entries_result);
+        if !failed.is_empty() {
+            let failed = failed
+                .iter()
+                .map(|index| index.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            logger.warning(&format!("Failed entries: {failed}"));
+        }
+    }
+
+    let duration = start.elapsed().saturating_sub(total_delay);
     let cookies = http_client.cookie_storage(logger);
     let success = is_success(&entries_result);
     HurlResult {
@@ -321,14 +355,29 @@ fn run_request(
     http_client: &mut Client,
     options: &RunnerOptions,
     variables: &mut VariableSet,
+    stdin: &mut StdinCache,
     stdout: &mut Stdout,
     logger: &mut Logger,
 ) -> Vec<EntryResult> {
     let mut results = vec![];
     let mut retry_count = 1;
+    let start = Instant::now();
+
+    // Captures of a failed attempt must not be visible to the next attempts, so we keep the
+    // variables as they were before running the entry, to restore them before retrying.
+    let initial_variables =
+        (options.retry.is_some() || options.retry_rate_limited).then(|| variables.clone());
 
     loop {
-        let mut result = entry::run(entry, entry_index, http_client, variables, options, logger);
+        let mut result = entry::run(
+            entry,
+            entry_index,
+            http_client,
+            variables,
+            stdin,
+            options,
+            logger,
+        );
 
         // Check if we need to retry.
         let mut has_error = !result.errors.is_empty();
@@ -339,6 +388,14 @@ fn run_request(
         } else {
             false
         };
+        // We also stop retrying if the next attempt would start after the retry max time.
+        let retry_max_time_reached = match options.retry_max_time {
+            Some(max_time) if options.retry.is_some() && has_error && !retry_max_reached => {
+                let delay = retry_delay(&result, retry_count, options);
+                is_retry_max_time_reached(start.elapsed(), delay, max_time)
+            }
+            _ => false,
+        };
         // If `retry_max_reached` is true, we print now a warning, before displaying any assert
         // error so any potential error is the last thing displayed to the user.
         // If `retry_max_reached` is not true (for instance `retry`is true, or there is no error
@@ -347,10 +404,38 @@ fn run_request(
             logger.debug_important("Retry max count reached, no more retry");
             logger.debug("");
         }
+        if retry_max_time_reached {
+            logger.debug_important("Retry max time reached, no more retry");
+            logger.debug("");
+            // The last attempt errors are kept, with the actual values, and a poll timeout is added.
+            // It's an assert error if the entry only failed on asserts, so the exit code is unchanged.
+            if let Some(max_time) = options.retry_max_time {
+                let kind = RunnerErrorKind::PollTimeout {
+                    attempts: retry_count,
+                    max_time,
+                };
+                let source_info = get_retry_max_time_source_info(entry);
+                let assert = result.errors.iter().all(|e| e.assert);
+                result
+                    .errors
+                    .push(RunnerError::new(source_info, kind, assert));
+            }
+        }
 
         // We log eventual errors, only if we're not retrying the current entry...
         // The retry does not take into account a possible output Error
-        let retry = options.retry.is_some() && !retry_max_reached && has_error;
+        let retry =
+            options.retry.is_some() && !retry_max_reached && !retry_max_time_reached && has_error;
+        // Without retry option, a rate limited entry can still be retried once.
+        let retry = retry
+            || (options.retry.is_none()
+                && options.retry_rate_limited
+                && retry_count == 1
+                && has_error
+                && result
+                    .calls
+                    .last()
+                    .is_some_and(|call| is_rate_limited(&call.response)));
 
         // When --output is overridden on a request level, we output the HTTP response only if the
         // call has succeeded. Output errors are not taken into account for retrying requests.
@@ -376,16 +461,20 @@ fn run_request(
             break;
         }
 
-        let delay = options.retry_interval.as_millis();
+        let delay = retry_delay(results.last().unwrap(), retry_count, options);
         logger.debug("");
         logger.debug_important(&format!(
-            "Retry entry {entry_index} (x{retry_count} pause {delay} ms)"
+            "Retry entry {entry_index} (x{retry_count} pause {} ms)",
+            delay.as_millis()
         ));
         retry_count += 1;
+        if let Some(initial_variables) = &initial_variables {
+            *variables = initial_variables.clone();
+        }
         // If we retry the entry, we do not want to display a 'blank' progress bar during the
         // sleep delay. During the pause, we artificially show the previously erased progress
         // line.
-        thread::sleep(options.retry_interval);
+        thread::sleep(delay);
 
         // TODO: We keep this log because we don't want to change stderr with the changes
         // introduced by <https://github.com/Orange-OpenSource/hurl/issues/1973>
@@ -395,6 +484,87 @@ fn run_request(
     results
 }
 
+/// Returns the result of a skipped `entry`: the entry has not been executed, so it has no call,
+/// no capture and no assert, and is neither successful nor failed.
+fn skipped_entry_result(entry: &Entry, entry_index: usize) -> EntryResult {
+    EntryResult {
+        entry_index,
+        source_info: entry.source_info(),
+        skipped: true,
+        ..Default::default()
+    }
+}
+
+/// Returns `true` if an entry retry, starting after `delay` when `elapsed` time has been spent since
+/// the first attempt, would start after `max_time`.
+fn is_retry_max_time_reached(elapsed: Duration, delay: Duration, max_time: Duration) -> bool {
+    elapsed + delay > max_time
+}
+
+/// Maximum pause requested with a `Retry-After` header, when no maximum retry interval is set.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(300);
+
+/// Returns the pause before the `retry_count`-th retry (1-based) of an entry, given its last
+/// `result`.
+///
+/// A `Retry-After` header sent by the server takes precedence, bounded by the maximum retry
+/// interval (or [`MAX_RETRY_AFTER`] if it's not set). Otherwise, the retry interval is used,
+/// doubled before each retry if a maximum retry interval has been set. No jitter is added.
+fn retry_delay(result: &EntryResult, retry_count: usize, options: &RunnerOptions) -> Duration {
+    let retry_after = result
+        .calls
+        .last()
+        .and_then(|call| retry_after(&call.response, Utc::now()));
+    match retry_after {
+        Some(delay) => delay.min(options.retry_max_interval.unwrap_or(MAX_RETRY_AFTER)),
+        None => backoff_interval(
+            options.retry_interval,
+            options.retry_max_interval,
+            retry_count,
+        ),
+    }
+}
+
+/// Returns the interval before the `retry_count`-th retry (1-based): without `max_interval`, the
+/// interval is constant, otherwise it doubles at each retry, up to `max_interval`.
+fn backoff_interval(
+    interval: Duration,
+    max_interval: Option<Duration>,
+    retry_count: usize,
+) -> Duration {
+    let Some(max_interval) = max_interval else {
+        return interval;
+    };
+    let exponent = retry_count.saturating_sub(1).min(31) as u32;
+    interval
+        .saturating_mul(2_u32.pow(exponent))
+        .min(max_interval)
+}
+
+/// Returns the pause requested by the server with a `Retry-After` header, on a 429 (Too Many
+/// Requests) or 503 (Service Unavailable) `response`.
+///
+/// The header value can be a number of seconds or an HTTP date, see
+/// <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Retry-After>
+fn retry_after(response: &Response, now: DateTime<Utc>) -> Option<Duration> {
+    if !is_rate_limited(response) {
+        return None;
+    }
+    let value = response.headers.get(RETRY_AFTER)?.value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    let delay = date.with_timezone(&Utc) - now;
+    Some(delay.to_std().unwrap_or_default())
+}
+
+/// Returns `true` if this `response` is a 429 (Too Many Requests) or 503 (Service Unavailable)
+/// response.
+fn is_rate_limited(response: &Response) -> bool {
+    response.status == 429 || response.status == 503
+}
+
 /// Use source_info from output option if this option has been defined
 fn get_output_source_info(entry: &Entry) -> SourceInfo {
     let mut source_info = entry.source_info();
@@ -406,6 +576,20 @@ fn get_output_source_info(entry: &Entry) -> SourceInfo {
     source_info
 }
 
+/// Use source_info from the retry max time option name if this option has been defined
+fn get_retry_max_time_source_info(entry: &Entry) -> SourceInfo {
+    let mut source_info = entry.source_info();
+    for option_entry in entry.request.options() {
+        if let OptionKind::RetryMaxTime(_) = option_entry.kind {
+            source_info = SourceInfo::new(
+                option_entry.space0.source_info.end,
+                option_entry.space1.source_info.start,
+            );
+        }
+    }
+    source_info
+}
+
 /// Returns `true` if all the entries results are successful, `false` otherwise.
 ///
 /// For a given list of entry results, only the last one on the same index is checked.
@@ -434,6 +618,17 @@ fn is_success(entries: &[EntryResult]) -> bool {
     true
 }
 
+/// Returns the indexes of the failed entries.
+///
+/// As in [`RunSummary`](crate::runner::RunSummary), only the last entry result on the same index is
+/// checked.
+fn failed_entries(entries: &[EntryResult]) -> Vec<usize> {
+    result::last_results(entries)
+        .filter(|entry| !entry.errors.is_empty())
+        .map(|entry| entry.entry_index)
+        .collect()
+}
+
 /// Logs deprecated syntax and provides alternatives.
 fn warn_deprecated(entry: &Entry, filename: Option<&Input>, logger: &mut Logger) {
     let filename = filename.map_or(String::new(), |f| f.to_string());
@@ -449,6 +644,25 @@ fn warn_deprecated(entry: &Entry, filename: Option<&Input>, logger: &mut Logger)
     }
 }
 
+/// Logs options unknown to this version of Hurl, that are ignored.
+fn warn_unknown_options(entry: &Entry, filename: Option<&Input>, logger: &mut Logger) {
+    let filename = filename.map_or(String::new(), |f| f.to_string());
+    for option in entry.request.options() {
+        let OptionKind::Unknown(unknown) = &option.kind else {
+            continue;
+        };
+        let name = &unknown.name;
+        let pos = option.space0.source_info.end;
+        let (line, column) = (pos.line, pos.column);
+        let mut message =
+            format!("{filename}:{line}:{column} option '{name}' is unknown and ignored");
+        if let Some(suggestion) = parser::suggest_option_name(name) {
+            message.push_str(&format!(", did you mean '{suggestion}'?"));
+        }
+        logger.warning(&message);
+    }
+}
+
 // Returns the list of options that have non-default values.
 fn get_non_default_options(options: &RunnerOptions) -> Vec<(&'static str, String)> {
     let default_options = RunnerOptions::default();
@@ -570,8 +784,14 @@ fn log_run_entry(entry_index: usize, logger: &mut Logger) {
 
 #[cfg(test)]
 mod test {
+    use std::str::FromStr;
+
     use super::*;
-    use crate::runner::RunnerOptionsBuilder;
+    use hurl_core::reader::Pos;
+
+    use crate::http::{CurlCmd, Header, HeaderVec, HttpVersion, Request, Timings, Url};
+    use crate::runner::{RunnerOptionsBuilder, Value};
+    use crate::util::logger::LoggerOptionsBuilder;
 
     #[test]
     fn get_non_default_options_returns_empty_when_default() {
@@ -593,4 +813,249 @@ mod test {
         assert_eq!(first_non_default.0, "delay");
         assert_eq!(first_non_default.1, "500ms");
     }
+
+    #[test]
+    fn retry_max_time_cuts_off_the_next_retry() {
+        let max_time = Duration::from_secs(30);
+        let delay = Duration::from_millis(500);
+        assert!(!is_retry_max_time_reached(
+            Duration::from_secs(10),
+            delay,
+            max_time
+        ));
+        // The next retry would start exactly at the max time: it's still run.
+        assert!(!is_retry_max_time_reached(
+            Duration::from_millis(29_500),
+            delay,
+            max_time
+        ));
+        assert!(is_retry_max_time_reached(
+            Duration::from_millis(29_501),
+            delay,
+            max_time
+        ));
+        // A long pause can exceed the max time, even on the first attempt.
+        assert!(is_retry_max_time_reached(
+            Duration::ZERO,
+            Duration::from_secs(60),
+            max_time
+        ));
+    }
+
+    #[test]
+    fn backoff_interval_is_constant_without_max_interval() {
+        let interval = Duration::from_millis(1000);
+        let delays = (1..=4)
+            .map(|count| backoff_interval(interval, None, count).as_millis())
+            .collect::<Vec<_>>();
+        assert_eq!(delays, vec![1000, 1000, 1000, 1000]);
+    }
+
+    #[test]
+    fn backoff_interval_is_exponential_with_max_interval() {
+        let interval = Duration::from_millis(1000);
+        let max_interval = Some(Duration::from_millis(10000));
+        let delays = (1..=6)
+            .map(|count| backoff_interval(interval, max_interval, count).as_millis())
+            .collect::<Vec<_>>();
+        assert_eq!(delays, vec![1000, 2000, 4000, 8000, 10000, 10000]);
+
+        // Large retry counts do not overflow.
+        assert_eq!(
+            backoff_interval(interval, max_interval, 1000).as_millis(),
+            10000
+        );
+    }
+
+    fn response(status: u32, retry_after: Option<&str>) -> Response {
+        let mut headers = HeaderVec::new();
+        if let Some(value) = retry_after {
+            headers.push(Header::new(RETRY_AFTER, value));
+        }
+        Response::new(
+            HttpVersion::Http11,
+            status,
+            headers,
+            vec![],
+            Duration::default(),
+            Url::from_str("http://localhost").unwrap(),
+            None,
+        )
+    }
+
+    #[test]
+    fn retry_after_from_header() {
+        let now = DateTime::parse_from_rfc3339("2015-10-21T07:28:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(
+            retry_after(&response(429, Some("120")), now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            retry_after(&response(503, Some("Wed, 21 Oct 2015 07:28:30 GMT")), now),
+            Some(Duration::from_secs(30))
+        );
+        // A date in the past means no pause.
+        assert_eq!(
+            retry_after(&response(503, Some("Wed, 21 Oct 2015 07:27:00 GMT")), now),
+            Some(Duration::ZERO)
+        );
+
+        assert_eq!(retry_after(&response(429, None), now), None);
+        assert_eq!(retry_after(&response(429, Some("soon")), now), None);
+        // Only 429 and 503 responses are taken into account.
+        assert_eq!(retry_after(&response(500, Some("120")), now), None);
+    }
+
+    #[test]
+    fn skipped_entries_are_recorded_without_being_run() {
+        // Requests to an unreachable host: skipped entries must not be run.
+        let content = r#"GET http://localhost:1/feature
+[Options]
+skip-unless: {{feature_enabled}}
+HTTP 200
+
+GET http://localhost:1/legacy
+[Options]
+skip: true
+HTTP 200
+
+GET http://localhost:1/empty
+[Options]
+repeat: 0
+HTTP 200
+"#;
+        let mut variables = VariableSet::new();
+        variables
+            .insert("feature_enabled".to_string(), Value::Bool(false))
+            .unwrap();
+        let logger_options = LoggerOptionsBuilder::new().build();
+
+        let result = run(
+            content,
+            None,
+            &RunnerOptions::default(),
+            &variables,
+            &logger_options,
+        )
+        .unwrap();
+
+        assert!(result.success);
+        assert_eq!(result.entries.len(), 3);
+        for (index, entry) in result.entries.iter().enumerate() {
+            assert_eq!(entry.entry_index, index + 1);
+            assert!(entry.skipped);
+            assert!(entry.calls.is_empty());
+            assert!(entry.errors.is_empty());
+        }
+    }
+
+    #[test]
+    fn dry_run_entries_are_evaluated_without_being_run() {
+        // Requests to an unreachable host: in dry run, no transfer is done and nothing is asserted.
+        let content = r#"GET http://localhost:1/login
+HTTP 200
+[Captures]
+token: header "X-Token"
+
+GET http://localhost:1/account
+Authorization: Bearer {{token}}
+HTTP 200
+[Asserts]
+jsonpath "$.id" == 1
+"#;
+        let options = RunnerOptionsBuilder::new().dry_run(true).build();
+        let logger_options = LoggerOptionsBuilder::new().build();
+
+        let result = run(
+            content,
+            None,
+            &options,
+            &VariableSet::new(),
+            &logger_options,
+        )
+        .unwrap();
+
+        assert!(result.success);
+        assert_eq!(result.entries.len(), 2);
+        for (index, entry) in result.entries.iter().enumerate() {
+            assert_eq!(entry.entry_index, index + 1);
+            assert!(!entry.skipped);
+            assert!(entry.calls.is_empty());
+            assert!(entry.captures.is_empty());
+            assert!(entry.asserts.is_empty());
+            assert!(entry.errors.is_empty());
+        }
+        // The first request is evaluated, the second one uses an undefined captured variable.
+        assert_eq!(
+            result.entries[0].curl_cmd.to_string(),
+            "curl 'http://localhost:1/login'"
+        );
+        assert_eq!(result.entries[1].curl_cmd, CurlCmd::default());
+    }
+
+    #[test]
+    fn failed_entries_are_the_last_results_in_error() {
+        let error = RunnerError::new(
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            RunnerErrorKind::AssertStatus {
+                actual: "500".to_string(),
+            },
+            true,
+        );
+        let entry_result = |entry_index, failed| EntryResult {
+            entry_index,
+            errors: if failed { vec![error.clone()] } else { vec![] },
+            ..Default::default()
+        };
+        let entries = vec![
+            entry_result(1, true),
+            // Entry 2 fails, then succeeds on retry.
+            entry_result(2, true),
+            entry_result(2, false),
+            // Entry 3 succeeds, then fails on the next repeat.
+            entry_result(3, false),
+            entry_result(3, true),
+            entry_result(4, false),
+            entry_result(5, true),
+        ];
+        assert_eq!(failed_entries(&entries), vec![1, 3, 5]);
+        assert!(failed_entries(&[]).is_empty());
+    }
+
+    #[test]
+    fn rate_limited_responses() {
+        assert!(is_rate_limited(&response(429, None)));
+        assert!(is_rate_limited(&response(503, Some("120"))));
+        assert!(!is_rate_limited(&response(500, Some("120"))));
+        assert!(!is_rate_limited(&response(200, None)));
+    }
+
+    #[test]
+    fn retry_after_is_bounded() {
+        let call = Call {
+            request: Request::new(
+                "GET",
+                Url::from_str("http://localhost").unwrap(),
+                HeaderVec::new(),
+                vec![],
+            ),
+            response: response(429, Some("3600")),
+            timings: Timings::default(),
+        };
+        let result = EntryResult {
+            calls: vec![call],
+            ..Default::default()
+        };
+
+        let options = RunnerOptionsBuilder::new().build();
+        assert_eq!(retry_delay(&result, 1, &options), MAX_RETRY_AFTER);
+
+        let options = RunnerOptionsBuilder::new()
+            .retry_max_interval(Some(Duration::from_secs(10)))
+            .build();
+        assert_eq!(retry_delay(&result, 1, &options), Duration::from_secs(10));
+    }
 }
@@ -16,13 +16,37 @@
  *
  */
 use hurl_core::ast::{
-    JsonListElement, JsonObjectElement, JsonValue, Placeholder, Template, TemplateElement,
+    JsonListElement, JsonObjectElement, JsonValue, NdJson, Placeholder, Template, TemplateElement,
 };
 use hurl_core::parser::{parse_json_boolean, parse_json_null, parse_json_number};
 use hurl_core::reader::Reader;
 
 use crate::runner::error::{RunnerError, RunnerErrorKind};
-use crate::runner::{expr, VariableSet};
+use crate::runner::{expr, Value, VariableSet};
+
+/// Evaluates a newline-delimited JSON to a string given a set of `variables`.
+/// Each value is rendered on its own line, without whitespace, and followed by a newline.
+pub fn eval_ndjson(ndjson: &NdJson, variables: &VariableSet) -> Result<String, RunnerError> {
+    let mut s = String::new();
+    for value in &ndjson.values {
+        s.push_str(&eval_json_value(&value.value, variables, false)?);
+        s.push('\n');
+    }
+    Ok(s)
+}
+
+/// Normalizes a newline-delimited JSON `text`, so that two texts with the same values are equal,
+/// whatever their whitespace and empty lines: each value is serialized compactly, followed by a
+/// newline. Returns `None` if `text` is not a valid newline-delimited JSON.
+pub fn normalize_ndjson(text: &str) -> Option<String> {
+    let mut s = String::new();
+    for line in text.lines().filter(|l| !l.trim().is_empty()) {
+        let value = serde_json::from_str::<serde_json::Value>(line).ok()?;
+        s.push_str(&value.to_string());
+        s.push('\n');
+    }
+    Some(s)
+}
 
 /// Evaluates a JSON value to a string given a set of `variables`.
 /// If `keep_whitespace` is true, whitespace is preserved from the JSonValue, otherwise
@@ -65,7 +89,17 @@ pub fn eval_json_value(
             }
         }
         JsonValue::Placeholder(Placeholder { expr, .. }) => {
-            let s = expr::render(expr, variables)?;
+            let value = expr::eval(expr, variables)?;
+            // Lists and objects (captured from a JSON response for instance) are injected as JSON.
+            if let Value::List(_) | Value::Object(_) = value {
+                return Ok(value.to_json().to_string());
+            }
+            let Some(s) = value.render() else {
+                let kind = RunnerErrorKind::UnrenderableExpression {
+                    value: value.to_string(),
+                };
+                return Err(RunnerError::new(expr.source_info, kind, false));
+            };
 
             // The String can only be null, a bool, a number
             // It will be easier when your variables value have a type
@@ -112,14 +146,15 @@ fn eval_json_object_element(
     variables: &VariableSet,
     keep_whitespace: bool,
 ) -> Result<String, RunnerError> {
+    let name = eval_json_template(&element.name, variables)?;
     let value = eval_json_value(&element.value, variables, keep_whitespace)?;
     if keep_whitespace {
         Ok(format!(
             "{}\"{}\"{}:{}{}{}",
-            element.space0, element.name, element.space1, element.space2, value, element.space3
+            element.space0, name, element.space1, element.space2, value, element.space3
         ))
     } else {
-        Ok(format!("\"{}\":{}", element.name, value))
+        Ok(format!("\"{name}\":{value}"))
     }
 }
 
@@ -176,12 +211,13 @@ fn encode_json_char(c: char) -> String {
 
 #[cfg(test)]
 mod tests {
-    use crate::runner::Value;
     use hurl_core::ast::*;
+    use hurl_core::parser::parse_json;
     use hurl_core::reader::Pos;
 
     use super::super::error::RunnerErrorKind;
     use super::*;
+    use crate::runner::Number;
 
     pub fn json_hello_world_value() -> JsonValue {
         // "hello\u0020{{name}}!"
@@ -393,6 +429,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_ndjson() {
+        let mut variables = VariableSet::new();
+        variables
+            .insert("name".to_string(), Value::String("Bob".to_string()))
+            .unwrap();
+        let ndjson = NdJson {
+            values: vec![
+                NdJsonValue {
+                    space0: String::new(),
+                    value: json_person_value(),
+                },
+                NdJsonValue {
+                    space0: "\n\n".to_string(),
+                    value: JsonValue::List {
+                        space0: " ".to_string(),
+                        elements: vec![JsonListElement {
+                            space0: String::new(),
+                            value: json_hello_world_value(),
+                            space1: " ".to_string(),
+                        }],
+                    },
+                },
+            ],
+        };
+        assert_eq!(
+            eval_ndjson(&ndjson, &variables).unwrap(),
+            "{\"firstName\":\"John\"}\n[\"Hello\\u0020Bob!\"]\n".to_string()
+        );
+    }
+
     #[test]
     fn test_escape_sequence() {
         let variables = VariableSet::new();
@@ -489,4 +556,60 @@ mod tests {
             r#"{"firstName":"John"}"#.to_string()
         );
     }
+
+    #[test]
+    fn test_templated_key() {
+        let mut variables = VariableSet::new();
+        variables
+            .insert("field".to_string(), Value::String("na\"me".to_string()))
+            .unwrap();
+        variables
+            .insert("username".to_string(), Value::String("Bob".to_string()))
+            .unwrap();
+        let mut reader = Reader::new(r#"{"{{field}}": "{{username}}", "id": 1}"#);
+        let value = parse_json(&mut reader).unwrap();
+        assert_eq!(
+            eval_json_value(&value, &variables, true).unwrap(),
+            r#"{"na\"me": "Bob", "id": 1}"#.to_string()
+        );
+
+        let variables = VariableSet::new();
+        let error = eval_json_value(&value, &variables, true).err().unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::TemplateVariableNotDefined {
+                name: "field".to_string()
+            }
+        );
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 5), Pos::new(1, 10))
+        );
+    }
+
+    #[test]
+    fn test_typed_placeholder() {
+        let mut variables = VariableSet::new();
+        variables
+            .insert("count".to_string(), Value::Number(Number::Integer(3)))
+            .unwrap();
+        variables
+            .insert("enabled".to_string(), Value::Bool(true))
+            .unwrap();
+        variables
+            .insert(
+                "tags".to_string(),
+                Value::List(vec![
+                    Value::String("a".to_string()),
+                    Value::Number(Number::Integer(1)),
+                ]),
+            )
+            .unwrap();
+        let mut reader = Reader::new("[{{count}}, {{enabled}}, {{tags}}]");
+        let value = parse_json(&mut reader).unwrap();
+        assert_eq!(
+            eval_json_value(&value, &variables, true).unwrap(),
+            r#"[3, true, ["a",1]]"#.to_string()
+        );
+    }
 }
@@ -0,0 +1,761 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use regex::Regex;
+use serde_json::{Map, Value};
+
+/// Maximum depth of nested schemas, to protect against recursive `$ref`.
+const MAX_DEPTH: usize = 256;
+
+/// Validates a JSON `instance` against a JSON `schema` (draft-07).
+///
+/// Returns the list of violations, each one prefixed by the path of the invalid value (for
+/// instance `$.books[0].title: expected type string, got number`). An empty list means that
+/// `instance` is valid. An error is returned if `schema` is not a valid schema.
+///
+/// The validator passes the required tests of the official draft-07 test suite, except for these
+/// unsupported features:
+///
+/// - `$ref` can only reference the root schema with a JSON pointer (for instance
+///   `#/definitions/book`). Remote references, and references to the draft-07 meta-schema or to a
+///   schema identified by `$id` are reported as errors.
+/// - Annotation keywords (`format`, `contentEncoding`, `contentMediaType`, `title` etc...) are not
+///   validated: a `date-time`, `email`, `uri` etc... format accepts any string.
+pub fn validate(schema: &Value, instance: &Value) -> Result<Vec<String>, String> {
+    let validator = Validator { root: schema };
+    let mut violations = vec![];
+    validator.validate(schema, instance, "$", 0, &mut violations)?;
+    Ok(violations)
+}
+
+struct Validator<'a> {
+    root: &'a Value,
+}
+
+impl Validator<'_> {
+    fn validate(
+        &self,
+        schema: &Value,
+        instance: &Value,
+        path: &str,
+        depth: usize,
+        violations: &mut Vec<String>,
+    ) -> Result<(), String> {
+        if depth > MAX_DEPTH {
+            return Err("too many nested schemas".to_string());
+        }
+        let schema = match schema {
+            Value::Bool(true) => return Ok(()),
+            Value::Bool(false) => {
+                violations.push(format!("{path}: no value is allowed"));
+                return Ok(());
+            }
+            Value::Object(schema) => schema,
+            _ => return Err(format!("schema must be an object or a boolean at {path}")),
+        };
+
+        // In draft-07, keywords next to a `$ref` are ignored.
+        if let Some(reference) = schema.get("$ref") {
+            let schema = self.resolve(reference)?;
+            return self.validate(schema, instance, path, depth + 1, violations);
+        }
+
+        self.validate_type(schema, instance, path, violations)?;
+        self.validate_enum(schema, instance, path, violations)?;
+        self.validate_combinators(schema, instance, path, depth, violations)?;
+        match instance {
+            Value::Number(_) => self.validate_number(schema, instance, path, violations),
+            Value::String(s) => self.validate_string(schema, s, path, violations),
+            Value::Array(values) => self.validate_array(schema, values, path, depth, violations),
+            Value::Object(values) => self.validate_object(schema, values, path, depth, violations),
+            _ => Ok(()),
+        }
+    }
+
+    /// Resolves a `reference` to a schema, relative to the root schema.
+    fn resolve(&self, reference: &Value) -> Result<&Value, String> {
+        let Some(reference) = reference.as_str() else {
+            return Err("$ref must be a string".to_string());
+        };
+        let Some(pointer) = reference.strip_prefix('#') else {
+            return Err(format!("$ref <{reference}> is not supported"));
+        };
+        let pointer = percent_decode(pointer);
+        match self.root.pointer(&pointer) {
+            Some(schema) => Ok(schema),
+            None => Err(format!("$ref <{reference}> can not be resolved")),
+        }
+    }
+
+    fn validate_type(
+        &self,
+        schema: &Map<String, Value>,
+        instance: &Value,
+        path: &str,
+        violations: &mut Vec<String>,
+    ) -> Result<(), String> {
+        let types = match schema.get("type") {
+            None => return Ok(()),
+            Some(Value::String(t)) => vec![t.as_str()],
+            Some(Value::Array(types)) => types
+                .iter()
+                .map(|t| {
+                    t.as_str()
+                        .ok_or("type must be a string or an array of strings")
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            Some(_) => return Err("type must be a string or an array of strings".to_string()),
+        };
+        let mut valid = false;
+        for t in &types {
+            valid |= match *t {
+                "null" => instance.is_null(),
+                "boolean" => instance.is_boolean(),
+                "object" => instance.is_object(),
+                "array" => instance.is_array(),
+                "number" => instance.is_number(),
+                "string" => instance.is_string(),
+                "integer" => is_integer(instance),
+                _ => return Err(format!("unknown type <{t}>")),
+            };
+        }
+        if !valid {
+            violations.push(format!(
+                "{path}: expected type {}, got {}",
+                types.join(" or "),
+                type_name(instance)
+            ));
+        }
+        Ok(())
+    }
+
+    fn validate_enum(
+        &self,
+        schema: &Map<String, Value>,
+        instance: &Value,
+        path: &str,
+        violations: &mut Vec<String>,
+    ) -> Result<(), String> {
+        if let Some(values) = schema.get("enum") {
+            let Some(values) = values.as_array() else {
+                return Err("enum must be an array".to_string());
+            };
+            if !values.iter().any(|v| json_equal(v, instance)) {
+                violations.push(format!(
+                    "{path}: value {instance} is not one of the enum values"
+                ));
+            }
+        }
+        if let Some(value) = schema.get("const") {
+            if !json_equal(value, instance) {
+                violations.push(format!("{path}: expected constant {value}, got {instance}"));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_combinators(
+        &self,
+        schema: &Map<String, Value>,
+        instance: &Value,
+        path: &str,
+        depth: usize,
+        violations: &mut Vec<String>,
+    ) -> Result<(), String> {
+        if let Some(schemas) = schema.get("allOf") {
+            for schema in schema_array("allOf", schemas)? {
+                self.validate(schema, instance, path, depth + 1, violations)?;
+            }
+        }
+        if let Some(schemas) = schema.get("anyOf") {
+            let mut valid = false;
+            for schema in schema_array("anyOf", schemas)? {
+                valid |= self.is_valid(schema, instance, path, depth)?;
+            }
+            if !valid {
+                violations.push(format!("{path}: value doesn't match any schema of anyOf"));
+            }
+        }
+        if let Some(schemas) = schema.get("oneOf") {
+            let mut count = 0;
+            for schema in schema_array("oneOf", schemas)? {
+                if self.is_valid(schema, instance, path, depth)? {
+                    count += 1;
+                }
+            }
+            if count != 1 {
+                violations.push(format!(
+                    "{path}: value matches {count} schemas of oneOf instead of exactly one"
+                ));
+            }
+        }
+        if let Some(schema) = schema.get("not") {
+            if self.is_valid(schema, instance, path, depth)? {
+                violations.push(format!("{path}: value must not match the schema of not"));
+            }
+        }
+        if let Some(condition) = schema.get("if") {
+            let branch = if self.is_valid(condition, instance, path, depth)? {
+                schema.get("then")
+            } else {
+                schema.get("else")
+            };
+            if let Some(branch) = branch {
+                self.validate(branch, instance, path, depth + 1, violations)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_number(
+        &self,
+        schema: &Map<String, Value>,
+        instance: &Value,
+        path: &str,
+        violations: &mut Vec<String>,
+    ) -> Result<(), String> {
+        let Some(n) = instance.as_f64() else {
+            return Ok(());
+        };
+        if let Some(m) = number(schema, "multipleOf")? {
+            if m <= 0.0 {
+                return Err("multipleOf must be strictly greater than 0".to_string());
+            }
+            let q = n / m;
+            if (q - q.round()).abs() > f64::EPSILON * q.abs().max(1.0) {
+                violations.push(format!("{path}: {instance} is not a multiple of {m}"));
+            }
+        }
+        if let Some(max) = number(schema, "maximum")? {
+            if n > max {
+                violations.push(format!(
+                    "{path}: {instance} is greater than the maximum {max}"
+                ));
+            }
+        }
+        if let Some(max) = number(schema, "exclusiveMaximum")? {
+            if n >= max {
+                violations.push(format!(
+                    "{path}: {instance} is greater than or equal to the exclusive maximum {max}"
+                ));
+            }
+        }
+        if let Some(min) = number(schema, "minimum")? {
+            if n < min {
+                violations.push(format!("{path}: {instance} is less than the minimum {min}"));
+            }
+        }
+        if let Some(min) = number(schema, "exclusiveMinimum")? {
+            if n <= min {
+                violations.push(format!(
+                    "{path}: {instance} is less than or equal to the exclusive minimum {min}"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_string(
+        &self,
+        schema: &Map<String, Value>,
+        s: &str,
+        path: &str,
+        violations: &mut Vec<String>,
+    ) -> Result<(), String> {
+        let len = s.chars().count();
+        if let Some(max) = count(schema, "maxLength")? {
+            if len > max {
+                violations.push(format!("{path}: string is longer than {max} characters"));
+            }
+        }
+        if let Some(min) = count(schema, "minLength")? {
+            if len < min {
+                violations.push(format!("{path}: string is shorter than {min} characters"));
+            }
+        }
+        if let Some(pattern) = schema.get("pattern") {
+            let Some(pattern) = pattern.as_str() else {
+                return Err("pattern must be a string".to_string());
+            };
+            let Ok(re) = Regex::new(pattern) else {
+                return Err(format!("pattern <{pattern}> is not a valid regex"));
+            };
+            if !re.is_match(s) {
+                violations.push(format!("{path}: string doesn't match pattern <{pattern}>"));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_array(
+        &self,
+        schema: &Map<String, Value>,
+        values: &[Value],
+        path: &str,
+        depth: usize,
+        violations: &mut Vec<String>,
+    ) -> Result<(), String> {
+        match schema.get("items") {
+            Some(Value::Array(schemas)) => {
+                for (i, (schema, value)) in schemas.iter().zip(values).enumerate() {
+                    let path = format!("{path}[{i}]");
+                    self.validate(schema, value, &path, depth + 1, violations)?;
+                }
+                if let Some(schema) = schema.get("additionalItems") {
+                    for (i, value) in values.iter().enumerate().skip(schemas.len()) {
+                        let path = format!("{path}[{i}]");
+                        self.validate(schema, value, &path, depth + 1, violations)?;
+                    }
+                }
+            }
+            Some(schema) => {
+                for (i, value) in values.iter().enumerate() {
+                    let path = format!("{path}[{i}]");
+                    self.validate(schema, value, &path, depth + 1, violations)?;
+                }
+            }
+            None => {}
+        }
+        if let Some(max) = count(schema, "maxItems")? {
+            if values.len() > max {
+                violations.push(format!("{path}: array has more than {max} items"));
+            }
+        }
+        if let Some(min) = count(schema, "minItems")? {
+            if values.len() < min {
+                violations.push(format!("{path}: array has less than {min} items"));
+            }
+        }
+        if let Some(Value::Bool(true)) = schema.get("uniqueItems") {
+            let duplicate = values
+                .iter()
+                .enumerate()
+                .any(|(i, v)| values[..i].iter().any(|w| json_equal(v, w)));
+            if duplicate {
+                violations.push(format!("{path}: array items are not unique"));
+            }
+        }
+        if let Some(schema) = schema.get("contains") {
+            let mut valid = false;
+            for (i, value) in values.iter().enumerate() {
+                let path = format!("{path}[{i}]");
+                valid |= self.is_valid(schema, value, &path, depth)?;
+            }
+            if !valid {
+                violations.push(format!("{path}: no item matches the schema of contains"));
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_object(
+        &self,
+        schema: &Map<String, Value>,
+        values: &Map<String, Value>,
+        path: &str,
+        depth: usize,
+        violations: &mut Vec<String>,
+    ) -> Result<(), String> {
+        if let Some(max) = count(schema, "maxProperties")? {
+            if values.len() > max {
+                violations.push(format!("{path}: object has more than {max} properties"));
+            }
+        }
+        if let Some(min) = count(schema, "minProperties")? {
+            if values.len() < min {
+                violations.push(format!("{path}: object has less than {min} properties"));
+            }
+        }
+        if let Some(required) = schema.get("required") {
+            for name in string_array("required", required)? {
+                if !values.contains_key(name) {
+                    violations.push(format!("{path}: missing required property <{name}>"));
+                }
+            }
+        }
+
+        let properties = match schema.get("properties") {
+            None => None,
+            Some(Value::Object(properties)) => Some(properties),
+            Some(_) => return Err("properties must be an object".to_string()),
+        };
+        let pattern_properties = match schema.get("patternProperties") {
+            None => vec![],
+            Some(Value::Object(patterns)) => patterns
+                .iter()
+                .map(|(pattern, schema)| match Regex::new(pattern) {
+                    Ok(re) => Ok((re, schema)),
+                    Err(_) => Err(format!("pattern <{pattern}> is not a valid regex")),
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            Some(_) => return Err("patternProperties must be an object".to_string()),
+        };
+        for (name, value) in values {
+            let child = child_path(path, name);
+            let mut matched = false;
+            if let Some(schema) = properties.and_then(|p| p.get(name)) {
+                matched = true;
+                self.validate(schema, value, &child, depth + 1, violations)?;
+            }
+            for (re, schema) in &pattern_properties {
+                if re.is_match(name) {
+                    matched = true;
+                    self.validate(schema, value, &child, depth + 1, violations)?;
+                }
+            }
+            if matched {
+                continue;
+            }
+            match schema.get("additionalProperties") {
+                Some(Value::Bool(false)) => {
+                    violations.push(format!(
+                        "{path}: additional property <{name}> is not allowed"
+                    ));
+                }
+                Some(schema) => self.validate(schema, value, &child, depth + 1, violations)?,
+                None => {}
+            }
+        }
+
+        if let Some(dependencies) = schema.get("dependencies") {
+            let Some(dependencies) = dependencies.as_object() else {
+                return Err("dependencies must be an object".to_string());
+            };
+            for (name, dependency) in dependencies {
+                if !values.contains_key(name) {
+                    continue;
+                }
+                match dependency {
+                    Value::Array(_) => {
+                        for required in string_array("dependencies", dependency)? {
+                            if !values.contains_key(required) {
+                                violations.push(format!(
+                                    "{path}: property <{required}> is required by property <{name}>"
+                                ));
+                            }
+                        }
+                    }
+                    schema => {
+                        let instance = Value::Object(values.clone());
+                        self.validate(schema, &instance, path, depth + 1, violations)?;
+                    }
+                }
+            }
+        }
+        if let Some(schema) = schema.get("propertyNames") {
+            for name in values.keys() {
+                let instance = Value::String(name.clone());
+                if !self.is_valid(schema, &instance, path, depth)? {
+                    violations.push(format!(
+                        "{path}: property name <{name}> doesn't match the schema of propertyNames"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if `instance` is valid against `schema`, without reporting violations.
+    fn is_valid(
+        &self,
+        schema: &Value,
+        instance: &Value,
+        path: &str,
+        depth: usize,
+    ) -> Result<bool, String> {
+        let mut violations = vec![];
+        self.validate(schema, instance, path, depth + 1, &mut violations)?;
+        Ok(violations.is_empty())
+    }
+}
+
+/// Returns the numeric keyword `name` of a `schema`.
+fn number(schema: &Map<String, Value>, name: &str) -> Result<Option<f64>, String> {
+    match schema.get(name) {
+        None => Ok(None),
+        Some(value) => match value.as_f64() {
+            Some(n) => Ok(Some(n)),
+            None => Err(format!("{name} must be a number")),
+        },
+    }
+}
+
+/// Returns the non-negative integer keyword `name` of a `schema`.
+fn count(schema: &Map<String, Value>, name: &str) -> Result<Option<usize>, String> {
+    match schema.get(name) {
+        None => Ok(None),
+        Some(value) => match value.as_u64() {
+            Some(n) => Ok(Some(n as usize)),
+            None => Err(format!("{name} must be a non-negative integer")),
+        },
+    }
+}
+
+fn schema_array<'a>(name: &str, value: &'a Value) -> Result<&'a Vec<Value>, String> {
+    match value.as_array() {
+        Some(schemas) if !schemas.is_empty() => Ok(schemas),
+        _ => Err(format!("{name} must be a non-empty array")),
+    }
+}
+
+fn string_array<'a>(name: &str, value: &'a Value) -> Result<Vec<&'a str>, String> {
+    let error = || format!("{name} must be an array of strings");
+    let Some(values) = value.as_array() else {
+        return Err(error());
+    };
+    values
+        .iter()
+        .map(|v| v.as_str().ok_or_else(error))
+        .collect()
+}
+
+fn is_integer(value: &Value) -> bool {
+    match value {
+        Value::Number(n) => {
+            n.is_i64() || n.is_u64() || n.as_f64().is_some_and(|f| f.fract() == 0.0)
+        }
+        _ => false,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) if is_integer(value) => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Compares two JSON values, numbers being equal if they have the same mathematical value.
+fn json_equal(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Number(l), Value::Number(r)) => l == r || l.as_f64() == r.as_f64(),
+        (Value::Array(l), Value::Array(r)) => {
+            l.len() == r.len() && l.iter().zip(r).all(|(l, r)| json_equal(l, r))
+        }
+        (Value::Object(l), Value::Object(r)) => {
+            l.len() == r.len()
+                && l.iter()
+                    .all(|(k, l)| r.get(k).is_some_and(|r| json_equal(l, r)))
+        }
+        _ => left == right,
+    }
+}
+
+/// Returns the path of the property `name` of an object at `path`.
+fn child_path(path: &str, name: &str) -> String {
+    let is_identifier = !name.is_empty()
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && !name.starts_with(|c: char| c.is_ascii_digit());
+    if is_identifier {
+        format!("{path}.{name}")
+    } else {
+        format!("{path}['{name}']")
+    }
+}
+
+/// Decodes the percent-encoded chars of a JSON pointer used in a URI fragment.
+fn percent_decode(s: &str) -> String {
+    percent_encoding::percent_decode_str(s)
+        .decode_utf8_lossy()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_validate_type() {
+        let schema = json!({"type": "string"});
+        assert!(validate(&schema, &json!("Bob")).unwrap().is_empty());
+        assert_eq!(
+            validate(&schema, &json!(1)).unwrap(),
+            vec!["$: expected type string, got integer".to_string()]
+        );
+
+        let schema = json!({"type": ["integer", "null"]});
+        assert!(validate(&schema, &json!(1.0)).unwrap().is_empty());
+        assert!(validate(&schema, &json!(null)).unwrap().is_empty());
+        assert_eq!(
+            validate(&schema, &json!(1.5)).unwrap(),
+            vec!["$: expected type integer or null, got number".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_validate_object() {
+        let schema = json!({
+            "type": "object",
+            "required": ["id", "name"],
+            "properties": {
+                "id": {"type": "integer", "minimum": 1},
+                "name": {"type": "string", "minLength": 1},
+                "tags": {"type": "array", "items": {"type": "string"}, "uniqueItems": true}
+            },
+            "additionalProperties": false
+        });
+        let instance = json!({"id": 1, "name": "Bob", "tags": ["a", "b"]});
+        assert!(validate(&schema, &instance).unwrap().is_empty());
+
+        let instance = json!({"id": 0, "tags": ["a", 2, "a"], "first-name": "Bob"});
+        assert_eq!(
+            validate(&schema, &instance).unwrap(),
+            vec![
+                "$: missing required property <name>".to_string(),
+                "$: additional property <first-name> is not allowed".to_string(),
+                "$.id: 0 is less than the minimum 1".to_string(),
+                "$.tags[1]: expected type string, got integer".to_string(),
+                "$.tags: array items are not unique".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_combinators() {
+        let schema = json!({"oneOf": [{"type": "integer"}, {"type": "number"}]});
+        assert!(validate(&schema, &json!(1.5)).unwrap().is_empty());
+        assert_eq!(
+            validate(&schema, &json!(1)).unwrap(),
+            vec!["$: value matches 2 schemas of oneOf instead of exactly one".to_string()]
+        );
+
+        let schema = json!({"anyOf": [{"const": "a"}, {"enum": [1, 2]}]});
+        assert!(validate(&schema, &json!(2.0)).unwrap().is_empty());
+        assert_eq!(
+            validate(&schema, &json!("b")).unwrap(),
+            vec!["$: value doesn't match any schema of anyOf".to_string()]
+        );
+
+        let schema = json!({
+            "if": {"properties": {"kind": {"const": "book"}}},
+            "then": {"required": ["isbn"]},
+            "else": {"required": ["id"]}
+        });
+        assert!(validate(&schema, &json!({"kind": "book", "isbn": "1"}))
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            validate(&schema, &json!({"kind": "dvd"})).unwrap(),
+            vec!["$: missing required property <id>".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_validate_ref() {
+        let schema = json!({
+            "definitions": {
+                "node": {
+                    "type": "object",
+                    "properties": {
+                        "value": {"type": "integer"},
+                        "next": {"$ref": "#/definitions/node"}
+                    }
+                }
+            },
+            "$ref": "#/definitions/node"
+        });
+        let instance = json!({"value": 1, "next": {"value": 2, "next": {"value": "3"}}});
+        assert_eq!(
+            validate(&schema, &instance).unwrap(),
+            vec!["$.next.next.value: expected type integer, got string".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_invalid_schema() {
+        assert_eq!(
+            validate(&json!({"type": "text"}), &json!(1)).unwrap_err(),
+            "unknown type <text>"
+        );
+        assert_eq!(
+            validate(&json!({"$ref": "#/definitions/foo"}), &json!(1)).unwrap_err(),
+            "$ref <#/definitions/foo> can not be resolved"
+        );
+        assert_eq!(
+            validate(&json!({"pattern": "("}), &json!("a")).unwrap_err(),
+            "pattern <(> is not a valid regex"
+        );
+        assert_eq!(
+            validate(&json!({"$ref": "#"}), &json!(1)).unwrap_err(),
+            "too many nested schemas"
+        );
+    }
+
+    /// Groups of the test suite using `$id` or non-local `$ref`, which are not supported: the
+    /// validation of their schemas must fail with an error.
+    const UNSUPPORTED_GROUPS: &[(&str, &str)] = &[
+        ("definitions.json", "valid definition"),
+        ("definitions.json", "invalid definition"),
+        ("ref.json", "remote ref, containing refs itself"),
+        ("ref.json", "Recursive references between schemas"),
+        ("ref.json", "Location-independent identifier"),
+        (
+            "ref.json",
+            "Location-independent identifier with absolute URI",
+        ),
+        (
+            "ref.json",
+            "Location-independent identifier with base URI change in subschema",
+        ),
+    ];
+
+    /// Runs the required tests of the official draft-07 test suite (a copy is in
+    /// `tests/json_schema`, see <https://github.com/json-schema-org/JSON-Schema-Test-Suite>).
+    /// Remote references tests (`refRemote.json`) and optional tests (formats etc...) are not run.
+    #[test]
+    fn test_draft7_suite() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/json_schema/draft7");
+        let mut files = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        files.sort();
+        assert_eq!(files.len(), 35);
+
+        let mut failures = vec![];
+        for file in files {
+            let name = file.file_name().unwrap().to_string_lossy().to_string();
+            let content = std::fs::read_to_string(&file).unwrap();
+            let groups: Value = serde_json::from_str(&content).unwrap();
+            for group in groups.as_array().unwrap() {
+                let schema = &group["schema"];
+                let group_name = group["description"].as_str().unwrap();
+                let supported = !UNSUPPORTED_GROUPS.contains(&(name.as_str(), group_name));
+                for test in group["tests"].as_array().unwrap() {
+                    let test_name = test["description"].as_str().unwrap();
+                    let actual = validate(schema, &test["data"]).map(|v| v.is_empty());
+                    let ok = if supported {
+                        actual == Ok(test["valid"].as_bool().unwrap())
+                    } else {
+                        actual.is_err()
+                    };
+                    if !ok {
+                        failures.push(format!("{name} / {group_name} / {test_name}: {actual:?}"));
+                    }
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
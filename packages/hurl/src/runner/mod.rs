@@ -26,7 +26,7 @@ pub use self::hurl_file::run;
 pub use self::hurl_file::run_entries;
 pub use self::number::Number;
 pub use self::output::Output;
-pub use self::result::{AssertResult, CaptureResult, EntryResult, HurlResult};
+pub use self::result::{AssertResult, CaptureResult, EntryResult, HurlResult, RunSummary};
 pub use self::runner_options::{RunnerOptions, RunnerOptionsBuilder};
 pub use self::value::Value;
 pub use self::variable::VariableSet;
@@ -44,6 +44,7 @@ mod filter;
 mod function;
 mod hurl_file;
 mod json;
+mod json_schema;
 mod multiline;
 mod multipart;
 mod number;
@@ -15,7 +15,10 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{MultilineString, MultilineStringKind, Text};
+use hurl_core::ast::{
+    MultilineString, MultilineStringAttribute, MultilineStringKind, Placeholder, Template,
+    TemplateElement, Text,
+};
 use serde_json::json;
 
 use crate::runner::json::eval_json_value;
@@ -33,16 +36,31 @@ pub fn eval_multiline(
             ..
         }
         | MultilineString {
-            kind: MultilineStringKind::Json(Text { value, .. }),
+            kind: MultilineStringKind::Xml(Text { value, .. }),
             ..
         }
         | MultilineString {
-            kind: MultilineStringKind::Xml(Text { value, .. }),
+            kind: MultilineStringKind::Html(Text { value, .. }),
+            ..
+        }
+        | MultilineString {
+            kind: MultilineStringKind::Other(_, Text { value, .. }),
             ..
         } => {
             let s = eval_template(value, variables)?;
             Ok(s)
         }
+        MultilineString {
+            kind: MultilineStringKind::Json(Text { value, .. }),
+            attributes,
+        } => {
+            // Lenient JSON is stripped before rendering, so variables values are never altered.
+            if attributes.contains(&MultilineStringAttribute::Lenient) {
+                eval_template(&strip_lenient_json(value), variables)
+            } else {
+                eval_template(value, variables)
+            }
+        }
         MultilineString {
             kind: MultilineStringKind::GraphQl(graphql),
             ..
@@ -61,16 +79,135 @@ pub fn eval_multiline(
     }
 }
 
+/// A character of a lenient JSON template, or one of its placeholders.
+#[derive(Clone, Copy)]
+enum LenientItem<'a> {
+    Char(char),
+    Placeholder(&'a Placeholder),
+}
+
+/// Removes `//` line comments and trailing commas from a lenient JSON `template`, so that strict
+/// JSON is sent over the wire. Comments and commas inside JSON strings are kept untouched.
+///
+/// Only the literal text of the template is stripped: placeholders are kept as is (unless they're
+/// commented out) and are rendered afterward.
+fn strip_lenient_json(template: &Template) -> Template {
+    let items = template
+        .elements
+        .iter()
+        .flat_map(|element| match element {
+            TemplateElement::String { value, .. } => {
+                value.chars().map(LenientItem::Char).collect::<Vec<_>>()
+            }
+            TemplateElement::Placeholder(placeholder) => {
+                vec![LenientItem::Placeholder(placeholder)]
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut elements = vec![];
+    let mut text = String::new();
+    let mut in_string = false;
+    let mut i = 0;
+    while i < items.len() {
+        let c = match items[i] {
+            LenientItem::Char(c) => c,
+            LenientItem::Placeholder(placeholder) => {
+                push_text(&mut elements, &mut text);
+                elements.push(TemplateElement::Placeholder(placeholder.clone()));
+                i += 1;
+                continue;
+            }
+        };
+        if in_string {
+            text.push(c);
+            if c == '\\' {
+                if let Some(LenientItem::Char(next)) = items.get(i + 1) {
+                    text.push(*next);
+                    i += 1;
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+            i += 1;
+            continue;
+        }
+        match c {
+            '"' => {
+                in_string = true;
+                text.push(c);
+            }
+            '/' if is_comment_start(&items[i..]) => {
+                // The comment is skipped up to the end of line, the newline itself is kept.
+                i += comment_len(&items[i..]);
+                continue;
+            }
+            ',' if is_trailing_comma(&items[i + 1..]) => {}
+            _ => text.push(c),
+        }
+        i += 1;
+    }
+    push_text(&mut elements, &mut text);
+    Template {
+        delimiter: template.delimiter,
+        elements,
+        source_info: template.source_info,
+    }
+}
+
+/// Moves the pending `text` to a new string element of `elements`.
+fn push_text(elements: &mut Vec<TemplateElement>, text: &mut String) {
+    if !text.is_empty() {
+        let value = std::mem::take(text);
+        elements.push(TemplateElement::String {
+            encoded: value.clone(),
+            value,
+        });
+    }
+}
+
+/// Returns `true` if `items` starts with a `//` comment.
+fn is_comment_start(items: &[LenientItem]) -> bool {
+    matches!(items, [LenientItem::Char('/'), LenientItem::Char('/'), ..])
+}
+
+/// Returns the length of the comment starting `items`, up to the end of line (excluded).
+fn comment_len(items: &[LenientItem]) -> usize {
+    items
+        .iter()
+        .position(|item| matches!(item, LenientItem::Char('\n')))
+        .unwrap_or(items.len())
+}
+
+/// Returns `true` if the comma followed by `items` closes a list or an object, ignoring whitespaces
+/// and `//` comments.
+fn is_trailing_comma(items: &[LenientItem]) -> bool {
+    let mut i = 0;
+    while i < items.len() {
+        match items[i] {
+            LenientItem::Char(' ' | '\t' | '\n' | '\r') => i += 1,
+            LenientItem::Char('/') if is_comment_start(&items[i..]) => {
+                i += comment_len(&items[i..]);
+            }
+            LenientItem::Char(']' | '}') => return true,
+            _ => return false,
+        }
+    }
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use hurl_core::ast::{
-        GraphQl, GraphQlVariables, JsonObjectElement, JsonValue, MultilineString,
-        MultilineStringKind, SourceInfo, Template, TemplateElement, Whitespace,
+        Expr, ExprKind, GraphQl, GraphQlVariables, JsonObjectElement, JsonValue, MultilineString,
+        MultilineStringKind, Placeholder, SourceInfo, Template, TemplateElement, Variable,
+        Whitespace,
     };
     use hurl_core::reader::Pos;
 
-    use crate::runner::multiline::eval_multiline;
-    use crate::runner::VariableSet;
+    use crate::runner::multiline::{eval_multiline, strip_lenient_json};
+    use crate::runner::template::eval_template;
+    use crate::runner::{Value, VariableSet};
 
     fn whitespace() -> Whitespace {
         Whitespace {
@@ -198,4 +335,102 @@ mod tests {
         let body = eval_multiline(&multiline, &hurl_variables).unwrap();
         assert_eq!(body, r#"{"query":"{\n  human(id: \"1000\") {\n    name\n    height(unit: FOOT)\n  }\n}","variables":{"episode":"JEDI","withFriends":false}}"#.to_string());
     }
+
+    #[test]
+    fn test_strip_lenient_json() {
+        let text = r#"{
+    // The user id
+    "id": 123, // inline comment
+    "url": "https://example.org/a//b",
+    "name": "a \" // not a comment",
+    "tags": [
+        "a",
+        "b",
+    ],
+}
+"#;
+        assert_eq!(
+            strip_text(text),
+            r#"{
+    
+    "id": 123, 
+    "url": "https://example.org/a//b",
+    "name": "a \" // not a comment",
+    "tags": [
+        "a",
+        "b"
+    ]
+}
+"#
+        );
+        assert_eq!(strip_text("[1, 2, // last\n]"), "[1, 2 \n]");
+        assert_eq!(strip_text(r#"{"a": ",}"}"#), r#"{"a": ",}"}"#);
+    }
+
+    /// Strips and renders a lenient JSON `text` without placeholder.
+    fn strip_text(text: &str) -> String {
+        let template = Template {
+            delimiter: None,
+            elements: vec![TemplateElement::String {
+                value: text.to_string(),
+                encoded: text.to_string(),
+            }],
+            source_info: empty_source_info(),
+        };
+        eval_template(&strip_lenient_json(&template), &VariableSet::new()).unwrap()
+    }
+
+    #[test]
+    fn test_strip_lenient_json_before_rendering() {
+        // {"name": "{{name}}", // comment
+        // "tags": [{{tag}},],}
+        let placeholder = |name: &str| {
+            TemplateElement::Placeholder(Placeholder {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: empty_source_info(),
+                },
+                expr: Expr {
+                    kind: ExprKind::Variable(Variable {
+                        name: name.to_string(),
+                        source_info: empty_source_info(),
+                    }),
+                    source_info: empty_source_info(),
+                },
+                space1: Whitespace {
+                    value: String::new(),
+                    source_info: empty_source_info(),
+                },
+            })
+        };
+        let text = |s: &str| TemplateElement::String {
+            value: s.to_string(),
+            encoded: s.to_string(),
+        };
+        let template = Template {
+            delimiter: None,
+            elements: vec![
+                text(r#"{"name": ""#),
+                placeholder("name"),
+                text("\", // comment\n\"tags\": ["),
+                placeholder("tag"),
+                text(",],}"),
+            ],
+            source_info: empty_source_info(),
+        };
+        let mut variables = VariableSet::new();
+        // Variables values look like comments or trailing commas, but they're not stripped.
+        variables
+            .insert(
+                "name".to_string(),
+                Value::String(r#"bob\" // ,}"#.to_string()),
+            )
+            .unwrap();
+        variables
+            .insert("tag".to_string(), Value::String(r#""a",]"#.to_string()))
+            .unwrap();
+
+        let json = eval_template(&strip_lenient_json(&template), &variables).unwrap();
+        assert_eq!(json, "{\"name\": \"bob\\\" // ,}\", \n\"tags\": [\"a\",]]}");
+    }
 }
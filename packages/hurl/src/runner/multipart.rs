@@ -23,7 +23,7 @@ use hurl_core::ast::{FileParam, FileValue, KeyValue, MultipartParam};
 use crate::http;
 use crate::runner::body::eval_file;
 use crate::runner::error::RunnerError;
-use crate::runner::template::eval_template;
+use crate::runner::template::{eval_template, eval_template_bytes};
 use crate::runner::VariableSet;
 use crate::util::path::ContextDir;
 
@@ -51,14 +51,26 @@ pub fn eval_file_param(
     variables: &VariableSet,
 ) -> Result<http::FileParam, RunnerError> {
     let name = eval_template(&file_param.key, variables)?;
-    let filename = eval_template(&file_param.value.filename, variables)?;
-    let data = eval_file(&file_param.value.filename, variables, context_dir)?;
     let content_type = file_value_content_type(&file_param.value, variables)?;
+    let part_filename = match &file_param.value.part_filename {
+        Some(part_filename) => Some(eval_template(&part_filename.value, variables)?),
+        None => None,
+    };
+    // When the part data comes from a bytes variable, there is no file on disk: the part is
+    // named after the part filename if any, else after the param name.
+    let (filename, data) = match eval_template_bytes(&file_param.value.filename, variables) {
+        Some(data) => (part_filename.clone().unwrap_or_else(|| name.clone()), data),
+        None => (
+            eval_template(&file_param.value.filename, variables)?,
+            eval_file(&file_param.value.filename, variables, context_dir)?,
+        ),
+    };
     Ok(http::FileParam {
         name,
         filename,
         data,
         content_type,
+        part_filename,
     })
 }
 
@@ -68,7 +80,16 @@ pub fn file_value_content_type(
 ) -> Result<String, RunnerError> {
     let value = match file_value.content_type.clone() {
         None => {
-            let value = eval_template(&file_value.filename, variables)?;
+            // The content type is inferred from the part filename if any, else from the file
+            // read on disk.
+            let filename = match &file_value.part_filename {
+                Some(part_filename) => &part_filename.value,
+                None if eval_template_bytes(&file_value.filename, variables).is_some() => {
+                    return Ok("application/octet-stream".to_string());
+                }
+                None => &file_value.filename,
+            };
+            let value = eval_template(filename, variables)?;
             match Path::new(value.as_str())
                 .extension()
                 .and_then(OsStr::to_str)
@@ -93,10 +114,15 @@ pub fn file_value_content_type(
 
 #[cfg(test)]
 mod tests {
-    use hurl_core::ast::{LineTerminator, SourceInfo, Template, TemplateElement, Whitespace};
+    use std::path::PathBuf;
+
+    use hurl_core::ast::{
+        LineTerminator, PartFilename, SourceInfo, Template, TemplateElement, Whitespace,
+    };
     use hurl_core::reader::Pos;
 
     use super::*;
+    use crate::runner::RunnerErrorKind;
 
     pub fn whitespace() -> Whitespace {
         Whitespace {
@@ -143,6 +169,7 @@ mod tests {
                     space1: whitespace(),
                     space2: whitespace(),
                     content_type: None,
+                    part_filename: None,
                 },
                 line_terminator0: line_terminator,
             },
@@ -157,6 +184,7 @@ mod tests {
                 filename: "hello.txt".to_string(),
                 data: b"Hello World!".to_vec(),
                 content_type: "text/plain".to_string(),
+                part_filename: None,
             }
         );
     }
@@ -179,6 +207,7 @@ mod tests {
                     space1: whitespace(),
                     space2: whitespace(),
                     content_type: None,
+                    part_filename: None,
                 },
                 &variables
             )
@@ -201,6 +230,7 @@ mod tests {
                     space1: whitespace(),
                     space2: whitespace(),
                     content_type: None,
+                    part_filename: None,
                 },
                 &variables
             )
@@ -223,6 +253,7 @@ mod tests {
                     space1: whitespace(),
                     space2: whitespace(),
                     content_type: Some("text/html".to_string()),
+                    part_filename: None,
                 },
                 &variables
             )
@@ -245,6 +276,7 @@ mod tests {
                     space1: whitespace(),
                     space2: whitespace(),
                     content_type: None,
+                    part_filename: None,
                 },
                 &variables
             )
@@ -252,4 +284,85 @@ mod tests {
             "application/octet-stream".to_string()
         );
     }
+
+    fn file_param(filename: &str, part_filename: Option<&str>) -> FileParam {
+        let template = |value: &str, source_info: SourceInfo| Template {
+            delimiter: None,
+            source_info,
+            elements: vec![TemplateElement::String {
+                value: value.to_string(),
+                encoded: value.to_string(),
+            }],
+        };
+        FileParam {
+            line_terminators: vec![],
+            space0: whitespace(),
+            key: template("upload1", SourceInfo::new(Pos::new(1, 1), Pos::new(1, 8))),
+            space1: whitespace(),
+            space2: whitespace(),
+            value: FileValue {
+                space0: whitespace(),
+                filename: template(filename, SourceInfo::new(Pos::new(1, 15), Pos::new(1, 24))),
+                space1: whitespace(),
+                space2: whitespace(),
+                content_type: None,
+                part_filename: part_filename.map(|value| {
+                    Box::new(PartFilename {
+                        space0: whitespace(),
+                        space1: whitespace(),
+                        value: template(value, SourceInfo::new(Pos::new(1, 35), Pos::new(1, 45))),
+                    })
+                }),
+            },
+            line_terminator0: LineTerminator {
+                space0: whitespace(),
+                comment: None,
+                newline: whitespace(),
+            },
+        }
+    }
+
+    #[test]
+    pub fn test_eval_file_param_part_filename() {
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("tests");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+        let variables = VariableSet::default();
+
+        // The content type is inferred from the part filename, not from the file on disk.
+        let param = eval_file_param(
+            &file_param("hello.txt", Some("avatar.png")),
+            &context_dir,
+            &variables,
+        )
+        .unwrap();
+        assert_eq!(
+            param,
+            http::FileParam {
+                name: "upload1".to_string(),
+                filename: "hello.txt".to_string(),
+                data: b"Hello World!".to_vec(),
+                content_type: "image/png".to_string(),
+                part_filename: Some("avatar.png".to_string()),
+            }
+        );
+
+        let error = eval_file_param(
+            &file_param("missing.txt", Some("avatar.png")),
+            &context_dir,
+            &variables,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FileReadAccess {
+                path: PathBuf::from("missing.txt")
+            }
+        );
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 15), Pos::new(1, 24))
+        );
+    }
 }
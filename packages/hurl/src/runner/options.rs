@@ -15,13 +15,18 @@
  * limitations under the License.
  *
  */
+use std::path::{Path, PathBuf};
+
 use hurl_core::ast::{
     BooleanOption, CountOption, DurationOption, Entry, EntryOption, Float, NaturalOption,
-    Number as AstNumber, OptionKind, Placeholder, SectionValue, VariableDefinition, VariableValue,
+    Number as AstNumber, OptionKind, Placeholder, SectionValue, SourceInfo, VariableDefinition,
+    VariableValue,
 };
 use hurl_core::typing::{BytesPerSec, Count, DurationUnit};
 
-use crate::http::{IpResolve, RequestedHttpVersion};
+use crate::http::{
+    parse_cert_password, proxy_secrets, BodyCompression, IpResolve, RequestedHttpVersion,
+};
 use crate::runner::template::eval_template;
 use crate::runner::{
     expr, Number, Output, RunnerError, RunnerErrorKind, RunnerOptions, Value, VariableSet,
@@ -62,16 +67,32 @@ pub fn get_entry_options(
                     }
                     OptionKind::CaCertificate(filename) => {
                         let value = eval_template(filename, variables)?;
+                        check_file_exists(&value, filename.source_info)?;
                         entry_options.cacert_file = Some(value);
                     }
+                    OptionKind::Chunked(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.chunked = value;
+                    }
                     OptionKind::ClientCert(filename) => {
                         let value = eval_template(filename, variables)?;
+                        // The certificate can be followed by an optional password (`cert.pem:password`).
+                        let (cert, _) = parse_cert_password(&value);
+                        check_file_exists(&cert, filename.source_info)?;
                         entry_options.client_cert_file = Some(value);
                     }
                     OptionKind::ClientKey(filename) => {
                         let value = eval_template(filename, variables)?;
+                        check_file_exists(&value, filename.source_info)?;
                         entry_options.client_key_file = Some(value);
                     }
+                    OptionKind::CompressBody(value) => {
+                        let compression = eval_template(value, variables)?;
+                        let compression = BodyCompression::parse(&compression).map_err(|e| {
+                            RunnerError::new(value.source_info, RunnerErrorKind::Http(e), false)
+                        })?;
+                        entry_options.compress_body = Some(compression);
+                    }
                     OptionKind::Compressed(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.compressed = value;
@@ -184,6 +205,11 @@ pub fn get_entry_options(
                         let value = eval_count_option(value, variables)?;
                         entry_options.max_redirect = value;
                     }
+                    OptionKind::MaxTime(value) => {
+                        let value =
+                            eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
+                        entry_options.timeout = value;
+                    }
                     OptionKind::NetRc(value) => {
                         let value = eval_boolean_option(value, variables)?;
                         entry_options.netrc = value;
@@ -207,6 +233,9 @@ pub fn get_entry_options(
                     }
                     OptionKind::Proxy(value) => {
                         let value = eval_template(value, variables)?;
+                        proxy_secrets(&value)
+                            .into_iter()
+                            .for_each(|s| logger.add_secret(s));
                         entry_options.proxy = Some(value);
                     }
                     OptionKind::Repeat(value) => {
@@ -226,10 +255,20 @@ pub fn get_entry_options(
                             eval_duration_option(value, variables, DurationUnit::MilliSecond)?;
                         entry_options.retry_interval = value;
                     }
+                    OptionKind::RetryMaxTime(value) => {
+                        let value = eval_duration_option(value, variables, DurationUnit::Second)?;
+                        entry_options.retry_max_time = Some(value);
+                    }
                     OptionKind::Skip(value) => {
                         let value = eval_boolean_option(value, variables)?;
-                        entry_options.skip = value;
+                        entry_options.skip |= value;
+                    }
+                    OptionKind::SkipUnless(value) => {
+                        let value = eval_boolean_option(value, variables)?;
+                        entry_options.skip |= !value;
                     }
+                    // Unknown options are reported as warnings before the entry is run.
+                    OptionKind::Unknown(_) => {}
                     OptionKind::UnixSocket(value) => {
                         let value = eval_template(value, variables)?;
                         entry_options.unix_socket = Some(value);
@@ -282,6 +321,21 @@ fn has_options(entry: &Entry) -> bool {
         .any(|s| matches!(s.value, SectionValue::Options(_)))
 }
 
+/// Checks that the certificate or key file `filename` exists.
+///
+/// libcurl reports a missing file with a generic TLS error, so we check it beforehand to have
+/// an error positioned on the option value.
+fn check_file_exists(filename: &str, source_info: SourceInfo) -> Result<(), RunnerError> {
+    if Path::new(filename).is_file() {
+        Ok(())
+    } else {
+        let kind = RunnerErrorKind::FileReadAccess {
+            path: PathBuf::from(filename),
+        };
+        Err(RunnerError::new(source_info, kind, false))
+    }
+}
+
 /// Returns the overridden `entry` verbosity, or the default `verbosity` file.
 pub fn get_entry_verbosity(
     entry: &Entry,
@@ -477,6 +531,8 @@ mod tests {
 
     use super::*;
     use crate::runner::RunnerErrorKind;
+    use crate::util::logger::LoggerOptionsBuilder;
+    use crate::util::term::{Stderr, WriteMode};
 
     fn verbose_option_template() -> BooleanOption {
         // {{verbose}}
@@ -589,4 +645,46 @@ mod tests {
             std::time::Duration::from_millis(10)
         );
     }
+
+    #[test]
+    fn test_check_file_exists() {
+        let source_info = SourceInfo::new(Pos::new(1, 7), Pos::new(1, 25));
+        assert!(check_file_exists("Cargo.toml", source_info).is_ok());
+
+        let error = check_file_exists("unknown.pem", source_info).unwrap_err();
+        assert_eq!(error.source_info, source_info);
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FileReadAccess {
+                path: PathBuf::from("unknown.pem")
+            }
+        );
+    }
+
+    #[test]
+    fn test_skip_options_are_combined() {
+        // An entry is skipped as soon as one of its `skip` / `skip-unless` options says so,
+        // whatever their order.
+        for (options, skip) in [
+            ("skip: true\nskip-unless: true", true),
+            ("skip-unless: true\nskip: true", true),
+            ("skip: false\nskip-unless: false", true),
+            ("skip-unless: false\nskip: false", true),
+            ("skip: false\nskip-unless: true", false),
+        ] {
+            let content = format!("GET http://localhost:1\n[Options]\n{options}\n");
+            let hurl_file = hurl_core::parser::parse_hurl_file(&content).unwrap();
+            let mut variables = VariableSet::new();
+            let logger_options = LoggerOptionsBuilder::new().build();
+            let mut logger = Logger::new(&logger_options, Stderr::new(WriteMode::Buffered), &[]);
+            let entry_options = get_entry_options(
+                &hurl_file.entries[0],
+                &RunnerOptions::default(),
+                &mut variables,
+                &mut logger,
+            )
+            .unwrap();
+            assert_eq!(entry_options.skip, skip, "{options}");
+        }
+    }
 }
@@ -20,6 +20,7 @@ use hurl_core::reader::Pos;
 use std::cmp::Ordering;
 
 use crate::runner::error::RunnerError;
+use crate::runner::json_schema;
 use crate::runner::predicate_value::{eval_predicate_value, eval_predicate_value_template};
 use crate::runner::result::PredicateResult;
 use crate::runner::template::eval_template;
@@ -227,6 +228,7 @@ fn expected_no_value(
             let expected = eval_predicate_value_template(expected, variables)?;
             Ok(format!("matches regex <{expected}>"))
         }
+        PredicateFuncValue::MatchSchema { .. } => Ok("matches JSON schema".to_string()),
         PredicateFuncValue::IsInteger => Ok("integer".to_string()),
         PredicateFuncValue::IsFloat => Ok("float".to_string()),
         PredicateFuncValue::IsBoolean => Ok("boolean".to_string()),
@@ -296,6 +298,15 @@ fn eval_predicate_func(
         PredicateFuncValue::Match {
             value: expected, ..
         } => eval_match(expected, predicate_func.source_info, variables, value),
+        PredicateFuncValue::MatchSchema {
+            value: expected, ..
+        } => eval_match_schema(
+            expected,
+            predicate_func.source_info,
+            variables,
+            value,
+            context_dir,
+        ),
         PredicateFuncValue::IsInteger => eval_is_integer(value),
         PredicateFuncValue::IsFloat => eval_is_float(value),
         PredicateFuncValue::IsBoolean => eval_is_boolean(value),
@@ -442,7 +453,7 @@ fn eval_end_with(
 }
 
 /// Evaluates if an `expected` value (using a `variables` set) contains an `actual` value.
-/// This predicate works with string and bytes.
+/// This predicate works with string and bytes, and tests membership for list.
 fn eval_contain(
     expected: &PredicateValue,
     variables: &VariableSet,
@@ -465,6 +476,14 @@ fn eval_contain(
             expected: expected_display,
             type_mismatch: false,
         }),
+        (expected, Value::List(values)) => Ok(AssertResult {
+            success: values
+                .iter()
+                .any(|value| assert_values_equal(value, &expected).success),
+            actual: actual_display,
+            expected: expected_display,
+            type_mismatch: false,
+        }),
         _ => Ok(AssertResult {
             success: false,
             actual: actual_display,
@@ -528,6 +547,70 @@ fn eval_match(
     }
 }
 
+/// Evaluates if an `actual` value is valid against an `expected` JSON schema.
+///
+/// A string `actual` value (like the response body) is parsed as JSON. Each schema violation is
+/// reported in the actual value of the assert result. An invalid schema is reported as an error.
+fn eval_match_schema(
+    expected: &PredicateValue,
+    source_info: SourceInfo,
+    variables: &VariableSet,
+    actual: &Value,
+    context_dir: &ContextDir,
+) -> Result<AssertResult, RunnerError> {
+    let schema = match eval_predicate_value(expected, variables, context_dir)? {
+        Value::Bytes(bytes) => serde_json::from_slice(&bytes),
+        Value::String(s) => serde_json::from_str(&s),
+        _ => panic!("expect a file or a string predicate value"), // should have failed in parsing
+    };
+    let schema: serde_json::Value = match schema {
+        Ok(schema) => schema,
+        Err(e) => {
+            let kind = RunnerErrorKind::InvalidJsonSchema {
+                message: e.to_string(),
+            };
+            return Err(RunnerError::new(source_info, kind, false));
+        }
+    };
+
+    let expected_display = "matches JSON schema".to_string();
+    let instance = match actual {
+        Value::String(s) => match serde_json::from_str(s) {
+            Ok(instance) => instance,
+            Err(_) => serde_json::Value::String(s.clone()),
+        },
+        Value::Bool(_) | Value::List(_) | Value::Null | Value::Number(_) | Value::Object(_) => {
+            actual.to_json()
+        }
+        _ => {
+            return Ok(AssertResult {
+                success: false,
+                actual: actual.display(),
+                expected: expected_display,
+                type_mismatch: true,
+            })
+        }
+    };
+    let violations = match json_schema::validate(&schema, &instance) {
+        Ok(violations) => violations,
+        Err(message) => {
+            let kind = RunnerErrorKind::InvalidJsonSchema { message };
+            return Err(RunnerError::new(source_info, kind, false));
+        }
+    };
+    let actual_display = if violations.is_empty() {
+        "JSON matching the schema".to_string()
+    } else {
+        format!("JSON with schema violations: {}", violations.join("; "))
+    };
+    Ok(AssertResult {
+        success: violations.is_empty(),
+        actual: actual_display,
+        expected: expected_display,
+        type_mismatch: false,
+    })
+}
+
 /// Evaluates if an `actual` value is an integer.
 fn eval_is_integer(actual: &Value) -> Result<AssertResult, RunnerError> {
     Ok(AssertResult {
@@ -894,6 +977,7 @@ fn compare_values(actual: &Value, expected: &Value) -> Option<Ordering> {
     match (actual, expected) {
         (Value::Number(number1), Value::Number(number2)) => Some(number1.cmp_value(number2)),
         (Value::String(s1), Value::String(s2)) => Some(s1.cmp(s2)),
+        (Value::Date(d1), Value::Date(d2)) => Some(d1.cmp(d2)),
         _ => None,
     }
 }
@@ -1141,6 +1225,38 @@ mod tests {
         assert_eq!(assert_result.expected, "something");
     }
 
+    #[test]
+    fn test_predicate_not_exist() {
+        let variables = VariableSet::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+
+        // predicate: `not exists`
+        // value: None | Some(String)
+        let predicate = Predicate {
+            not: true,
+            space0: whitespace(),
+            predicate_func: PredicateFunc {
+                value: PredicateFuncValue::Exist,
+                source_info: SourceInfo::new(Pos::new(1, 22), Pos::new(1, 32)),
+            },
+        };
+
+        assert!(eval_predicate(&predicate, &variables, &None, &context_dir).is_ok());
+
+        let value = Some(Value::String("cookie1=value1".to_string()));
+        let error = eval_predicate(&predicate, &variables, &value, &context_dir).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::AssertFailure {
+                actual: "string <cookie1=value1>".to_string(),
+                expected: "not something".to_string(),
+                type_mismatch: false,
+            }
+        );
+    }
+
     #[test]
     fn test_predicate_value_equals_integers() {
         let variables = VariableSet::new();
@@ -1379,6 +1495,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_assert_status_range() {
+        // A 2xx status range is asserted with `status >= 200` and `status < 300`.
+        let in_range = |status: i64| {
+            let status = Value::Number(Number::Integer(status));
+            assert_values_greater_or_equal(&status, &Value::Number(Number::Integer(200))).success
+                && assert_values_less(&status, &Value::Number(Number::Integer(300))).success
+        };
+        assert!(!in_range(199));
+        assert!(in_range(200));
+        assert!(in_range(299));
+        assert!(!in_range(300));
+
+        assert_eq!(
+            assert_values_less(
+                &Value::Number(Number::Integer(300)),
+                &Value::Number(Number::Integer(300))
+            ),
+            AssertResult {
+                success: false,
+                type_mismatch: false,
+                actual: "int <300>".to_string(),
+                expected: "less than int <300>".to_string(),
+            }
+        );
+    }
+
     #[test]
     fn test_predicate_is_empty_are_false() {
         // predicate: `isEmpty`
@@ -1533,6 +1676,35 @@ mod tests {
         assert_eq!(assert_result.expected, "date");
     }
 
+    #[test]
+    fn test_compare_dates() {
+        let date1 = Value::Date(
+            chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2002, 6, 16, 10, 10, 10).unwrap(),
+        );
+        let date2 = Value::Date(
+            chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2024, 1, 1, 0, 0, 0).unwrap(),
+        );
+
+        let assert_result = assert_values_greater(&date2, &date1);
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "date <2024-01-01 00:00:00 UTC>");
+        assert_eq!(
+            assert_result.expected,
+            "greater than date <2002-06-16 10:10:10 UTC>"
+        );
+
+        assert!(!assert_values_greater(&date1, &date2).success);
+        assert!(assert_values_less(&date1, &date2).success);
+        assert!(assert_values_less_or_equal(&date1, &date1).success);
+        assert!(assert_values_greater_or_equal(&date1, &date1).success);
+
+        // A date can't be compared to a string.
+        let assert_result = assert_values_greater(&date1, &Value::String("2002-06-16".to_string()));
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+    }
+
     #[test]
     fn test_no_type_mismatch_with_none_value() {
         let variables = VariableSet::new();
@@ -1582,6 +1754,90 @@ mod tests {
         assert!(eval_predicate(&predicate, &variables, &None, &context_dir).is_ok());
     }
 
+    fn string_predicate_value(value: &str) -> PredicateValue {
+        PredicateValue::String(Template {
+            delimiter: Some('"'),
+            elements: vec![TemplateElement::String {
+                value: value.to_string(),
+                encoded: value.to_string(),
+            }],
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        })
+    }
+
+    #[test]
+    fn test_predicate_string_utf8() {
+        let variables = VariableSet::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+        let value = Value::String("日本語 café 🦀".to_string());
+
+        let expected = string_predicate_value("日本");
+        let assert_result = eval_start_with(&expected, &variables, &value, &context_dir).unwrap();
+        assert!(assert_result.success);
+        assert_eq!(assert_result.expected, "starts with string <日本>");
+
+        let expected = string_predicate_value("本語");
+        let assert_result = eval_start_with(&expected, &variables, &value, &context_dir).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "string <日本語 café 🦀>");
+
+        let expected = string_predicate_value("é 🦀");
+        let assert_result = eval_end_with(&expected, &variables, &value, &context_dir).unwrap();
+        assert!(assert_result.success);
+        assert_eq!(assert_result.expected, "ends with string <é 🦀>");
+
+        let expected = string_predicate_value("語 caf");
+        let assert_result = eval_contain(&expected, &variables, &value, &context_dir).unwrap();
+        assert!(assert_result.success);
+        assert_eq!(assert_result.expected, "contains string <語 caf>");
+
+        let expected = string_predicate_value("cafe");
+        let assert_result = eval_contain(&expected, &variables, &value, &context_dir).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+    }
+
+    #[test]
+    fn test_predicate_contain_list() {
+        let variables = VariableSet::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+        let value = Value::List(vec![
+            Value::String("Дюна".to_string()),
+            Value::Number(Number::Integer(1965)),
+        ]);
+
+        let expected = string_predicate_value("Дюна");
+        let assert_result = eval_contain(&expected, &variables, &value, &context_dir).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "[string <Дюна>, int <1965>]");
+        assert_eq!(assert_result.expected, "contains string <Дюна>");
+
+        // Membership is tested on the whole element, not on a substring.
+        let expected = string_predicate_value("Дю");
+        let assert_result = eval_contain(&expected, &variables, &value, &context_dir).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+
+        let expected = PredicateValue::Number(hurl_core::ast::Number::Integer(I64::new(
+            1965,
+            "1965".to_string(),
+        )));
+        let assert_result = eval_contain(&expected, &variables, &value, &context_dir).unwrap();
+        assert!(assert_result.success);
+
+        let value = Value::Bool(true);
+        let expected = string_predicate_value("true");
+        let assert_result = eval_contain(&expected, &variables, &value, &context_dir).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+    }
+
     #[test]
     fn test_predicate_match() {
         let variables = VariableSet::new();
@@ -1600,6 +1856,63 @@ mod tests {
         assert_eq!(assert_result.expected, "matches regex <a{3}>");
     }
 
+    #[test]
+    fn test_predicate_match_schema() {
+        let variables = VariableSet::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+        let source_info = SourceInfo::new(Pos::new(1, 19), Pos::new(1, 63));
+
+        // predicate: `matchesSchema "{\"required\": [\"id\"]}"`
+        let schema = r#"{"required": ["id"]}"#;
+        let expected = PredicateValue::String(Template {
+            delimiter: Some('"'),
+            elements: vec![TemplateElement::String {
+                value: schema.to_string(),
+                encoded: schema.to_string(),
+            }],
+            source_info,
+        });
+        let value = Value::String(r#"{"id": 1}"#.to_string());
+        let assert_result =
+            eval_match_schema(&expected, source_info, &variables, &value, &context_dir).unwrap();
+        assert!(assert_result.success);
+        assert_eq!(assert_result.actual, "JSON matching the schema");
+        assert_eq!(assert_result.expected, "matches JSON schema");
+
+        let value = Value::Object(vec![("name".to_string(), Value::Null)]);
+        let assert_result =
+            eval_match_schema(&expected, source_info, &variables, &value, &context_dir).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(
+            assert_result.actual,
+            "JSON with schema violations: $: missing required property <id>"
+        );
+
+        // An invalid schema is an error, not an assert failure.
+        let schema = r#"{"type": "text"}"#;
+        let expected = PredicateValue::String(Template {
+            delimiter: Some('"'),
+            elements: vec![TemplateElement::String {
+                value: schema.to_string(),
+                encoded: schema.to_string(),
+            }],
+            source_info,
+        });
+        let error = eval_match_schema(&expected, source_info, &variables, &value, &context_dir)
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::InvalidJsonSchema {
+                message: "unknown type <text>".to_string()
+            }
+        );
+        assert_eq!(error.source_info, source_info);
+    }
+
     #[test]
     fn test_predicate_is_iso_date() {
         let value = Value::String("2020-03-09T22:18:26.625Z".to_string());
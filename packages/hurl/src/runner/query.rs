@@ -23,6 +23,7 @@ use regex::Regex;
 use sha2::Digest;
 
 use crate::http;
+use crate::http::LOCATION;
 use crate::runner::cache::BodyCache;
 use crate::runner::error::{RunnerError, RunnerErrorKind};
 use crate::runner::template::eval_template;
@@ -32,15 +33,21 @@ use crate::runner::{filter, Number, Value, VariableSet};
 pub type QueryResult = Result<Option<Value>, RunnerError>;
 
 /// Evaluates this `query` and returns a [`QueryResult`], using the HTTP `response` and `variables`.
+///
+/// `redirects` are the HTTP calls that have been followed before getting the final `response`.
 pub fn eval_query(
     query: &Query,
     variables: &VariableSet,
     response: &http::Response,
+    redirects: &[http::Call],
     cache: &mut BodyCache,
 ) -> QueryResult {
     match &query.value {
         QueryValue::Status => eval_query_status(response),
+        QueryValue::Version => eval_query_version(response),
         QueryValue::Url => eval_query_url(response),
+        QueryValue::Redirects => eval_query_redirects(response, redirects),
+        QueryValue::Location => eval_query_location(response, query.source_info),
         QueryValue::Header { name, .. } => eval_query_header(response, name, variables),
         QueryValue::Cookie {
             expr: CookiePath { name, attribute },
@@ -75,11 +82,58 @@ fn eval_query_status(response: &http::Response) -> QueryResult {
     )))))
 }
 
+/// Evaluates the HTTP version of the `response` (`HTTP/1.0`, `HTTP/1.1`, `HTTP/2` or `HTTP/3`).
+fn eval_query_version(response: &http::Response) -> QueryResult {
+    Ok(Some(Value::String(response.version.to_string())))
+}
+
 /// Evaluates the final URL of the HTTP `response`.
 fn eval_query_url(response: &http::Response) -> QueryResult {
     Ok(Some(Value::String(response.url.to_string())))
 }
 
+/// Evaluates the `Location` header of the HTTP `response` as an absolute URL.
+///
+/// A relative location is resolved against the URL of the `response`.
+fn eval_query_location(response: &http::Response, source_info: SourceInfo) -> QueryResult {
+    let Some(location) = response.headers.get(LOCATION) else {
+        return Ok(None);
+    };
+    match response.url.join(&location.value) {
+        Ok(url) => Ok(Some(Value::String(url.to_string()))),
+        Err(_) => {
+            let kind = RunnerErrorKind::InvalidUrl {
+                url: location.value.clone(),
+                message: format!("can not be resolved against <{}>", response.url),
+            };
+            Err(RunnerError::new(source_info, kind, false))
+        }
+    }
+}
+
+/// Evaluates the redirection chain that has been followed to get the final HTTP `response`.
+///
+/// Each hop is an object with the `status` of the redirect response and the `location` URL
+/// that has been requested next.
+fn eval_query_redirects(response: &http::Response, redirects: &[http::Call]) -> QueryResult {
+    let hops = redirects
+        .iter()
+        .enumerate()
+        .map(|(i, call)| {
+            let location = match redirects.get(i + 1) {
+                Some(next) => next.request.url.to_string(),
+                None => response.url.to_string(),
+            };
+            let status = Value::Number(Number::Integer(i64::from(call.response.status)));
+            Value::Object(vec![
+                ("location".to_string(), Value::String(location)),
+                ("status".to_string(), status),
+            ])
+        })
+        .collect();
+    Ok(Some(Value::List(hops)))
+}
+
 /// Evaluates a response query header `name`, on the HTTP `response` given a set of `variables`.
 fn eval_query_header(
     response: &http::Response,
@@ -164,6 +218,13 @@ fn parse_cache_xml<'cache>(
     cache: &'cache mut BodyCache,
     query_source_info: SourceInfo,
 ) -> Result<&'cache Document, RunnerError> {
+    if response.body.is_empty() {
+        return Err(RunnerError::new(
+            query_source_info,
+            RunnerErrorKind::QueryEmptyBody,
+            false,
+        ));
+    }
     // Get the response as text if possible
     let text = match response.text() {
         Ok(t) => t,
@@ -217,6 +278,13 @@ fn parse_cache_json<'cache>(
     cache: &'cache mut BodyCache,
     query_source_info: SourceInfo,
 ) -> Result<&'cache serde_json::Value, RunnerError> {
+    if response.body.is_empty() {
+        return Err(RunnerError::new(
+            query_source_info,
+            RunnerErrorKind::QueryEmptyBody,
+            false,
+        ));
+    }
     // Get the response as text if possible
     let text = match response.text() {
         Ok(t) => t,
@@ -679,6 +747,7 @@ pub mod tests {
                 },
                 &variables,
                 &http::hello_http_response(),
+                &[],
                 &mut cache,
             )
             .unwrap()
@@ -687,6 +756,150 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_query_version() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let response = http::Response {
+            version: HttpVersion::Http2,
+            ..default_response()
+        };
+        assert_eq!(
+            eval_query(
+                &Query {
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    value: QueryValue::Version,
+                },
+                &variables,
+                &response,
+                &[],
+                &mut cache,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("HTTP/2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_query_redirects() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::Redirects,
+        };
+        let redirect = |from: &str, status: u32| http::Call {
+            request: http::Request::new("GET", from.parse().unwrap(), HeaderVec::new(), vec![]),
+            response: http::Response {
+                status,
+                ..default_response()
+            },
+            timings: Default::default(),
+        };
+        let redirects = vec![
+            redirect("http://localhost/a", 301),
+            redirect("http://localhost/b", 302),
+        ];
+        let response = http::Response {
+            url: "http://localhost/c".parse().unwrap(),
+            ..default_response()
+        };
+        assert_eq!(
+            eval_query(&query, &variables, &response, &redirects, &mut cache)
+                .unwrap()
+                .unwrap(),
+            Value::List(vec![
+                Value::Object(vec![
+                    (
+                        "location".to_string(),
+                        Value::String("http://localhost/b".to_string())
+                    ),
+                    ("status".to_string(), Value::Number(Number::Integer(301))),
+                ]),
+                Value::Object(vec![
+                    (
+                        "location".to_string(),
+                        Value::String("http://localhost/c".to_string())
+                    ),
+                    ("status".to_string(), Value::Number(Number::Integer(302))),
+                ]),
+            ])
+        );
+        assert_eq!(
+            eval_query(&query, &variables, &response, &[], &mut cache)
+                .unwrap()
+                .unwrap(),
+            Value::List(vec![])
+        );
+    }
+
+    #[test]
+    fn test_query_location() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+            value: QueryValue::Location,
+        };
+        let response = |location: &str| {
+            let mut headers = HeaderVec::new();
+            headers.push(http::Header::new("Location", location));
+            http::Response {
+                status: 201,
+                headers,
+                url: "http://localhost:8000/items/new?id=1".parse().unwrap(),
+                ..default_response()
+            }
+        };
+
+        let locations = [
+            ("/items/42", "http://localhost:8000/items/42"),
+            ("42", "http://localhost:8000/items/42"),
+            ("../users/1", "http://localhost:8000/users/1"),
+            ("http://example.org/items", "http://example.org/items"),
+        ];
+        for (location, url) in locations {
+            assert_eq!(
+                eval_query(&query, &variables, &response(location), &[], &mut cache)
+                    .unwrap()
+                    .unwrap(),
+                Value::String(url.to_string())
+            );
+        }
+
+        // No Location header
+        assert_eq!(
+            eval_query(&query, &variables, &default_response(), &[], &mut cache).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_query_duration() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let response = http::Response {
+            duration: std::time::Duration::from_millis(523),
+            ..default_response()
+        };
+        assert_eq!(
+            eval_query(
+                &Query {
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    value: QueryValue::Duration,
+                },
+                &variables,
+                &response,
+                &[],
+                &mut cache,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(523))
+        );
+    }
+
     #[test]
     fn test_header_not_found() {
         let variables = VariableSet::new();
@@ -718,6 +931,7 @@ pub mod tests {
                 &query_header,
                 &variables,
                 &http::hello_http_response(),
+                &[],
                 &mut cache
             )
             .unwrap(),
@@ -753,6 +967,7 @@ pub mod tests {
                 &query_header,
                 &variables,
                 &http::hello_http_response(),
+                &[],
                 &mut cache
             )
             .unwrap()
@@ -797,7 +1012,7 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache)
+            eval_query(&query, &variables, &response, &[], &mut cache)
                 .unwrap()
                 .unwrap(),
             Value::String("DQAAAKEaem_vYg".to_string())
@@ -826,7 +1041,7 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache)
+            eval_query(&query, &variables, &response, &[], &mut cache)
                 .unwrap()
                 .unwrap(),
             Value::String("/accounts".to_string())
@@ -855,7 +1070,7 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache)
+            eval_query(&query, &variables, &response, &[], &mut cache)
                 .unwrap()
                 .unwrap(),
             Value::Unit
@@ -884,7 +1099,7 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache).unwrap(),
+            eval_query(&query, &variables, &response, &[], &mut cache).unwrap(),
             None
         );
     }
@@ -984,6 +1199,7 @@ pub mod tests {
                 },
                 &variables,
                 &http::hello_http_response(),
+                &[],
                 &mut cache,
             )
             .unwrap()
@@ -997,6 +1213,7 @@ pub mod tests {
             },
             &variables,
             &http::bytes_http_response(),
+            &[],
             &mut cache,
         )
         .err()
@@ -1022,7 +1239,7 @@ pub mod tests {
             body: vec![200],
             ..default_response()
         };
-        let error = eval_query(&xpath_users(), &variables, &http_response, &mut cache)
+        let error = eval_query(&xpath_users(), &variables, &http_response, &[], &mut cache)
             .err()
             .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
@@ -1061,6 +1278,7 @@ pub mod tests {
             &query,
             &variables,
             &http::xml_two_users_http_response(),
+            &[],
             &mut cache,
         )
         .unwrap_err();
@@ -1078,6 +1296,7 @@ pub mod tests {
                 &xpath_users(),
                 &variables,
                 &http::xml_two_users_http_response(),
+                &[],
                 &mut cache,
             )
             .unwrap()
@@ -1089,6 +1308,7 @@ pub mod tests {
                 &xpath_count_user_query(),
                 &variables,
                 &http::xml_two_users_http_response(),
+                &[],
                 &mut cache,
             )
             .unwrap()
@@ -1130,6 +1350,7 @@ pub mod tests {
                 &xpath_html_charset(),
                 &variables,
                 &http::html_http_response(),
+                &[],
                 &mut cache,
             )
             .unwrap()
@@ -1166,6 +1387,7 @@ pub mod tests {
             &jsonpath_query,
             &variables,
             &http::json_http_response(),
+            &[],
             &mut cache,
         )
         .unwrap_err();
@@ -1192,13 +1414,55 @@ pub mod tests {
             body: String::into_bytes(String::from("xxx")),
             ..default_response()
         };
-        let error = eval_query(&jsonpath_success(), &variables, &http_response, &mut cache)
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &jsonpath_success(),
+            &variables,
+            &http_response,
+            &[],
+            &mut cache,
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
         assert_eq!(error.kind, RunnerErrorKind::QueryInvalidJson);
     }
 
+    #[test]
+    fn test_query_empty_body() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let http_response = default_response();
+        assert!(http_response.body.is_empty());
+
+        let error = eval_query(
+            &jsonpath_success(),
+            &variables,
+            &http_response,
+            &[],
+            &mut cache,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.kind, RunnerErrorKind::QueryEmptyBody);
+
+        let error = eval_query(&xpath_users(), &variables, &http_response, &[], &mut cache)
+            .err()
+            .unwrap();
+        assert_eq!(error.kind, RunnerErrorKind::QueryEmptyBody);
+
+        // Other body queries can still be used.
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 6)),
+            value: QueryValue::Bytes,
+        };
+        assert_eq!(
+            eval_query(&query, &variables, &http_response, &[], &mut cache)
+                .unwrap()
+                .unwrap(),
+            Value::Bytes(vec![])
+        );
+    }
+
     #[test]
     fn test_query_json_not_found() {
         let variables = VariableSet::new();
@@ -1209,7 +1473,14 @@ pub mod tests {
             ..default_response()
         };
         assert_eq!(
-            eval_query(&jsonpath_success(), &variables, &http_response, &mut cache).unwrap(),
+            eval_query(
+                &jsonpath_success(),
+                &variables,
+                &http_response,
+                &[],
+                &mut cache
+            )
+            .unwrap(),
             None
         );
     }
@@ -1224,6 +1495,7 @@ pub mod tests {
                 &jsonpath_success(),
                 &variables,
                 &http::json_http_response(),
+                &[],
                 &mut cache
             )
             .unwrap()
@@ -1235,6 +1507,7 @@ pub mod tests {
                 &jsonpath_errors(),
                 &variables,
                 &http::json_http_response(),
+                &[],
                 &mut cache
             )
             .unwrap()
@@ -1262,6 +1535,7 @@ pub mod tests {
                 &regex_name(),
                 &variables,
                 &http::hello_http_response(),
+                &[],
                 &mut cache
             )
             .unwrap()
@@ -1273,6 +1547,7 @@ pub mod tests {
             &regex_invalid(),
             &variables,
             &http::hello_http_response(),
+            &[],
             &mut cache,
         )
         .err()
@@ -1297,6 +1572,7 @@ pub mod tests {
                 },
                 &variables,
                 &http::hello_http_response(),
+                &[],
                 &mut cache,
             )
             .unwrap()
@@ -1321,6 +1597,7 @@ pub mod tests {
                     body: vec![0xff],
                     ..default_response()
                 },
+                &[],
                 &mut cache,
             )
             .unwrap()
@@ -20,11 +20,12 @@ use std::str::FromStr;
 use base64::engine::general_purpose;
 use base64::Engine;
 use hurl_core::ast::{
-    Body, Bytes, Method, MultilineString, MultilineStringKind, Request, Template,
+    Body, Bytes, KeyValue, Method, MultilineString, MultilineStringKind, Request, Template,
 };
 
 use crate::http;
 use crate::http::{HeaderVec, HttpError, Url, AUTHORIZATION};
+use crate::runner::body::StdinCache;
 use crate::runner::error::RunnerError;
 use crate::runner::{body, multipart, template, RunnerErrorKind, VariableSet};
 use crate::util::path::ContextDir;
@@ -34,6 +35,7 @@ pub fn eval_request(
     request: &Request,
     variables: &VariableSet,
     context_dir: &ContextDir,
+    stdin: &mut StdinCache,
 ) -> Result<http::RequestSpec, RunnerError> {
     let method = eval_method(&request.method);
     let url = eval_url(&request.url, variables)?;
@@ -49,6 +51,20 @@ pub fn eval_request(
 
     // Basic auth
     if let Some(kv) = &request.basic_auth() {
+        // The basic auth credentials are sent in the `Authorization` header: an explicit header
+        // would silently conflict with them.
+        if let Some((header, _)) = request
+            .headers
+            .iter()
+            .zip(headers.iter())
+            .find(|(_, h)| h.name_eq(AUTHORIZATION))
+        {
+            return Err(RunnerError::new(
+                header.key.source_info,
+                RunnerErrorKind::AuthorizationConflict,
+                false,
+            ));
+        }
         let name = template::eval_template(&kv.key, variables)?;
         let value = template::eval_template(&kv.value, variables)?;
         let user_password = format!("{}:{}", name, value);
@@ -87,7 +103,7 @@ pub fn eval_request(
     }
 
     let body = match &request.body {
-        Some(body) => body::eval_body(body, variables, context_dir)?,
+        Some(body) => body::eval_body(body, variables, context_dir, stdin)?,
         None => http::Body::Binary(vec![]),
     };
 
@@ -116,6 +132,12 @@ pub fn eval_request(
     }) = request.body
     {
         Some("application/json".to_string())
+    } else if let Some(Body {
+        value: Bytes::NdJson(..),
+        ..
+    }) = request.body
+    {
+        Some("application/x-ndjson".to_string())
     } else if let Some(Body {
         value:
             Bytes::Xml { .. }
@@ -127,6 +149,16 @@ pub fn eval_request(
     }) = request.body
     {
         Some("application/xml".to_string())
+    } else if let Some(Body {
+        value:
+            Bytes::MultilineString(MultilineString {
+                kind: MultilineStringKind::Html(..),
+                ..
+            }),
+        ..
+    }) = request.body
+    {
+        Some("text/html".to_string())
     } else {
         None
     };
@@ -158,6 +190,47 @@ fn eval_url(url_template: &Template, variables: &VariableSet) -> Result<Url, Run
     })
 }
 
+/// Adds the `default_headers` to `request` given a set of `variables`.
+///
+/// A default header is not added if `request` already has a header with the same name.
+pub fn add_default_headers(
+    request: &mut http::RequestSpec,
+    default_headers: &[KeyValue],
+    variables: &VariableSet,
+) -> Result<(), RunnerError> {
+    // Headers are checked against the request headers only, so a default header can be repeated.
+    let mut headers = vec![];
+    for header in default_headers {
+        let name = template::eval_template(&header.key, variables)?;
+        if request.headers.contains_key(&name) {
+            continue;
+        }
+        let value = template::eval_template(&header.value, variables)?;
+        headers.push(http::Header::new(&name, &value));
+    }
+    for header in headers {
+        request.headers.push(header);
+    }
+    Ok(())
+}
+
+/// Returns the base64 encoded credentials of the basic `Authorization` header of `request` if
+/// they contain one of the `secrets`.
+///
+/// As credentials are encoded, redacting a secret from logs is not enough to hide it: the encoded
+/// value must also be redacted.
+pub fn basic_auth_secret(request: &http::RequestSpec, secrets: &[String]) -> Option<String> {
+    let header = request.headers.get(AUTHORIZATION)?;
+    let token = header.value.strip_prefix("Basic ")?;
+    let credentials = general_purpose::STANDARD.decode(token).ok()?;
+    let credentials = String::from_utf8_lossy(&credentials);
+    if secrets.iter().any(|s| credentials.contains(s.as_str())) {
+        Some(token.to_string())
+    } else {
+        None
+    }
+}
+
 /// Experimental feature
 /// @cookie_storage_add
 pub fn cookie_storage_set(request: &Request) -> Option<String> {
@@ -355,9 +428,14 @@ mod tests {
     #[test]
     fn test_error_variable() {
         let variables = VariableSet::new();
-        let error = eval_request(&hello_request(), &variables, &ContextDir::default())
-            .err()
-            .unwrap();
+        let error = eval_request(
+            &hello_request(),
+            &variables,
+            &ContextDir::default(),
+            &mut StdinCache::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             error.source_info,
             SourceInfo::new(Pos::new(1, 7), Pos::new(1, 15))
@@ -370,6 +448,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_url_without_host() {
+        let mut variables = VariableSet::new();
+        variables
+            .insert(
+                String::from("base_url"),
+                Value::String(String::from("http://:8000")),
+            )
+            .unwrap();
+        let error = eval_request(
+            &hello_request(),
+            &variables,
+            &ContextDir::default(),
+            &mut StdinCache::default(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.source_info, hello_request().url.source_info);
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::InvalidUrl {
+                url: String::from("http://:8000/hello"),
+                message: String::from("empty host"),
+            }
+        );
+    }
+
     #[test]
     fn test_hello_request() {
         let mut variables = VariableSet::new();
@@ -379,8 +484,13 @@ mod tests {
                 Value::String(String::from("http://localhost:8000")),
             )
             .unwrap();
-        let http_request =
-            eval_request(&hello_request(), &variables, &ContextDir::default()).unwrap();
+        let http_request = eval_request(
+            &hello_request(),
+            &variables,
+            &ContextDir::default(),
+            &mut StdinCache::default(),
+        )
+        .unwrap();
         assert_eq!(http_request, http::hello_http_request());
     }
 
@@ -393,8 +503,13 @@ mod tests {
                 Value::String(String::from("value1")),
             )
             .unwrap();
-        let http_request =
-            eval_request(&query_request(), &variables, &ContextDir::default()).unwrap();
+        let http_request = eval_request(
+            &query_request(),
+            &variables,
+            &ContextDir::default(),
+            &mut StdinCache::default(),
+        )
+        .unwrap();
         assert_eq!(http_request, http::query_http_request());
     }
 
@@ -476,4 +591,124 @@ mod tests {
             })
         );
     }
+
+    fn parse_request(content: &str) -> Request {
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        hurl_file.entries[0].request.clone()
+    }
+
+    #[test]
+    fn test_basic_auth() {
+        let mut variables = VariableSet::new();
+        variables
+            .insert(
+                "password".to_string(),
+                Value::String("open sesame".to_string()),
+            )
+            .unwrap();
+        let request = parse_request(
+            "GET http://localhost:8000/basic-auth\n[BasicAuth]\nAladdin: {{password}}\n",
+        );
+        let http_request = eval_request(
+            &request,
+            &variables,
+            &ContextDir::default(),
+            &mut StdinCache::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            http_request.headers.get(AUTHORIZATION).unwrap().value,
+            "Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ=="
+        );
+
+        // Non ASCII credentials are encoded in UTF-8.
+        let request =
+            parse_request("GET http://localhost:8000/basic-auth\n[BasicAuth]\nbob: café\n");
+        let http_request = eval_request(
+            &request,
+            &variables,
+            &ContextDir::default(),
+            &mut StdinCache::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            http_request.headers.get(AUTHORIZATION).unwrap().value,
+            "Basic Ym9iOmNhZsOp"
+        );
+    }
+
+    #[test]
+    fn test_basic_auth_conflict() {
+        let variables = VariableSet::new();
+        let request = parse_request(
+            "GET http://localhost:8000/basic-auth\nauthorization: Bearer abc\n[BasicAuth]\nbob: secret\n",
+        );
+        let error = eval_request(
+            &request,
+            &variables,
+            &ContextDir::default(),
+            &mut StdinCache::default(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.kind, RunnerErrorKind::AuthorizationConflict);
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(2, 1), Pos::new(2, 14))
+        );
+    }
+
+    #[test]
+    fn test_add_default_headers() {
+        let mut variables = VariableSet::new();
+        variables
+            .insert("source".to_string(), Value::String("ci".to_string()))
+            .unwrap();
+        let default_headers = [
+            hurl_core::parser::parse_header("X-Request-Source: {{source}}").unwrap(),
+            hurl_core::parser::parse_header("User-Agent: hurl-ci").unwrap(),
+        ];
+        let request = parse_request("GET http://localhost:8000/hello\nuser-agent: curl/8.0.0\n");
+        let mut http_request = eval_request(
+            &request,
+            &variables,
+            &ContextDir::default(),
+            &mut StdinCache::default(),
+        )
+        .unwrap();
+        add_default_headers(&mut http_request, &default_headers, &variables).unwrap();
+
+        assert_eq!(
+            http_request.headers.get("X-Request-Source").unwrap().value,
+            "ci"
+        );
+        // The header of the request takes precedence.
+        assert_eq!(
+            http_request.headers.get_all("User-Agent"),
+            vec![&http::Header::new("user-agent", "curl/8.0.0")]
+        );
+    }
+
+    #[test]
+    fn test_basic_auth_secret() {
+        let variables = VariableSet::new();
+        let request =
+            parse_request("GET http://localhost:8000/basic-auth\n[BasicAuth]\nbob: s3cr3t\n");
+        let http_request = eval_request(
+            &request,
+            &variables,
+            &ContextDir::default(),
+            &mut StdinCache::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            basic_auth_secret(&http_request, &["s3cr3t".to_string()]),
+            Some("Ym9iOnMzY3IzdA==".to_string())
+        );
+        assert_eq!(basic_auth_secret(&http_request, &["foo".to_string()]), None);
+        assert_eq!(
+            basic_auth_secret(&http::hello_http_request(), &["s3cr3t".to_string()]),
+            None
+        );
+    }
 }
@@ -15,9 +15,12 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{Base64, Body, Bytes, Hex, Response, SourceInfo, StatusValue};
+use std::path::PathBuf;
+
+use hurl_core::ast::{Base64, Body, Bytes, File, Hex, Response, SourceInfo, StatusValue};
 
 use crate::http;
+use crate::runner::body::StdinCache;
 use crate::runner::cache::BodyCache;
 use crate::runner::error::{RunnerError, RunnerErrorKind};
 use crate::runner::result::{AssertResult, CaptureResult};
@@ -61,8 +64,10 @@ pub fn eval_asserts(
     response: &Response,
     variables: &VariableSet,
     http_response: &http::Response,
+    redirects: &[http::Call],
     cache: &mut BodyCache,
     context_dir: &ContextDir,
+    stdin: &mut StdinCache,
 ) -> Vec<AssertResult> {
     let mut asserts = vec![];
 
@@ -141,14 +146,20 @@ pub fn eval_asserts(
 
     // Second, evaluates implicit asserts on response body.
     if let Some(body) = &response.body {
-        let assert = eval_implicit_body_asserts(body, variables, http_response, context_dir);
+        let assert = eval_implicit_body_asserts(body, variables, http_response, context_dir, stdin);
         asserts.push(assert);
     }
 
     // Then, checks all the explicit asserts.
     for assert in response.asserts() {
-        let assert_result =
-            assert::eval_explicit_assert(assert, variables, http_response, cache, context_dir);
+        let assert_result = assert::eval_explicit_assert(
+            assert,
+            variables,
+            http_response,
+            redirects,
+            cache,
+            context_dir,
+        );
         asserts.push(assert_result);
     }
     asserts
@@ -160,6 +171,7 @@ fn eval_implicit_body_asserts(
     variables: &VariableSet,
     http_response: &http::Response,
     context_dir: &ContextDir,
+    stdin: &mut StdinCache,
 ) -> AssertResult {
     match &spec_body.value {
         Bytes::Json(value) => {
@@ -187,8 +199,38 @@ fn eval_implicit_body_asserts(
                 source_info: spec_body.space0.source_info,
             }
         }
+        Bytes::NdJson(value) => {
+            // Both sides are normalized, so that values are compared regardless of their
+            // whitespace. A response that isn't a valid NDJSON is compared as it is.
+            let expected = match json::eval_ndjson(value, variables) {
+                Ok(s) => Ok(Value::String(json::normalize_ndjson(&s).unwrap_or(s))),
+                Err(e) => Err(e),
+            };
+            let actual = match http_response.text() {
+                Ok(s) => Ok(Value::String(json::normalize_ndjson(&s).unwrap_or(s))),
+                Err(e) => {
+                    let source_info = SourceInfo {
+                        start: spec_body.space0.source_info.end,
+                        end: spec_body.space0.source_info.end,
+                    };
+                    Err(RunnerError::new(
+                        source_info,
+                        RunnerErrorKind::Http(e),
+                        true,
+                    ))
+                }
+            };
+            AssertResult::Body {
+                actual,
+                expected,
+                source_info: spec_body.space0.source_info,
+            }
+        }
         Bytes::Xml(value) => {
-            let expected = Ok(Value::String(value.to_string()));
+            let expected = match template::eval_template(value, variables) {
+                Ok(s) => Ok(Value::String(s)),
+                Err(e) => Err(e),
+            };
             let actual = match http_response.text() {
                 Ok(s) => Ok(Value::String(s)),
                 Err(e) => {
@@ -319,9 +361,18 @@ fn eval_implicit_body_asserts(
                 },
             }
         }
-        Bytes::File { .. } => {
-            let expected = match body::eval_body(spec_body, variables, context_dir) {
-                Ok(body) => Ok(Value::Bytes(body.bytes())),
+        Bytes::File(File { filename, .. }) => {
+            let expected = match body::eval_body(spec_body, variables, context_dir, stdin) {
+                Ok(body) => match body.read_bytes() {
+                    Ok(bytes) => Ok(Value::Bytes(bytes)),
+                    // The file is reported as written by the user, not with its resolved path.
+                    Err(_) => template::eval_template(filename, variables).and_then(|path| {
+                        let kind = RunnerErrorKind::FileReadAccess {
+                            path: PathBuf::from(path),
+                        };
+                        Err(RunnerError::new(filename.source_info, kind, false))
+                    }),
+                },
                 Err(e) => Err(e),
             };
             let actual = match http_response.uncompress_body() {
@@ -351,12 +402,14 @@ fn eval_implicit_body_asserts(
 pub fn eval_captures(
     response: &Response,
     http_response: &http::Response,
+    redirects: &[http::Call],
     cache: &mut BodyCache,
     variables: &mut VariableSet,
 ) -> Result<Vec<CaptureResult>, RunnerError> {
     let mut captures = vec![];
     for capture in response.captures() {
-        let capture_result = capture::eval_capture(capture, variables, http_response, cache)?;
+        let capture_result =
+            capture::eval_capture(capture, variables, http_response, redirects, cache)?;
         // Update variables now so the captures set is ready in case
         // the next captures reference this new variable.
         let name = capture_result.name.clone();
@@ -438,8 +491,10 @@ mod tests {
                 &user_response(),
                 &variables,
                 &http::xml_two_users_http_response(),
+                &[],
                 &mut cache,
                 &context_dir,
+                &mut StdinCache::default(),
             ),
             vec![AssertResult::Explicit {
                 actual: Ok(Some(Value::Number(Number::Integer(2)))),
@@ -457,6 +512,51 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_eval_ndjson_body_assert() {
+        let content = "GET http://localhost:8000/ndjson\nHTTP 200\n{\"id\": 1, \"tags\": [\"a\"]}\n{ \"id\" : 2 }\n";
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let body = hurl_file.entries[0]
+            .response
+            .as_ref()
+            .unwrap()
+            .body
+            .as_ref()
+            .unwrap();
+        let variables = VariableSet::new();
+        let context_dir = ContextDir::default();
+        let response = |text: &str| http::Response {
+            body: text.as_bytes().to_vec(),
+            ..http::xml_two_users_http_response()
+        };
+
+        // The actual body is compared with the same whitespace normalization as the expected one.
+        for text in [
+            "{\"id\":1,\"tags\":[\"a\"]}\n{\"id\":2}\n",
+            "{ \"id\": 1, \"tags\": [ \"a\" ] }\n\n{\"id\" :2}",
+        ] {
+            let assert = eval_implicit_body_asserts(
+                body,
+                &variables,
+                &response(text),
+                &context_dir,
+                &mut StdinCache::default(),
+            );
+            assert_eq!(assert.error(), None);
+        }
+
+        for text in ["{\"id\":1,\"tags\":[\"a\"]}\n", "{\"id\":1"] {
+            let assert = eval_implicit_body_asserts(
+                body,
+                &variables,
+                &response(text),
+                &context_dir,
+                &mut StdinCache::default(),
+            );
+            assert!(assert.error().is_some());
+        }
+    }
+
     #[test]
     pub fn test_eval_version_status_asserts() {
         assert_eq!(
@@ -485,6 +585,7 @@ mod tests {
             eval_captures(
                 &user_response(),
                 &http::xml_two_users_http_response(),
+                &[],
                 &mut cache,
                 &mut variables,
             )
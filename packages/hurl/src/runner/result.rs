@@ -19,6 +19,7 @@ use std::time::Duration;
 
 use hurl_core::ast::SourceInfo;
 use hurl_core::reader::Pos;
+use serde::{Deserialize, Serialize};
 
 use crate::http::{Call, Cookie, CurlCmd};
 use crate::runner::error::RunnerError;
@@ -33,7 +34,8 @@ use crate::util::term::Stdout;
 pub struct HurlResult {
     /// The entries result for this run.
     pub entries: Vec<EntryResult>,
-    /// Total duration of the run, including asserts and results computation.
+    /// Total duration of the run, including asserts and results computation, but excluding
+    /// delays between entries.
     pub duration: Duration,
     /// `true` if the run is successful, `false` if there has been runtime or asserts errors.
     pub success: bool,
@@ -69,6 +71,109 @@ impl HurlResult {
         }
         errors
     }
+
+    /// Returns a summary of this run: entries, asserts and errors counts.
+    pub fn summary(&self) -> RunSummary {
+        RunSummary::new(&self.entries, self.duration)
+    }
+}
+
+/// Aggregated counts of a Hurl file execution.
+///
+/// As in [`HurlResult::errors`], only the last result of an entry is taken into account: results
+/// from previous retries are ignored. The summary is serialized in the JSON report, with its duration
+/// in milliseconds.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RunSummary {
+    /// Number of entries that have been run.
+    pub entries_count: usize,
+    /// Number of entries that have been skipped (not run).
+    pub entries_skipped_count: usize,
+    /// Number of entries in error.
+    pub entries_failed_count: usize,
+    /// Number of asserts that have been evaluated, implicit and explicit.
+    pub asserts_count: usize,
+    /// Number of failed asserts, that have made their entry fail.
+    pub asserts_failed_count: usize,
+    /// Number of failed asserts that have not been enforced (with `--ignore-asserts`), and have not
+    /// made their entry fail.
+    pub asserts_ignored_count: usize,
+    /// Number of errors, including failed asserts.
+    pub errors_count: usize,
+    /// Total duration of the run.
+    #[serde(rename = "time", with = "duration_ms")]
+    pub duration: Duration,
+}
+
+/// Returns the last result of each entry of `entries`: results of previous retries are skipped.
+pub(crate) fn last_results(entries: &[EntryResult]) -> impl Iterator<Item = &EntryResult> {
+    entries.iter().enumerate().filter_map(|(i, entry)| {
+        let is_last = entries
+            .get(i + 1)
+            .is_none_or(|next| next.entry_index != entry.entry_index);
+        is_last.then_some(entry)
+    })
+}
+
+/// Serializes a [`Duration`] as a number of milliseconds.
+mod duration_ms {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_millis() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_millis)
+    }
+}
+
+impl RunSummary {
+    /// Folds a list of entry results into a new summary.
+    pub fn new(entries: &[EntryResult], duration: Duration) -> Self {
+        let mut summary = RunSummary {
+            duration,
+            ..Default::default()
+        };
+        for entry in last_results(entries) {
+            if entry.skipped {
+                summary.entries_skipped_count += 1;
+                continue;
+            }
+            // A failed assert has only made its entry fail if its error has been recorded.
+            let (asserts_failed_count, asserts_ignored_count) = entry
+                .asserts
+                .iter()
+                .filter_map(|a| a.error())
+                .fold((0, 0), |(failed, ignored), error| {
+                    let enforced = entry
+                        .errors
+                        .iter()
+                        .any(|e| e.source_info == error.source_info && e.kind == error.kind);
+                    if enforced {
+                        (failed + 1, ignored)
+                    } else {
+                        (failed, ignored + 1)
+                    }
+                });
+            summary.entries_count += 1;
+            if !entry.errors.is_empty() {
+                summary.entries_failed_count += 1;
+            }
+            summary.asserts_count += entry.asserts.len();
+            summary.asserts_failed_count += asserts_failed_count;
+            summary.asserts_ignored_count += asserts_ignored_count;
+            summary.errors_count += entry.errors.len();
+        }
+        summary
+    }
+
+    /// Returns the number of succeeded asserts.
+    pub fn asserts_passed_count(&self) -> usize {
+        self.asserts_count - self.asserts_failed_count - self.asserts_ignored_count
+    }
 }
 
 /// Represents the execution result of an entry.
@@ -95,6 +200,9 @@ pub struct EntryResult {
     pub compressed: bool,
     /// The debug curl command line from this entry result.
     pub curl_cmd: CurlCmd,
+    /// The entry has not been executed because of `skip` or `skip-unless` options (or a repeat
+    /// count of 0): a skipped entry has no call, capture, assert or error.
+    pub skipped: bool,
 }
 
 impl Default for EntryResult {
@@ -109,6 +217,7 @@ impl Default for EntryResult {
             transfer_duration: Duration::from_millis(0),
             compressed: false,
             curl_cmd: CurlCmd::default(),
+            skipped: false,
         }
     }
 }
@@ -213,3 +322,152 @@ impl EntryResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_assert(actual: u64, expected: u64) -> AssertResult {
+        AssertResult::Status {
+            actual,
+            expected,
+            source_info: SourceInfo::new(Pos::new(2, 6), Pos::new(2, 9)),
+        }
+    }
+
+    fn skipped_entry_result(entry_index: usize) -> EntryResult {
+        EntryResult {
+            entry_index,
+            skipped: true,
+            ..Default::default()
+        }
+    }
+
+    fn entry_result(entry_index: usize, asserts: Vec<AssertResult>) -> EntryResult {
+        let errors = asserts.iter().filter_map(|a| a.error()).collect();
+        EntryResult {
+            entry_index,
+            asserts,
+            errors,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_run_summary() {
+        let entries = vec![
+            entry_result(1, vec![status_assert(200, 200)]),
+            // Entry 2 is retried: only its last result is counted.
+            entry_result(2, vec![status_assert(500, 200)]),
+            entry_result(2, vec![status_assert(200, 200)]),
+            entry_result(3, vec![status_assert(404, 200), status_assert(200, 200)]),
+            // Entry 4 is skipped: it's not counted as a run entry.
+            skipped_entry_result(4),
+        ];
+        let summary = RunSummary::new(&entries, Duration::from_millis(100));
+        assert_eq!(
+            summary,
+            RunSummary {
+                entries_count: 3,
+                entries_skipped_count: 1,
+                entries_failed_count: 1,
+                asserts_count: 4,
+                asserts_failed_count: 1,
+                asserts_ignored_count: 0,
+                errors_count: 1,
+                duration: Duration::from_millis(100),
+            }
+        );
+        assert_eq!(summary.asserts_passed_count(), 3);
+
+        // With `--ignore-asserts`, failed asserts are not recorded as errors.
+        let entries = vec![
+            EntryResult {
+                errors: vec![],
+                ..entry_result(1, vec![status_assert(404, 200), status_assert(200, 200)])
+            },
+            entry_result(2, vec![status_assert(500, 200)]),
+        ];
+        let summary = RunSummary::new(&entries, Duration::ZERO);
+        assert_eq!(summary.entries_failed_count, 1);
+        assert_eq!(summary.asserts_count, 3);
+        assert_eq!(summary.asserts_failed_count, 1);
+        assert_eq!(summary.asserts_ignored_count, 1);
+        assert_eq!(summary.asserts_passed_count(), 1);
+
+        let summary = RunSummary::new(&[], Duration::ZERO);
+        assert_eq!(summary, RunSummary::default());
+    }
+
+    #[test]
+    fn test_run_summary_to_json() {
+        let entries = vec![
+            entry_result(1, vec![status_assert(200, 200)]),
+            entry_result(2, vec![status_assert(404, 200), status_assert(200, 200)]),
+            skipped_entry_result(3),
+        ];
+        let summary = RunSummary::new(&entries, Duration::from_millis(230));
+        let value = serde_json::to_value(&summary).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "entries_count": 2,
+                "entries_skipped_count": 1,
+                "entries_failed_count": 1,
+                "asserts_count": 3,
+                "asserts_failed_count": 1,
+                "asserts_ignored_count": 0,
+                "errors_count": 1,
+                "time": 230,
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<RunSummary>(value).unwrap(),
+            summary
+        );
+    }
+
+    #[test]
+    fn test_errors_with_skipped_entries() {
+        let entry_source_info = |line| SourceInfo::new(Pos::new(line, 1), Pos::new(line, 4));
+        let entries = vec![
+            skipped_entry_result(1),
+            // Entry 2 is retried: only the errors of its last result are effective.
+            entry_result(2, vec![status_assert(500, 200)]),
+            entry_result(2, vec![status_assert(404, 200)]),
+            skipped_entry_result(3),
+            entry_result(4, vec![status_assert(500, 200)]),
+            skipped_entry_result(5),
+        ];
+        let entries = entries
+            .into_iter()
+            .map(|e| EntryResult {
+                source_info: entry_source_info(e.entry_index),
+                ..e
+            })
+            .collect();
+        let hurl_result = HurlResult {
+            entries,
+            duration: Duration::ZERO,
+            success: false,
+            cookies: vec![],
+            timestamp: 0,
+        };
+
+        let errors = hurl_result
+            .errors()
+            .into_iter()
+            .map(|(error, source_info)| (error.kind.clone(), source_info))
+            .collect::<Vec<_>>();
+        let status_error = |actual: &str| RunnerErrorKind::AssertStatus {
+            actual: actual.to_string(),
+        };
+        assert_eq!(
+            errors,
+            vec![
+                (status_error("404"), entry_source_info(2)),
+                (status_error("500"), entry_source_info(4)),
+            ]
+        );
+    }
+}
@@ -17,25 +17,29 @@
  */
 use std::time::Duration;
 
-use hurl_core::ast::Entry;
+use hurl_core::ast::{Entry, KeyValue};
 use hurl_core::typing::{BytesPerSec, Count};
 
-use crate::http::{IpResolve, RequestedHttpVersion};
+use crate::http::{BodyCompression, IpResolve, RequestedHttpVersion};
 use crate::runner::Output;
 use crate::util::path::ContextDir;
 
 pub struct RunnerOptionsBuilder {
     aws_sigv4: Option<String>,
     cacert_file: Option<String>,
+    chunked: bool,
     client_cert_file: Option<String>,
     client_key_file: Option<String>,
+    compress_body: Option<BodyCompression>,
     compressed: bool,
     connect_timeout: Duration,
     connects_to: Vec<String>,
     context_dir: ContextDir,
     continue_on_error: bool,
     cookie_input_file: Option<String>,
+    default_headers: Vec<KeyValue>,
     delay: Duration,
+    dry_run: bool,
     follow_location: bool,
     follow_location_trusted: bool,
     from_entry: Option<usize>,
@@ -61,6 +65,9 @@ pub struct RunnerOptionsBuilder {
     resolves: Vec<String>,
     retry: Option<Count>,
     retry_interval: Duration,
+    retry_max_interval: Option<Duration>,
+    retry_max_time: Option<Duration>,
+    retry_rate_limited: bool,
     skip: bool,
     ssl_no_revoke: bool,
     timeout: Duration,
@@ -75,15 +82,19 @@ impl Default for RunnerOptionsBuilder {
         RunnerOptionsBuilder {
             aws_sigv4: None,
             cacert_file: None,
+            chunked: false,
             client_cert_file: None,
             client_key_file: None,
+            compress_body: None,
             compressed: false,
             connect_timeout: Duration::from_secs(300),
             connects_to: vec![],
             context_dir: ContextDir::default(),
             continue_on_error: false,
             cookie_input_file: None,
+            default_headers: vec![],
             delay: Duration::from_millis(0),
+            dry_run: false,
             follow_location: false,
             follow_location_trusted: false,
             from_entry: None,
@@ -109,6 +120,9 @@ impl Default for RunnerOptionsBuilder {
             resolves: vec![],
             retry: None,
             retry_interval: Duration::from_millis(1000),
+            retry_max_interval: None,
+            retry_max_time: None,
+            retry_rate_limited: false,
             skip: false,
             ssl_no_revoke: false,
             timeout: Duration::from_secs(300),
@@ -139,6 +153,12 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sends the request body with chunked transfer encoding, without `Content-Length` header.
+    pub fn chunked(&mut self, chunked: bool) -> &mut Self {
+        self.chunked = chunked;
+        self
+    }
+
     /// Sets Client certificate file and password.
     pub fn client_cert_file(&mut self, client_cert_file: Option<String>) -> &mut Self {
         self.client_cert_file = client_cert_file;
@@ -151,6 +171,12 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Compresses the request body with `compress_body` before sending it.
+    pub fn compress_body(&mut self, compress_body: Option<BodyCompression>) -> &mut Self {
+        self.compress_body = compress_body;
+        self
+    }
+
     /// Requests a compressed response using one of the algorithms br, gzip, deflate and
     /// automatically decompress the content.
     pub fn compressed(&mut self, compressed: bool) -> &mut Self {
@@ -183,6 +209,15 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Evaluates requests without sending them.
+    ///
+    /// Requests are fully evaluated and logged, but no HTTP transfer is done, so captures and
+    /// asserts are not computed.
+    pub fn dry_run(&mut self, dry_run: bool) -> &mut Self {
+        self.dry_run = dry_run;
+        self
+    }
+
     /// Sets root file system to import files in Hurl.
     ///
     /// This is used for both files in multipart form data and request body.
@@ -206,6 +241,15 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Adds headers to each request, unless the request already has a header with the same name.
+    ///
+    /// Contrary to [`RunnerOptionsBuilder::headers`], headers of the request take precedence.
+    /// Names and values are templates evaluated with the variables of each request.
+    pub fn default_headers(&mut self, default_headers: &[KeyValue]) -> &mut Self {
+        self.default_headers = default_headers.to_vec();
+        self
+    }
+
     /// Sets stopping or continuing executing requests to the end of the Hurl file even when an assert error occurs.
     ///
     /// By default, Hurl exits after an assert error in the HTTP response. Note that this option does
@@ -379,6 +423,35 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the maximum duration between each retry.
+    ///
+    /// When set, the duration between each retry doubles, starting from the retry interval, up to
+    /// this maximum (exponential backoff). Default is `None`: the retry interval is constant.
+    pub fn retry_max_interval(&mut self, retry_max_interval: Option<Duration>) -> &mut Self {
+        self.retry_max_interval = retry_max_interval;
+        self
+    }
+
+    /// Sets the maximum duration of the retries of an entry.
+    ///
+    /// An entry is not retried anymore if the next attempt would start after this duration,
+    /// measured from the first attempt. Default is `None`: only the retry count is taken into
+    /// account.
+    pub fn retry_max_time(&mut self, retry_max_time: Option<Duration>) -> &mut Self {
+        self.retry_max_time = retry_max_time;
+        self
+    }
+
+    /// Sets retry once on rate limited responses.
+    ///
+    /// When true, an entry failing with a 429 or 503 response is retried once, even if `retry` is
+    /// not set, after the delay given by the `Retry-After` header (or the retry interval).
+    /// Default is false.
+    pub fn retry_rate_limited(&mut self, retry_rate_limited: bool) -> &mut Self {
+        self.retry_rate_limited = retry_rate_limited;
+        self
+    }
+
     pub fn ssl_no_revoke(&mut self, ssl_no_revoke: bool) -> &mut Self {
         self.ssl_no_revoke = ssl_no_revoke;
         self
@@ -421,8 +494,10 @@ impl RunnerOptionsBuilder {
         RunnerOptions {
             aws_sigv4: self.aws_sigv4.clone(),
             cacert_file: self.cacert_file.clone(),
+            chunked: self.chunked,
             client_cert_file: self.client_cert_file.clone(),
             client_key_file: self.client_key_file.clone(),
+            compress_body: self.compress_body,
             compressed: self.compressed,
             connect_timeout: self.connect_timeout,
             connects_to: self.connects_to.clone(),
@@ -430,6 +505,8 @@ impl RunnerOptionsBuilder {
             context_dir: self.context_dir.clone(),
             continue_on_error: self.continue_on_error,
             cookie_input_file: self.cookie_input_file.clone(),
+            default_headers: self.default_headers.clone(),
+            dry_run: self.dry_run,
             follow_location: self.follow_location,
             follow_location_trusted: self.follow_location_trusted,
             from_entry: self.from_entry,
@@ -455,6 +532,9 @@ impl RunnerOptionsBuilder {
             resolves: self.resolves.clone(),
             retry: self.retry,
             retry_interval: self.retry_interval,
+            retry_max_interval: self.retry_max_interval,
+            retry_max_time: self.retry_max_time,
+            retry_rate_limited: self.retry_rate_limited,
             skip: self.skip,
             ssl_no_revoke: self.ssl_no_revoke,
             timeout: self.timeout,
@@ -466,12 +546,16 @@ impl RunnerOptionsBuilder {
     }
 }
 
+// Hooks are compared by address, which is fine for the options equality used in tests.
+#[allow(unknown_lints, unpredictable_function_pointer_comparisons)]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RunnerOptions {
     pub(crate) aws_sigv4: Option<String>,
     pub(crate) cacert_file: Option<String>,
+    pub(crate) chunked: bool,
     pub(crate) client_cert_file: Option<String>,
     pub(crate) client_key_file: Option<String>,
+    pub(crate) compress_body: Option<BodyCompression>,
     pub(crate) compressed: bool,
     pub(crate) connect_timeout: Duration,
     pub(crate) connects_to: Vec<String>,
@@ -479,6 +563,8 @@ pub struct RunnerOptions {
     pub(crate) context_dir: ContextDir,
     pub(crate) continue_on_error: bool,
     pub(crate) cookie_input_file: Option<String>,
+    pub(crate) default_headers: Vec<KeyValue>,
+    pub(crate) dry_run: bool,
     pub(crate) follow_location: bool,
     pub(crate) follow_location_trusted: bool,
     pub(crate) from_entry: Option<usize>,
@@ -504,6 +590,9 @@ pub struct RunnerOptions {
     pub(crate) resolves: Vec<String>,
     pub(crate) retry: Option<Count>,
     pub(crate) retry_interval: Duration,
+    pub(crate) retry_max_interval: Option<Duration>,
+    pub(crate) retry_max_time: Option<Duration>,
+    pub(crate) retry_rate_limited: bool,
     pub(crate) skip: bool,
     pub(crate) ssl_no_revoke: bool,
     pub(crate) timeout: Duration,
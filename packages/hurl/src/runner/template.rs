@@ -15,10 +15,10 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{Placeholder, Template, TemplateElement};
+use hurl_core::ast::{ExprKind, Placeholder, Template, TemplateElement};
 
 use crate::runner::error::RunnerError;
-use crate::runner::{expr, VariableSet};
+use crate::runner::{expr, Value, VariableSet};
 
 /// Renders to string a `template` given a map of variables.
 pub fn eval_template(template: &Template, variables: &VariableSet) -> Result<String, RunnerError> {
@@ -33,6 +33,24 @@ pub fn eval_template(template: &Template, variables: &VariableSet) -> Result<Str
     Ok(value)
 }
 
+/// Returns the bytes of a `template` made of a single variable holding bytes (for instance, a
+/// `{{data}}` template where `data` has been captured with a `bytes` query).
+///
+/// Returns `None` if the template is anything else, in which case it should be rendered to a string.
+pub fn eval_template_bytes(template: &Template, variables: &VariableSet) -> Option<Vec<u8>> {
+    let [TemplateElement::Placeholder(Placeholder { expr, .. })] = template.elements.as_slice()
+    else {
+        return None;
+    };
+    let ExprKind::Variable(variable) = &expr.kind else {
+        return None;
+    };
+    match variables.get(&variable.name)?.value() {
+        Value::Bytes(bytes) => Some(bytes.clone()),
+        _ => None,
+    }
+}
+
 fn eval_template_element(
     template_element: &TemplateElement,
     variables: &VariableSet,
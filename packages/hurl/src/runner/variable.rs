@@ -184,7 +184,7 @@ mod test {
                 VariableKind::Secret
             ))
         );
-        assert!(variables.get("BAZ").is_none())
+        assert!(variables.get("BAZ").is_none());
     }
 
     #[test]
@@ -136,6 +136,13 @@ impl Logger {
         }
     }
 
+    /// Adds a `secret` value to redact from this logger messages.
+    pub(crate) fn add_secret(&mut self, secret: String) {
+        if !self.secrets.contains(&secret) {
+            self.secrets.push(secret);
+        }
+    }
+
     /// Prints a given message to this logger [`Stderr`] instance, no matter what is the verbosity.
     pub fn info(&mut self, message: &str) {
         self.stderr.eprintln(message);
@@ -357,3 +364,88 @@ impl Logger {
         self.stderr.eprintln(&redacted);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::{RunnerError, RunnerErrorKind};
+    use crate::util::term::WriteMode;
+
+    fn new_logger(verbosity: Option<Verbosity>) -> Logger {
+        let options = LoggerOptionsBuilder::new().verbosity(verbosity).build();
+        Logger::new(&options, Stderr::new(WriteMode::Buffered), &[])
+    }
+
+    #[test]
+    fn test_messages_filtered_by_verbosity() {
+        let mut logger = new_logger(None);
+        logger.info("info");
+        logger.debug("debug");
+        logger.warning("warning");
+        assert_eq!(logger.stderr.buffer(), "info\nwarning: warning\n");
+
+        let mut logger = new_logger(Some(Verbosity::Verbose));
+        logger.info("info");
+        logger.debug("debug");
+        logger.warning("warning");
+        assert_eq!(logger.stderr.buffer(), "info\n* debug\nwarning: warning\n");
+    }
+
+    #[test]
+    fn test_secrets_redacted() {
+        let options = LoggerOptionsBuilder::new()
+            .verbosity(Some(Verbosity::Verbose))
+            .build();
+        let secrets = ["xyz".to_string()];
+        let mut logger = Logger::new(&options, Stderr::new(WriteMode::Buffered), &secrets);
+        logger.add_secret("s3cr3t".to_string());
+        logger.debug_headers_out(&[("Authorization", "Bearer xyz")]);
+        logger.capture("token", &Value::String("s3cr3t".to_string()));
+        assert_eq!(
+            logger.stderr.buffer(),
+            "> Authorization: Bearer ***\n>\n* token: ***\n"
+        );
+    }
+
+    fn log_all(logger: &mut Logger) {
+        let content = "GET http://localhost:8000/not_found\nHTTP 200\n";
+        let error = RunnerError::new(
+            SourceInfo::new(Pos::new(2, 6), Pos::new(2, 9)),
+            RunnerErrorKind::AssertStatus {
+                actual: "404".to_string(),
+            },
+            true,
+        );
+        let entry_src_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 36));
+        logger.debug_important("Executing entry 1");
+        logger.debug_method_version_out("GET /not_found HTTP/1.1");
+        logger.debug_headers_out(&[("Host", "localhost:8000")]);
+        logger.debug_status_version_in("HTTP/1.1 404");
+        logger.debug_headers_in(&[("Content-Length", "0")]);
+        logger.warning("warning");
+        logger.error_runtime_rich(content, None, &error, entry_src_info);
+    }
+
+    #[test]
+    fn test_no_color() {
+        hurl_core::text::init_crate_colored();
+
+        let options = LoggerOptionsBuilder::new()
+            .color(false)
+            .verbosity(Some(Verbosity::VeryVerbose))
+            .build();
+        let mut logger = Logger::new(&options, Stderr::new(WriteMode::Buffered), &[]);
+        log_all(&mut logger);
+        assert!(!logger.stderr.buffer().contains('\x1b'));
+
+        let options = LoggerOptionsBuilder::new()
+            .color(true)
+            .verbosity(Some(Verbosity::VeryVerbose))
+            .build();
+        let mut logger = Logger::new(&options, Stderr::new(WriteMode::Buffered), &[]);
+        log_all(&mut logger);
+        assert!(logger.stderr.buffer().contains('\x1b'));
+    }
+}
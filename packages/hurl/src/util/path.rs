@@ -61,7 +61,18 @@ impl ContextDir {
         let file = self.resolved_path(filename);
         let absolute_file = self.current_dir.join(file);
         let absolute_file_root = self.current_dir.join(&self.file_root);
-        is_descendant(absolute_file.as_path(), absolute_file_root.as_path())
+        if !is_descendant(absolute_file.as_path(), absolute_file_root.as_path()) {
+            return false;
+        }
+        // The check above is purely lexical: a symbolic link inside the file root can still
+        // point outside it. When the file exists, we also check its canonical path.
+        match (
+            absolute_file.canonicalize(),
+            absolute_file_root.canonicalize(),
+        ) {
+            (Ok(file), Ok(file_root)) => file.starts_with(file_root),
+            _ => true,
+        }
     }
 }
 
@@ -212,6 +223,30 @@ mod tests {
         assert!(!ctx.is_access_allowed(Path::new("../../file/foo.bin")));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn check_filename_allowed_access_with_symlink() {
+        // ```
+        // $ cd /tmp/hurl-xxx
+        // $ hurl --file-root root test.hurl
+        // ```
+        // with `root/secret.txt` being a symbolic link to `../secret.txt`.
+        let current_dir = std::env::temp_dir().join(format!("hurl-{}", uuid::Uuid::new_v4()));
+        let root = current_dir.join("root");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(current_dir.join("secret.txt"), "secret").unwrap();
+        std::fs::write(root.join("public.txt"), "public").unwrap();
+        std::os::unix::fs::symlink(current_dir.join("secret.txt"), root.join("secret.txt"))
+            .unwrap();
+
+        let ctx = ContextDir::new(&current_dir, Path::new("root"));
+        assert!(ctx.is_access_allowed(Path::new("public.txt")));
+        assert!(!ctx.is_access_allowed(Path::new("secret.txt")));
+        assert!(!ctx.is_access_allowed(Path::new("../secret.txt")));
+
+        std::fs::remove_dir_all(&current_dir).unwrap();
+    }
+
     #[test]
     fn is_descendant_true() {
         let child = Path::new("/tmp/foo/bar.txt");
@@ -39,7 +39,8 @@ impl RedactedString<'_> {
     /// Appends a given string slice onto the end of this `RedactedString`.
     pub fn push_str(&mut self, string: &str) {
         let mut value = string.to_string();
-        for s in self.secrets {
+        // An empty secret (an empty environment variable for instance) has nothing to redact.
+        for s in self.secrets.iter().filter(|s| !s.is_empty()) {
             value = value.replace(s, "***");
         }
         self.value.push_str(&value);
@@ -86,6 +87,6 @@ mod tests {
         assert_eq(
             &rs,
             "Hello, here are secrets values: ***, ***, ***. Baz is not secret",
-        )
+        );
     }
 }
@@ -278,6 +278,18 @@ pub struct FileValue {
     pub space1: Whitespace,
     pub space2: Whitespace,
     pub content_type: Option<String>,
+    pub part_filename: Option<Box<PartFilename>>,
+}
+
+/// Explicit filename of a multipart file part, `filename=avatar.png`, overriding the name of the
+/// file read from disk.
+///
+/// When a content type is specified, the filename is separated from it by a `;`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartFilename {
+    pub space0: Whitespace,
+    pub space1: Whitespace,
+    pub value: Template,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -313,7 +325,10 @@ pub struct Query {
 #[allow(clippy::large_enum_variant)]
 pub enum QueryValue {
     Status,
+    Version,
     Url,
+    Redirects,
+    Location,
     Header {
         space0: Whitespace,
         name: Template,
@@ -485,6 +500,10 @@ pub enum PredicateFuncValue {
         space0: Whitespace,
         value: PredicateValue,
     },
+    MatchSchema {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
     IsInteger,
     IsFloat,
     IsBoolean,
@@ -512,22 +531,29 @@ pub enum MultilineStringKind {
     Text(Text),
     Json(Text),
     Xml(Text),
+    Html(Text),
     GraphQl(GraphQl),
+    /// A text with a language hint not known by Hurl (`yaml`, `csv` etc...).
+    Other(String, Text),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum MultilineStringAttribute {
     Escape,
     NoVariable,
+    /// Allows `//` comments and trailing commas in a JSON multiline string.
+    Lenient,
 }
 
 impl MultilineString {
-    pub fn lang(&self) -> &'static str {
-        match self.kind {
+    pub fn lang(&self) -> &str {
+        match &self.kind {
             MultilineStringKind::Text(_) => "",
             MultilineStringKind::Json(_) => "json",
             MultilineStringKind::Xml(_) => "xml",
+            MultilineStringKind::Html(_) => "html",
             MultilineStringKind::GraphQl(_) => "graphql",
+            MultilineStringKind::Other(lang, _) => lang,
         }
     }
 
@@ -535,7 +561,9 @@ impl MultilineString {
         match &self.kind {
             MultilineStringKind::Text(text)
             | MultilineStringKind::Json(text)
-            | MultilineStringKind::Xml(text) => text.value.clone(),
+            | MultilineStringKind::Xml(text)
+            | MultilineStringKind::Html(text)
+            | MultilineStringKind::Other(_, text) => text.value.clone(),
             MultilineStringKind::GraphQl(text) => text.value.clone(),
         }
     }
@@ -569,6 +597,19 @@ pub struct Base64 {
     pub value: Vec<u8>,
     pub encoded: String,
     pub space1: Whitespace,
+    /// Is the value encoded with the URL and filename safe alphabet (`base64url,`)?
+    pub url_safe: bool,
+}
+
+impl Base64 {
+    /// Returns the keyword used to introduce this base64 value.
+    pub fn keyword(&self) -> &'static str {
+        if self.url_safe {
+            "base64url"
+        } else {
+            "base64"
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -690,12 +731,26 @@ pub struct LineTerminator {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Bytes {
     Json(json::Value),
-    Xml(String),
+    Xml(Template),
     MultilineString(MultilineString),
     OnelineString(Template),
     Base64(Base64),
     File(File),
     Hex(Hex),
+    NdJson(NdJson),
+}
+
+/// A newline-delimited JSON body: a list of JSON values, each one starting on a new line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NdJson {
+    pub values: Vec<NdJsonValue>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NdJsonValue {
+    /// Whitespaces and newlines preceding this value (empty for the first value).
+    pub space0: String,
+    pub value: json::Value,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -747,6 +802,7 @@ pub struct Expr {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ExprKind {
     Variable(Variable),
+    VariableDefault(Box<VariableDefault>),
     Function(Function),
 }
 
@@ -756,6 +812,17 @@ pub struct Variable {
     pub source_info: SourceInfo,
 }
 
+/// A variable with a fallback value, used when the variable is not defined:
+/// `{{name | default "value"}}`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariableDefault {
+    pub variable: Variable,
+    pub space0: Whitespace,
+    pub space1: Whitespace,
+    pub space2: Whitespace,
+    pub value: Template,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Function {
     NewDate,
@@ -782,8 +849,10 @@ pub struct EntryOption {
 pub enum OptionKind {
     AwsSigV4(Template),
     CaCertificate(Template),
+    Chunked(BooleanOption),
     ClientCert(Template),
     ClientKey(Template),
+    CompressBody(Template),
     Compressed(BooleanOption),
     ConnectTo(Template),
     ConnectTimeout(DurationOption),
@@ -799,6 +868,7 @@ pub enum OptionKind {
     FollowLocationTrusted(BooleanOption),
     LimitRate(NaturalOption),
     MaxRedirect(CountOption),
+    MaxTime(DurationOption),
     NetRc(BooleanOption),
     NetRcFile(Template),
     NetRcOptional(BooleanOption),
@@ -809,7 +879,10 @@ pub enum OptionKind {
     Resolve(Template),
     Retry(CountOption),
     RetryInterval(DurationOption),
+    RetryMaxTime(DurationOption),
     Skip(BooleanOption),
+    SkipUnless(BooleanOption),
+    Unknown(UnknownOption),
     UnixSocket(Template),
     User(Template),
     Variable(VariableDefinition),
@@ -818,12 +891,14 @@ pub enum OptionKind {
 }
 
 impl OptionKind {
-    pub fn name(&self) -> &'static str {
+    pub fn name(&self) -> &str {
         match self {
             OptionKind::AwsSigV4(_) => "aws-sigv4",
             OptionKind::CaCertificate(_) => "cacert",
+            OptionKind::Chunked(_) => "chunked",
             OptionKind::ClientCert(_) => "cert",
             OptionKind::ClientKey(_) => "key",
+            OptionKind::CompressBody(_) => "compress-body",
             OptionKind::Compressed(_) => "compressed",
             OptionKind::ConnectTo(_) => "connect-to",
             OptionKind::ConnectTimeout(_) => "connect-timeout",
@@ -839,6 +914,7 @@ impl OptionKind {
             OptionKind::IpV6(_) => "ipv6",
             OptionKind::LimitRate(_) => "limit-rate",
             OptionKind::MaxRedirect(_) => "max-redirs",
+            OptionKind::MaxTime(_) => "max-time",
             OptionKind::NetRc(_) => "netrc",
             OptionKind::NetRcFile(_) => "netrc-file",
             OptionKind::NetRcOptional(_) => "netrc-optional",
@@ -849,7 +925,10 @@ impl OptionKind {
             OptionKind::Resolve(_) => "resolve",
             OptionKind::Retry(_) => "retry",
             OptionKind::RetryInterval(_) => "retry-interval",
+            OptionKind::RetryMaxTime(_) => "retry-max-time",
             OptionKind::Skip(_) => "skip",
+            OptionKind::SkipUnless(_) => "skip-unless",
+            OptionKind::Unknown(option) => &option.name,
             OptionKind::UnixSocket(_) => "unix-socket",
             OptionKind::User(_) => "user",
             OptionKind::Variable(_) => "variable",
@@ -862,8 +941,10 @@ impl OptionKind {
         match self {
             OptionKind::AwsSigV4(value) => value.to_string(),
             OptionKind::CaCertificate(filename) => filename.to_string(),
+            OptionKind::Chunked(value) => value.to_string(),
             OptionKind::ClientCert(filename) => filename.to_string(),
             OptionKind::ClientKey(filename) => filename.to_string(),
+            OptionKind::CompressBody(value) => value.to_string(),
             OptionKind::Compressed(value) => value.to_string(),
             OptionKind::ConnectTo(value) => value.to_string(),
             OptionKind::ConnectTimeout(value) => value.to_string(),
@@ -879,6 +960,7 @@ impl OptionKind {
             OptionKind::IpV6(value) => value.to_string(),
             OptionKind::LimitRate(value) => value.to_string(),
             OptionKind::MaxRedirect(value) => value.to_string(),
+            OptionKind::MaxTime(value) => value.to_string(),
             OptionKind::NetRc(value) => value.to_string(),
             OptionKind::NetRcFile(filename) => filename.to_string(),
             OptionKind::NetRcOptional(value) => value.to_string(),
@@ -889,7 +971,10 @@ impl OptionKind {
             OptionKind::Resolve(value) => value.to_string(),
             OptionKind::Retry(value) => value.to_string(),
             OptionKind::RetryInterval(value) => value.to_string(),
+            OptionKind::RetryMaxTime(value) => value.to_string(),
             OptionKind::Skip(value) => value.to_string(),
+            OptionKind::SkipUnless(value) => value.to_string(),
+            OptionKind::Unknown(option) => option.value.clone(),
             OptionKind::UnixSocket(value) => value.to_string(),
             OptionKind::User(value) => value.to_string(),
             OptionKind::Variable(VariableDefinition { name, value, .. }) => {
@@ -925,6 +1010,14 @@ pub enum DurationOption {
     Placeholder(Placeholder),
 }
 
+/// An option not supported by this version of Hurl. It's kept in the AST, with its value as a raw
+/// string, so that files using newer options can still be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownOption {
+    pub name: String,
+    pub value: String,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct VariableDefinition {
     pub source_info: SourceInfo,
@@ -950,6 +1043,9 @@ pub struct Filter {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum FilterValue {
+    Base64Decode,
+    Base64Encode,
+    Base64UrlSafeEncode,
     Count,
     DaysAfterNow,
     DaysBeforeNow,
@@ -967,6 +1063,8 @@ pub enum FilterValue {
         space0: Whitespace,
         expr: Template,
     },
+    JwtPayload,
+    Length,
     Nth {
         space0: Whitespace,
         n: U64,
@@ -990,7 +1088,9 @@ pub enum FilterValue {
         fmt: Template,
     },
     ToFloat,
+    ToHex,
     ToInt,
+    ToLower,
     UrlDecode,
     UrlEncode,
     XPath {
@@ -19,8 +19,8 @@ use crate::ast::{
     BooleanOption, CookieAttribute, CookieAttributeName, CookiePath, CountOption, DurationOption,
     Expr, ExprKind, Float, Function, Hex, Method, MultilineString, MultilineStringAttribute,
     MultilineStringKind, NaturalOption, Number, Placeholder, PredicateFuncValue, Regex, Status,
-    StatusValue, Template, TemplateElement, Variable, VariableDefinition, VariableValue, Version,
-    VersionValue,
+    StatusValue, Template, TemplateElement, Variable, VariableDefault, VariableDefinition,
+    VariableValue, Version, VersionValue,
 };
 use core::fmt;
 
@@ -117,11 +117,31 @@ impl fmt::Display for ExprKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             ExprKind::Variable(variable) => write!(f, "{}", variable),
+            ExprKind::VariableDefault(default) => write!(f, "{}", default),
             ExprKind::Function(function) => write!(f, "{}", function),
         }
     }
 }
 
+impl fmt::Display for VariableDefault {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = self
+            .value
+            .elements
+            .iter()
+            .map(|e| match e {
+                TemplateElement::String { encoded, .. } => encoded.clone(),
+                TemplateElement::Placeholder(value) => format!("{{{{{value}}}}}"),
+            })
+            .collect::<String>();
+        write!(
+            f,
+            "{}{}|{}default{}\"{}\"",
+            self.variable, self.space0.value, self.space1.value, self.space2.value, value
+        )
+    }
+}
+
 impl fmt::Display for Variable {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.name)
@@ -185,7 +205,9 @@ impl fmt::Display for MultilineString {
         let body = match &self.kind {
             MultilineStringKind::Text(text)
             | MultilineStringKind::Json(text)
-            | MultilineStringKind::Xml(text) => text.value.to_string(),
+            | MultilineStringKind::Xml(text)
+            | MultilineStringKind::Html(text)
+            | MultilineStringKind::Other(_, text) => text.value.to_string(),
             MultilineStringKind::GraphQl(graphql) => {
                 let var = match &graphql.variables {
                     None => String::new(),
@@ -208,6 +230,7 @@ impl fmt::Display for MultilineStringAttribute {
         match self {
             MultilineStringAttribute::Escape => write!(f, "escape"),
             MultilineStringAttribute::NoVariable => write!(f, "novariable"),
+            MultilineStringAttribute::Lenient => write!(f, "lenient"),
         }
     }
 }
@@ -280,6 +303,7 @@ impl PredicateFuncValue {
             PredicateFuncValue::Contain { .. } => "contains",
             PredicateFuncValue::Include { .. } => "includes",
             PredicateFuncValue::Match { .. } => "matches",
+            PredicateFuncValue::MatchSchema { .. } => "matchesSchema",
             PredicateFuncValue::IsInteger => "isInteger",
             PredicateFuncValue::IsFloat => "isFloat",
             PredicateFuncValue::IsBoolean => "isBoolean",
@@ -138,7 +138,8 @@ pub fn add_carets(message: &str, source_info: SourceInfo, content: &[&str]) -> S
     } else {
         1
     };
-    let line_raw = content.get(error_line - 1).unwrap();
+    // An error can be located at the end of the content, after the last line.
+    let line_raw = content.get(error_line - 1).unwrap_or(&"");
     let prefix = get_carets(line_raw, error_column, width);
 
     let mut s = String::new();
@@ -225,7 +226,7 @@ fn get_carets(line_raw: &str, error_column: usize, width: usize) -> String {
 }
 
 pub fn add_source_line(text: &mut StyledString, content: &[&str], line: usize) {
-    let line = content.get(line - 1).unwrap();
+    let line = content.get(line - 1).unwrap_or(&"");
     let line = line.replace('\t', "    ");
     text.push(" ");
     text.push(&line);
@@ -304,6 +305,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_carets_end_of_content() {
+        // An error can be located after the last line, when the content ends with a newline.
+        assert_eq!(
+            add_carets(
+                "expecting ']'",
+                SourceInfo::new(Pos::new(3, 1), Pos::new(3, 1)),
+                &["POST http://localhost", "[1, 2"]
+            ),
+            " ^ expecting ']'".to_string()
+        );
+    }
+
     #[test]
     fn test_get_carets() {
         // `Hello World`
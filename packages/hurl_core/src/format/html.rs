@@ -20,9 +20,9 @@ use crate::ast::{
     CookieAttribute, CookiePath, CountOption, DurationOption, Entry, EntryOption, File, FileParam,
     FileValue, Filter, FilterValue, GraphQl, GraphQlVariables, Hex, HurlFile, JsonValue, KeyValue,
     LineTerminator, Method, MultilineString, MultilineStringKind, MultipartParam, NaturalOption,
-    OptionKind, Placeholder, Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, Query,
-    QueryValue, Regex, RegexValue, Request, Response, Section, SectionValue, Status, Template,
-    TemplateElement, VariableDefinition, VariableValue, Version, Whitespace,
+    NdJson, OptionKind, Placeholder, Predicate, PredicateFunc, PredicateFuncValue, PredicateValue,
+    Query, QueryValue, Regex, RegexValue, Request, Response, Section, SectionValue, Status,
+    Template, TemplateElement, VariableDefinition, VariableValue, Version, Whitespace,
 };
 use crate::typing::Count;
 use std::fmt::Display;
@@ -224,8 +224,10 @@ impl HtmlFormatter {
         match &option.kind {
             OptionKind::AwsSigV4(value) => self.fmt_template(value),
             OptionKind::CaCertificate(filename) => self.fmt_filename(filename),
+            OptionKind::Chunked(value) => self.fmt_bool_option(value),
             OptionKind::ClientCert(filename) => self.fmt_filename(filename),
             OptionKind::ClientKey(filename) => self.fmt_filename(filename),
+            OptionKind::CompressBody(value) => self.fmt_template(value),
             OptionKind::Compressed(value) => self.fmt_bool_option(value),
             OptionKind::ConnectTo(value) => self.fmt_template(value),
             OptionKind::ConnectTimeout(value) => self.fmt_duration_option(value),
@@ -241,6 +243,7 @@ impl HtmlFormatter {
             OptionKind::IpV6(value) => self.fmt_bool_option(value),
             OptionKind::LimitRate(value) => self.fmt_natural_option(value),
             OptionKind::MaxRedirect(value) => self.fmt_count_option(value),
+            OptionKind::MaxTime(value) => self.fmt_duration_option(value),
             OptionKind::NetRc(value) => self.fmt_bool_option(value),
             OptionKind::NetRcFile(filename) => self.fmt_filename(filename),
             OptionKind::NetRcOptional(value) => self.fmt_bool_option(value),
@@ -251,7 +254,10 @@ impl HtmlFormatter {
             OptionKind::Resolve(value) => self.fmt_template(value),
             OptionKind::Retry(value) => self.fmt_count_option(value),
             OptionKind::RetryInterval(value) => self.fmt_duration_option(value),
+            OptionKind::RetryMaxTime(value) => self.fmt_duration_option(value),
             OptionKind::Skip(value) => self.fmt_bool_option(value),
+            OptionKind::SkipUnless(value) => self.fmt_bool_option(value),
+            OptionKind::Unknown(option) => self.fmt_string(&escape_xml(&option.value)),
             OptionKind::UnixSocket(value) => self.fmt_template(value),
             OptionKind::User(value) => self.fmt_template(value),
             OptionKind::Variable(value) => self.fmt_variable_definition(value),
@@ -323,6 +329,15 @@ impl HtmlFormatter {
         if let Some(content_type) = &file_value.content_type {
             self.fmt_string(content_type);
         }
+        if let Some(part_filename) = &file_value.part_filename {
+            if file_value.content_type.is_some() {
+                self.fmt_space(&part_filename.space0);
+                self.buffer.push(';');
+                self.fmt_space(&part_filename.space1);
+            }
+            self.buffer.push_str("filename=");
+            self.fmt_filename(&part_filename.value);
+        }
     }
 
     fn fmt_filename(&mut self, filename: &Template) {
@@ -370,6 +385,8 @@ impl HtmlFormatter {
         match query_value {
             QueryValue::Status => self.fmt_span("query-type", "status"),
             QueryValue::Url => self.fmt_span("query-type", "url"),
+            QueryValue::Redirects => self.fmt_span("query-type", "redirects"),
+            QueryValue::Location => self.fmt_span("query-type", "location"),
             QueryValue::Header { space0, name } => {
                 self.fmt_span("query-type", "header");
                 self.fmt_space(space0);
@@ -401,6 +418,7 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_template(name);
             }
+            QueryValue::Version => self.fmt_span("query-type", "version"),
             QueryValue::Duration => self.fmt_span("query-type", "duration"),
             QueryValue::Bytes => self.fmt_span("query-type", "bytes"),
             QueryValue::Sha256 => self.fmt_span("query-type", "sha256"),
@@ -535,6 +553,10 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_predicate_value(value);
             }
+            PredicateFuncValue::MatchSchema { space0, value } => {
+                self.fmt_space(space0);
+                self.fmt_predicate_value(value);
+            }
             PredicateFuncValue::IsInteger => {}
             PredicateFuncValue::IsFloat => {}
             PredicateFuncValue::IsBoolean => {}
@@ -677,8 +699,9 @@ impl HtmlFormatter {
                 self.fmt_span_close();
             }
             Bytes::Json(value) => self.fmt_json_value(value),
+            Bytes::NdJson(value) => self.fmt_ndjson(value),
             Bytes::MultilineString(value) => self.fmt_multiline_string(value, true),
-            Bytes::Xml(value) => self.fmt_xml(value),
+            Bytes::Xml(value) => self.fmt_xml(&value.to_encoded_string()),
         }
     }
 
@@ -730,6 +753,16 @@ impl HtmlFormatter {
         self.fmt_span("json", &json);
     }
 
+    fn fmt_ndjson(&mut self, ndjson: &NdJson) {
+        let s = ndjson
+            .values
+            .iter()
+            .map(|v| format!("{}{}", v.space0, v.value.encoded()))
+            .collect::<String>();
+        let json = format_multilines(&s);
+        self.fmt_span("json", &json);
+    }
+
     fn fmt_space(&mut self, space: &Whitespace) {
         let Whitespace { value, .. } = space;
         if !value.is_empty() {
@@ -759,7 +792,8 @@ impl HtmlFormatter {
     }
 
     fn fmt_base64(&mut self, base64: &Base64) {
-        self.buffer.push_str("base64,");
+        self.buffer.push_str(base64.keyword());
+        self.buffer.push(',');
         self.fmt_space(&base64.space0);
         self.fmt_span("base64", &base64.encoded);
         self.fmt_space(&base64.space1);
@@ -796,6 +830,11 @@ impl HtmlFormatter {
 
     fn fmt_filter_value(&mut self, filter_value: &FilterValue) {
         match filter_value {
+            FilterValue::Base64Decode => self.fmt_span("filter-type", "base64Decode"),
+            FilterValue::Base64Encode => self.fmt_span("filter-type", "base64Encode"),
+            FilterValue::Base64UrlSafeEncode => {
+                self.fmt_span("filter-type", "base64UrlSafeEncode");
+            }
             FilterValue::Count => self.fmt_span("filter-type", "count"),
             FilterValue::DaysAfterNow => self.fmt_span("filter-type", "daysAfterNow"),
             FilterValue::DaysBeforeNow => self.fmt_span("filter-type", "daysBeforeNow"),
@@ -816,6 +855,8 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_template(expr);
             }
+            FilterValue::JwtPayload => self.fmt_span("filter-type", "jwtPayload"),
+            FilterValue::Length => self.fmt_span("filter-type", "length"),
             FilterValue::Nth { space0, n: value } => {
                 self.fmt_span("filter-type", "nth");
                 self.fmt_space(space0);
@@ -849,7 +890,9 @@ impl HtmlFormatter {
                 self.fmt_template(fmt);
             }
             FilterValue::ToFloat => self.fmt_span("filter-type", "toFloat"),
+            FilterValue::ToHex => self.fmt_span("filter-type", "toHex"),
             FilterValue::ToInt => self.fmt_span("filter-type", "toInt"),
+            FilterValue::ToLower => self.fmt_span("filter-type", "toLower"),
             FilterValue::UrlDecode => self.fmt_span("filter-type", "urlDecode"),
             FilterValue::UrlEncode => self.fmt_span("filter-type", "urlEncode"),
             FilterValue::XPath { space0, expr } => {
@@ -887,7 +930,11 @@ impl Template {
         for element in self.elements.iter() {
             let elem_str = match element {
                 TemplateElement::String { encoded, .. } => encoded.to_string(),
-                TemplateElement::Placeholder(expr) => format!("{{{{{expr}}}}}"),
+                TemplateElement::Placeholder(Placeholder {
+                    space0,
+                    expr,
+                    space1,
+                }) => format!("{{{{{}{expr}{}}}}}", space0.value, space1.value),
             };
             s.push_str(elem_str.as_str());
         }
@@ -903,7 +950,9 @@ impl MultilineStringKind {
         match self {
             MultilineStringKind::Text(text)
             | MultilineStringKind::Json(text)
-            | MultilineStringKind::Xml(text) => text.value.to_encoded_string(),
+            | MultilineStringKind::Xml(text)
+            | MultilineStringKind::Html(text)
+            | MultilineStringKind::Other(_, text) => text.value.to_encoded_string(),
             MultilineStringKind::GraphQl(graphql) => graphql.to_encoded_string(),
         }
     }
@@ -1097,6 +1146,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_xml_templated() {
+        let content = "POST http://localhost:8000/xml\n<a id=\"{{ id }}\">&amp;</a>\n";
+        let hurl_file = crate::parser::parse_hurl_file(content).unwrap();
+        let html = format(&hurl_file, false);
+        assert!(html.contains(
+            "<span class=\"xml\"><span class=\"line\">&lt;a id=\"{{ id }}\"&gt;&amp;amp;&lt;/a&gt;</span></span>"
+        ));
+    }
+
     #[test]
     fn test_xml_escape() {
         assert_eq!(escape_xml("hello"), "hello");
@@ -23,6 +23,18 @@ use crate::reader::Reader;
 /// Encoded
 /// YW55IGNhcm5hbCBwbGVhcw==    any carnal pleas   # [97, 110, 121, 32, 99, 97, 114, 110, 97, 108, 32, 112, 108, 101, 97, 115]
 pub fn parse(reader: &mut Reader) -> Vec<u8> {
+    parse_with(reader, value)
+}
+
+/// Decodes a base64 text using the URL and filename safe alphabet (`-` and `_` instead
+/// of `+` and `/`), see <https://datatracker.ietf.org/doc/html/rfc4648#section-5>.
+///
+/// As with the standard alphabet, padding is optional.
+pub fn parse_url_safe(reader: &mut Reader) -> Vec<u8> {
+    parse_with(reader, url_safe_value)
+}
+
+fn parse_with(reader: &mut Reader, value: fn(char) -> Option<i32>) -> Vec<u8> {
     let mut bytes = vec![];
     let mut buf = vec![]; // base64 text
     loop {
@@ -137,6 +149,15 @@ fn value(c: char) -> Option<i32> {
     }
 }
 
+fn url_safe_value(c: char) -> Option<i32> {
+    match c {
+        '-' => Some(62),
+        '_' => Some(63),
+        '+' | '/' => None,
+        _ => value(c),
+    }
+}
+
 fn padding(reader: &mut Reader) -> String {
     // consume padding can not fail
     let mut buf = String::new();
@@ -241,6 +262,21 @@ mod tests {
         assert_eq!(parse(&mut reader), b"any carnal pleasu");
     }
 
+    #[test]
+    fn test_decode_url_safe() {
+        let mut reader = Reader::new("-_-_8A;");
+        assert_eq!(parse_url_safe(&mut reader), vec![251, 255, 191, 240]);
+        assert_eq!(reader.cursor().index, 6);
+
+        let mut reader = Reader::new("+/+/8A==;");
+        assert_eq!(parse(&mut reader), vec![251, 255, 191, 240]);
+
+        // Standard alphabet specific chars are not valid in URL-safe base64.
+        let mut reader = Reader::new("TWE+;");
+        assert_eq!(parse_url_safe(&mut reader), vec![77, 97]);
+        assert_eq!(reader.cursor().index, 3);
+    }
+
     #[test]
     fn test_decode_with_whitespace() {
         let mut reader = Reader::new("TW E=\n;");
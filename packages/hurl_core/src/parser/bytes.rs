@@ -15,11 +15,12 @@
  * limitations under the License.
  *
  */
-use crate::ast::Bytes;
+use crate::ast::{Bytes, NdJson, NdJsonValue, SourceInfo, Template};
 use crate::combinator::choice;
 use crate::parser::json::parse as parse_json;
 use crate::parser::multiline::multiline_string;
 use crate::parser::string::backtick_template;
+use crate::parser::template::{templatize, EncodedString};
 use crate::parser::{primitives, xml, ParseResult};
 use crate::reader::Reader;
 
@@ -38,30 +39,76 @@ pub fn bytes(reader: &mut Reader) -> ParseResult<Bytes> {
     )
 }
 
+/// Parses a XML body.
+///
+/// Once the end of the XML body has been detected, the body is read again to locate placeholders:
+/// the XML markup (including entities like `&amp;`) is kept untouched.
 fn xml_bytes(reader: &mut Reader) -> ParseResult<Bytes> {
-    match xml::parse(reader) {
-        Err(e) => Err(e),
-        Ok(value) => Ok(Bytes::Xml(value)),
+    let start = reader.cursor();
+    let value = xml::parse(reader)?;
+    let end = reader.cursor();
+
+    reader.seek(start);
+    let mut chars = vec![];
+    for c in value.chars() {
+        let pos = reader.cursor().pos;
+        reader.read();
+        chars.push((c, c.to_string(), pos));
     }
+    let source_info = SourceInfo::new(start.pos, end.pos);
+    let encoded_string = EncodedString { source_info, chars };
+    let elements = templatize(encoded_string)?;
+    let template = Template {
+        delimiter: None,
+        elements,
+        source_info,
+    };
+    Ok(Bytes::Xml(template))
 }
 
+/// Parses a JSON body, or a newline-delimited JSON body if other values follow the first one.
 fn json_bytes(reader: &mut Reader) -> ParseResult<Bytes> {
-    match parse_json(reader) {
-        Err(e) => Err(e),
-        Ok(value) => Ok(Bytes::Json(value)),
+    let value = parse_json(reader)?;
+    let next_values = ndjson_next_values(reader)?;
+    if next_values.is_empty() {
+        return Ok(Bytes::Json(value));
+    }
+    let mut values = vec![NdJsonValue {
+        space0: String::new(),
+        value,
+    }];
+    values.extend(next_values);
+    Ok(Bytes::NdJson(NdJson { values }))
+}
+
+/// Parses the values following the first one of a newline-delimited JSON body.
+///
+/// Subsequent values must start on a new line with an object or a list (empty lines are allowed).
+fn ndjson_next_values(reader: &mut Reader) -> ParseResult<Vec<NdJsonValue>> {
+    let mut values = vec![];
+    loop {
+        let save = reader.cursor();
+        let space0 = reader.read_while(|c| c == ' ' || c == '\t' || c == '\n' || c == '\r');
+        if !space0.contains('\n') || !matches!(reader.peek(), Some('{') | Some('[')) {
+            reader.seek(save);
+            break;
+        }
+        let value = parse_json(reader)?;
+        values.push(NdJsonValue { space0, value });
     }
+    Ok(values)
 }
 
 fn file_bytes(reader: &mut Reader) -> ParseResult<Bytes> {
-    primitives::file(reader).map(Bytes::File)
+    primitives::file_body(reader).map(Bytes::File)
 }
 
 fn base64_bytes(reader: &mut Reader) -> ParseResult<Bytes> {
-    primitives::base64(reader).map(Bytes::Base64)
+    primitives::base64_body(reader).map(Bytes::Base64)
 }
 
 fn hex_bytes(reader: &mut Reader) -> ParseResult<Bytes> {
-    primitives::hex(reader).map(Bytes::Hex)
+    primitives::hex_body(reader).map(Bytes::Hex)
 }
 
 pub fn multiline_string_bytes(reader: &mut Reader) -> ParseResult<Bytes> {
@@ -76,7 +123,7 @@ fn string_bytes(reader: &mut Reader) -> ParseResult<Bytes> {
 mod tests {
     use super::super::error::*;
     use super::*;
-    use crate::ast::{JsonListElement, JsonValue, SourceInfo, Template, TemplateElement};
+    use crate::ast::{JsonListElement, JsonValue, TemplateElement};
     use crate::reader::Pos;
 
     #[test]
@@ -136,15 +183,137 @@ mod tests {
         assert_eq!(reader.cursor().index, 2);
     }
 
+    #[test]
+    fn test_bytes_ndjson() {
+        let mut reader = Reader::new("{}\n\n  [1]\n{ }\nHTTP 200");
+        assert_eq!(
+            bytes(&mut reader).unwrap(),
+            Bytes::NdJson(NdJson {
+                values: vec![
+                    NdJsonValue {
+                        space0: String::new(),
+                        value: JsonValue::Object {
+                            space0: String::new(),
+                            elements: vec![],
+                        },
+                    },
+                    NdJsonValue {
+                        space0: "\n\n  ".to_string(),
+                        value: JsonValue::List {
+                            space0: String::new(),
+                            elements: vec![JsonListElement {
+                                space0: String::new(),
+                                value: JsonValue::Number("1".to_string()),
+                                space1: String::new(),
+                            }],
+                        },
+                    },
+                    NdJsonValue {
+                        space0: "\n".to_string(),
+                        value: JsonValue::Object {
+                            space0: " ".to_string(),
+                            elements: vec![],
+                        },
+                    },
+                ],
+            })
+        );
+        assert_eq!(reader.cursor().index, 13);
+
+        // A single JSON value followed by newlines is still a JSON body.
+        let mut reader = Reader::new("{}\n\nHTTP 200");
+        assert_eq!(
+            bytes(&mut reader).unwrap(),
+            Bytes::Json(JsonValue::Object {
+                space0: String::new(),
+                elements: vec![],
+            })
+        );
+        assert_eq!(reader.cursor().index, 2);
+    }
+
+    #[test]
+    fn test_bytes_ndjson_error() {
+        let mut reader = Reader::new("{}\n{ x }");
+        let error = bytes(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 2, column: 3 });
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "\"".to_string()
+            },
+        );
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_bytes_xml() {
         let mut reader = Reader::new("<a/>");
         assert_eq!(
             bytes(&mut reader).unwrap(),
-            Bytes::Xml(String::from("<a/>"))
+            Bytes::Xml(Template {
+                delimiter: None,
+                elements: vec![TemplateElement::String {
+                    value: "<a/>".to_string(),
+                    encoded: "<a/>".to_string(),
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 5)),
+            })
         );
     }
 
+    #[test]
+    fn test_bytes_xml_templated() {
+        let mut reader = Reader::new("<a id=\"{{id}}\">&amp;</a>");
+        let Bytes::Xml(template) = bytes(&mut reader).unwrap() else {
+            panic!("Expecting XML bytes");
+        };
+        assert_eq!(template.elements.len(), 3);
+        assert_eq!(
+            template.elements[0],
+            TemplateElement::String {
+                value: "<a id=\"".to_string(),
+                encoded: "<a id=\"".to_string(),
+            }
+        );
+        let TemplateElement::Placeholder(placeholder) = &template.elements[1] else {
+            panic!("Expecting a placeholder");
+        };
+        assert_eq!(
+            placeholder.expr.source_info,
+            SourceInfo::new(Pos::new(1, 10), Pos::new(1, 12))
+        );
+        assert_eq!(
+            template.elements[2],
+            TemplateElement::String {
+                value: "\">&amp;</a>".to_string(),
+                encoded: "\">&amp;</a>".to_string(),
+            }
+        );
+        assert_eq!(template.to_string(), "<a id=\"{{id}}\">&amp;</a>");
+    }
+
+    #[test]
+    fn test_bytes_xml_escaped_braces() {
+        // A literal `{{` is written with a character reference, or split in two CDATA sections.
+        let xml = "<a>&#123;{ <![CDATA[{]]><![CDATA[{]]></a>";
+        let mut reader = Reader::new(xml);
+        assert_eq!(
+            bytes(&mut reader).unwrap(),
+            Bytes::Xml(Template {
+                delimiter: None,
+                elements: vec![TemplateElement::String {
+                    value: xml.to_string(),
+                    encoded: xml.to_string(),
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 42)),
+            })
+        );
+
+        let mut reader = Reader::new("<a>{{</a>");
+        assert!(bytes(&mut reader).is_err());
+    }
+
     #[test]
     fn test_bytes_json_error() {
         let mut reader = Reader::new("{ x ");
@@ -158,6 +327,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bytes_committed_branch_error() {
+        // Once a branch has read a distinguishing prefix, its error is returned and the next
+        // branches are not tried.
+        let data = [
+            (
+                "base64,SGVs!bG8=;",
+                Pos::new(1, 12),
+                ParseErrorKind::Expecting {
+                    value: ";".to_string(),
+                },
+            ),
+            (
+                "base64 SGVsbG8=;",
+                Pos::new(1, 7),
+                ParseErrorKind::Expecting {
+                    value: ",".to_string(),
+                },
+            ),
+            ("hex,zz;", Pos::new(1, 5), ParseErrorKind::HexDigit),
+            ("file,;", Pos::new(1, 6), ParseErrorKind::Filename),
+            (
+                "[1, 2",
+                Pos::new(1, 6),
+                ParseErrorKind::Expecting {
+                    value: "]".to_string(),
+                },
+            ),
+            ("<a>hello</b>", Pos::new(1, 12), ParseErrorKind::Xml),
+        ];
+        for (text, pos, kind) in data {
+            let mut reader = Reader::new(text);
+            let error = bytes(&mut reader).err().unwrap();
+            assert_eq!(error.pos, pos, "{text}");
+            assert_eq!(error.kind, kind, "{text}");
+            assert!(!error.recoverable, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_bytes_without_semicolon_at_eof() {
+        let mut reader = Reader::new("file,data.bin\n");
+        let Bytes::File(file) = bytes(&mut reader).unwrap() else {
+            panic!("expecting a file body");
+        };
+        assert_eq!(file.filename.to_string(), "data.bin");
+        assert_eq!(reader.cursor().index, 13);
+
+        let mut reader = Reader::new("base64,SGVsbG8=  \n\n");
+        let Bytes::Base64(base64) = bytes(&mut reader).unwrap() else {
+            panic!("expecting a base64 body");
+        };
+        assert_eq!(base64.value, b"Hello");
+        assert_eq!(reader.cursor().index, 17);
+
+        let mut reader = Reader::new("hex,0102");
+        let Bytes::Hex(hex) = bytes(&mut reader).unwrap() else {
+            panic!("expecting an hex body");
+        };
+        assert_eq!(hex.value, vec![1, 2]);
+        assert!(reader.is_eof());
+
+        // Trailing comments are allowed after the body.
+        let mut reader = Reader::new("hex,0102 # end of body\n# last line");
+        let Bytes::Hex(hex) = bytes(&mut reader).unwrap() else {
+            panic!("expecting an hex body");
+        };
+        assert_eq!(hex.value, vec![1, 2]);
+        assert_eq!(reader.cursor().index, 9);
+
+        let mut reader = Reader::new("base64,SGVsbG8=\n\n# last line\n");
+        let Bytes::Base64(base64) = bytes(&mut reader).unwrap() else {
+            panic!("expecting a base64 body");
+        };
+        assert_eq!(base64.value, b"Hello");
+        assert_eq!(reader.cursor().index, 15);
+    }
+
+    #[test]
+    fn test_bytes_without_semicolon_error() {
+        // The semicolon is still required when the body is followed by other content.
+        let data = [
+            ("file,data.bin\nHTTP 200\n", Pos::new(1, 14)),
+            ("base64,SGVsbG8=\nHTTP 200\n", Pos::new(1, 16)),
+            ("hex,0102\nHTTP 200\n", Pos::new(1, 9)),
+            ("hex,0102\n# comment\nHTTP 200\n", Pos::new(1, 9)),
+        ];
+        for (text, pos) in data {
+            let mut reader = Reader::new(text);
+            let error = bytes(&mut reader).err().unwrap();
+            assert_eq!(error.pos, pos, "{text}");
+            assert_eq!(
+                error.kind,
+                ParseErrorKind::Expecting {
+                    value: ";".to_string()
+                },
+                "{text}"
+            );
+            assert!(!error.recoverable, "{text}");
+        }
+    }
+
     #[test]
     fn test_bytes_multilines_error() {
         let mut reader = Reader::new("```\nxxx ");
@@ -171,6 +442,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bytes_graphql_multilines_error() {
+        let mut reader = Reader::new("```graphql\n{ hero { name } }\n");
+        let error = bytes(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 3, column: 1 });
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "```".to_string()
+            }
+        );
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_bytes_eof() {
         let mut reader = Reader::new("");
@@ -209,4 +494,43 @@ mod tests {
         );
         assert_eq!(reader.cursor().index, 5);
     }
+
+    #[test]
+    fn test_bytes_hash_is_not_a_comment() {
+        let mut reader = Reader::new("`#foo` # comment");
+        assert_eq!(
+            bytes(&mut reader).unwrap(),
+            Bytes::OnelineString(Template {
+                delimiter: Some('`'),
+                elements: vec![TemplateElement::String {
+                    value: "#foo".to_string(),
+                    encoded: "#foo".to_string()
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 7))
+            })
+        );
+        assert_eq!(reader.cursor().index, 6);
+
+        let mut reader = Reader::new("{\"key\": \"#foo\"}");
+        let Bytes::Json(JsonValue::Object { elements, .. }) = bytes(&mut reader).unwrap() else {
+            panic!("expected a JSON object");
+        };
+        assert_eq!(
+            elements[0].value,
+            JsonValue::String(Template {
+                delimiter: Some('"'),
+                elements: vec![TemplateElement::String {
+                    value: "#foo".to_string(),
+                    encoded: "#foo".to_string()
+                }],
+                source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 14))
+            })
+        );
+
+        let mut reader = Reader::new("```\n# foo\n```");
+        let Bytes::MultilineString(multiline) = bytes(&mut reader).unwrap() else {
+            panic!("expected a multiline string");
+        };
+        assert_eq!(multiline.to_string(), "# foo\n");
+    }
 }
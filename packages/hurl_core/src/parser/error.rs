@@ -20,6 +20,7 @@ use std::cmp;
 use crate::ast::SourceInfo;
 use crate::error;
 use crate::error::DisplaySourceError;
+use crate::parser::option::option_names;
 use crate::reader::Pos;
 use crate::text::{Style, StyledString};
 
@@ -155,37 +156,9 @@ impl DisplaySourceError for ParseError {
                 format!("the duration unit is not valid. {did_you_mean}")
             }
             ParseErrorKind::InvalidOption(name) => {
-                let valid_values = [
-                    "aws-sigv4",
-                    "cacert",
-                    "cert",
-                    "compressed",
-                    "connect-to",
-                    "delay",
-                    "insecure",
-                    "http1.0",
-                    "http1.1",
-                    "http2",
-                    "http3",
-                    "ipv4",
-                    "ipv6",
-                    "key",
-                    "location",
-                    "max-redirs",
-                    "output",
-                    "path-as-is",
-                    "proxy",
-                    "resolve",
-                    "retry",
-                    "retry-interval",
-                    "skip",
-                    "unix-socket",
-                    "variable",
-                    "verbose",
-                    "very-verbose",
-                ];
-                let default = format!("Valid values are {}", valid_values.join(", "));
-                let did_you_mean = did_you_mean(&valid_values, name.as_str(), &default);
+                let option_names = option_names();
+                let default = format!("Valid values are {}", option_names.join(", "));
+                let did_you_mean = did_you_mean(&option_names, name.as_str(), &default);
                 format!("the option name is not valid. {did_you_mean}")
             }
             ParseErrorKind::Json(variant) => match variant {
@@ -287,7 +260,7 @@ fn did_you_mean(valid_values: &[&str], actual: &str, default: &str) -> String {
     }
 }
 
-fn suggestion(valid_values: &[&str], actual: &str) -> Option<String> {
+pub(crate) fn suggestion(valid_values: &[&str], actual: &str) -> Option<String> {
     for value in valid_values {
         if levenshtein_distance(
             value.to_lowercase().as_str(),
@@ -377,4 +350,40 @@ mod tests {
    |"#
         );
     }
+
+    #[test]
+    fn test_invalid_option_error() {
+        let content = "GET http://localhost\n[Options]\nmax_time: 10";
+        let filename = "test.hurl";
+        let error = ParseError {
+            pos: Pos::new(3, 1),
+            recoverable: false,
+            kind: ParseErrorKind::InvalidOption("max_time".to_string()),
+        };
+        assert_eq!(
+            error.to_string(filename, content, None, OutputFormat::Terminal(false)),
+            r#"Parsing option
+  --> test.hurl:3:1
+   |
+ 3 | max_time: 10
+   | ^ the option name is not valid. Did you mean max-time?
+   |"#
+        );
+    }
+
+    #[test]
+    fn test_multilines_error() {
+        let content = "POST http://localhost:8000/data\n```\nline1\nline2";
+        let filename = "test.hurl";
+        let error = crate::parser::parse_hurl_file(content).err().unwrap();
+        assert_eq!(
+            error.to_string(filename, content, None, OutputFormat::Terminal(false)),
+            r#"Parsing literal
+  --> test.hurl:4:6
+   |
+ 4 | line2
+   |      ^ expecting '```'
+   |"#
+        );
+    }
 }
@@ -15,7 +15,10 @@
  * limitations under the License.
  *
  */
-use crate::ast::{Expr, ExprKind, SourceInfo, Variable};
+use crate::ast::{Expr, ExprKind, SourceInfo, Variable, VariableDefault};
+use crate::combinator::ParseError as ParseErrorTrait;
+use crate::parser::primitives::{literal, one_or_more_spaces, try_literal, zero_or_more_spaces};
+use crate::parser::string::quoted_template;
 use crate::parser::ParseResult;
 use crate::parser::{function, ParseError, ParseErrorKind};
 use crate::reader::Reader;
@@ -32,7 +35,8 @@ pub fn parse(reader: &mut Reader) -> ParseResult<Expr> {
             if e.recoverable {
                 reader.seek(save_state);
                 let variable = variable_name(reader)?;
-                ExprKind::Variable(variable)
+                let variable = env_variable(variable, reader)?;
+                variable_default(variable, reader)?
             } else {
                 return Err(e);
             }
@@ -59,6 +63,42 @@ fn variable_name(reader: &mut Reader) -> ParseResult<Variable> {
     })
 }
 
+/// Parses an environment variable name following an `env` `variable`: `env.HOME`.
+///
+/// The returned variable is named `env.HOME` and spans the whole `env.HOME` expression.
+fn env_variable(variable: Variable, reader: &mut Reader) -> ParseResult<Variable> {
+    if variable.name != "env" || reader.peek() != Some('.') {
+        return Ok(variable);
+    }
+    reader.read();
+    let env_name = variable_name(reader)?;
+    Ok(Variable {
+        name: format!("env.{}", env_name.name),
+        source_info: SourceInfo::new(variable.source_info.start, env_name.source_info.end),
+    })
+}
+
+/// Parses an optional default value following a `variable`: `name | default "value"`.
+fn variable_default(variable: Variable, reader: &mut Reader) -> ParseResult<ExprKind> {
+    let save_state = reader.cursor();
+    let space0 = zero_or_more_spaces(reader)?;
+    if try_literal("|", reader).is_err() {
+        reader.seek(save_state);
+        return Ok(ExprKind::Variable(variable));
+    }
+    let space1 = zero_or_more_spaces(reader)?;
+    literal("default", reader)?;
+    let space2 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let value = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(ExprKind::VariableDefault(Box::new(VariableDefault {
+        variable,
+        space0,
+        space1,
+        space2,
+        value,
+    })))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,4 +124,63 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_env_variable() {
+        let mut reader = Reader::new("env.HOME}}");
+        let expr = parse(&mut reader).unwrap();
+        assert_eq!(
+            expr.kind,
+            ExprKind::Variable(Variable {
+                name: String::from("env.HOME"),
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+            })
+        );
+        assert_eq!(reader.cursor().index, 8);
+
+        let mut reader = Reader::new("env.}}");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 5));
+        assert_eq!(error.kind, ParseErrorKind::TemplateVariable);
+    }
+
+    #[test]
+    fn test_variable_default() {
+        let mut reader = Reader::new("name | default \"Bob\"}}");
+        let expr = parse(&mut reader).unwrap();
+        assert_eq!(
+            expr.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 21))
+        );
+        let ExprKind::VariableDefault(default) = expr.kind else {
+            panic!("expecting a variable with default value");
+        };
+        assert_eq!(default.variable.name, "name");
+        assert_eq!(default.value.to_string(), "Bob");
+        assert_eq!(reader.cursor().index, 20);
+
+        let mut reader = Reader::new("name }}");
+        let expr = parse(&mut reader).unwrap();
+        assert!(matches!(expr.kind, ExprKind::Variable(_)));
+        assert_eq!(reader.cursor().index, 4);
+    }
+
+    #[test]
+    fn test_variable_default_error() {
+        let mut reader = Reader::new("name | defaul \"Bob\"");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 8));
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "default".to_string()
+            }
+        );
+        assert!(!error.recoverable);
+
+        let mut reader = Reader::new("name | default Bob");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 16));
+        assert!(!error.recoverable);
+    }
 }
@@ -53,6 +53,9 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
     let start = reader.cursor();
     let value = choice(
         &[
+            base64_decode_filter,
+            base64_encode_filter,
+            base64_url_safe_encode_filter,
             count_filter,
             days_after_now_filter,
             days_before_now_filter,
@@ -61,12 +64,16 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
             html_decode_filter,
             html_encode_filter,
             jsonpath_filter,
+            jwt_payload_filter,
+            length_filter,
             nth_filter,
             regex_filter,
             replace_filter,
             split_filter,
             to_float_filter,
+            to_hex_filter,
             to_int_filter,
+            to_lower_filter,
             to_date_filter,
             url_decode_filter,
             url_encode_filter,
@@ -138,6 +145,16 @@ fn jsonpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::JsonPath { space0, expr })
 }
 
+fn jwt_payload_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jwtPayload", reader)?;
+    Ok(FilterValue::JwtPayload)
+}
+
+fn length_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("length", reader)?;
+    Ok(FilterValue::Length)
+}
+
 fn nth_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("nth", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -190,11 +207,36 @@ fn to_int_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::ToInt)
 }
 
+fn to_hex_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("toHex", reader)?;
+    Ok(FilterValue::ToHex)
+}
+
+fn to_lower_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("toLower", reader)?;
+    Ok(FilterValue::ToLower)
+}
+
 fn url_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("urlEncode", reader)?;
     Ok(FilterValue::UrlEncode)
 }
 
+fn base64_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("base64Decode", reader)?;
+    Ok(FilterValue::Base64Decode)
+}
+
+fn base64_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("base64Encode", reader)?;
+    Ok(FilterValue::Base64Encode)
+}
+
+fn base64_url_safe_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("base64UrlSafeEncode", reader)?;
+    Ok(FilterValue::Base64UrlSafeEncode)
+}
+
 fn url_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("urlDecode", reader)?;
     Ok(FilterValue::UrlDecode)
@@ -225,6 +267,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_length() {
+        let mut reader = Reader::new("length");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 7)),
+                value: FilterValue::Length,
+            }
+        );
+    }
+
+    #[test]
+    fn test_jwt_payload() {
+        let mut reader = Reader::new("jwtPayload");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 11)),
+                value: FilterValue::JwtPayload,
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_hex() {
+        let mut reader = Reader::new("toHex");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 6)),
+                value: FilterValue::ToHex,
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_lower() {
+        let mut reader = Reader::new("toLower");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 8)),
+                value: FilterValue::ToLower,
+            }
+        );
+    }
+
+    #[test]
+    fn test_base64_decode() {
+        let mut reader = Reader::new("base64Decode");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 13)),
+                value: FilterValue::Base64Decode,
+            }
+        );
+    }
+
+    #[test]
+    fn test_base64_encode() {
+        let mut reader = Reader::new("base64Encode");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 13)),
+                value: FilterValue::Base64Encode,
+            }
+        );
+
+        let mut reader = Reader::new("base64UrlSafeEncode");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20)),
+                value: FilterValue::Base64UrlSafeEncode,
+            }
+        );
+    }
+
     #[test]
     fn test_error() {
         let mut reader = Reader::new("xcount");
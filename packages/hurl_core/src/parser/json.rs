@@ -18,7 +18,7 @@
 use crate::ast::{JsonListElement, JsonObjectElement, JsonValue, SourceInfo, Template};
 use crate::combinator::{choice, non_recover, ParseError as ParseErrorTrait};
 use crate::parser::primitives::{boolean, hex_digit, literal, try_literal};
-use crate::parser::template::EncodedString;
+use crate::parser::template::{EncodedString, PlaceholderState};
 use crate::parser::{templatize, JsonErrorVariant, ParseError, ParseErrorKind, ParseResult};
 use crate::reader::{Pos, Reader};
 
@@ -82,14 +82,13 @@ fn string_value(reader: &mut Reader) -> ParseResult<JsonValue> {
 fn string_template(reader: &mut Reader) -> ParseResult<Template> {
     try_literal("\"", reader)?;
     let delimiter = Some('"');
-    let mut chars = vec![];
     let start = reader.cursor();
-    loop {
-        if reader.peek() == Some('"') || reader.is_eof() {
-            break;
-        }
-        let char = any_char(reader)?;
-        chars.push(char);
+    let (mut chars, unclosed) = string_chars(reader, true)?;
+    if unclosed {
+        // With an unclosed placeholder, we read the string again, ending at the first quote, so
+        // the error is reported on the placeholder.
+        reader.seek(start);
+        (chars, _) = string_chars(reader, false)?;
     }
     let end = reader.cursor();
 
@@ -108,6 +107,29 @@ fn string_template(reader: &mut Reader) -> ParseResult<Template> {
     Ok(template)
 }
 
+/// Reads the chars of a JSON string, up to the closing quote.
+///
+/// If `quote_in_placeholder` is `true`, a quote inside a placeholder `{{ }}` doesn't end the
+/// string. Returns the chars and whether the chars end inside a placeholder.
+#[allow(clippy::type_complexity)]
+fn string_chars(
+    reader: &mut Reader,
+    quote_in_placeholder: bool,
+) -> ParseResult<(Vec<(char, String, Pos)>, bool)> {
+    let mut chars = vec![];
+    let mut placeholder = PlaceholderState::default();
+    loop {
+        let quote_allowed = quote_in_placeholder && placeholder.is_inside();
+        if (reader.peek() == Some('"') && !quote_allowed) || reader.is_eof() {
+            break;
+        }
+        let char = any_char(reader)?;
+        placeholder.push(&char.1);
+        chars.push(char);
+    }
+    Ok((chars, placeholder.is_inside()))
+}
+
 fn any_char(reader: &mut Reader) -> ParseResult<(char, String, Pos)> {
     let start = reader.cursor();
     match escape_char(reader) {
@@ -571,6 +593,17 @@ mod tests {
         assert_eq!(reader.cursor().index, 4);
     }
 
+    #[test]
+    fn test_string_value_with_variable_default() {
+        let mut reader = Reader::new("\"Hello {{name | default \"Bob\"}}!\",");
+        let JsonValue::String(template) = string_value(&mut reader).unwrap() else {
+            panic!("expecting a JSON string");
+        };
+        assert_eq!(template.elements.len(), 3);
+        assert_eq!(template.to_string(), "Hello {{name | default \"Bob\"}}!");
+        assert_eq!(reader.peek(), Some(','));
+    }
+
     #[test]
     fn test_string_value_error() {
         let mut reader = Reader::new("1");
@@ -595,6 +628,22 @@ mod tests {
         );
         assert!(!error.recoverable);
 
+        let mut reader = Reader::new("\"{{x | default \"a\"\"");
+        let error = string_value(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 16
+            }
+        );
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "}}".to_string()
+            }
+        );
+
         let mut reader = Reader::new("\"{{x\"");
         let error = string_value(&mut reader).err().unwrap();
         assert_eq!(error.pos, Pos { line: 1, column: 5 });
@@ -17,18 +17,58 @@
  */
 pub type ParseResult<T> = Result<T, ParseError>;
 
+/// Parses a Hurl file content `s` and returns its full AST.
+///
+/// The AST preserves whitespace, comments and the [`crate::ast::SourceInfo`] of its nodes, so it
+/// can be used by tools (linters, formatters, editors) without evaluating anything.
+///
+/// # Example
+///
+/// ```
+/// use hurl_core::parser::parse_hurl_file;
+///
+/// let hurl_file = parse_hurl_file("GET https://example.org\nHTTP 200\n").unwrap();
+/// let entry = &hurl_file.entries[0];
+/// assert_eq!(entry.request.method.to_string(), "GET");
+/// assert_eq!(entry.request.url.to_string(), "https://example.org");
+/// assert!(entry.response.is_some());
+/// ```
 pub fn parse_hurl_file(s: &str) -> ParseResult<HurlFile> {
     let mut reader = Reader::new(s);
     parsers::hurl_file(&mut reader)
 }
 
+/// Parses a single header line `name: value`, the name and the value can be templates.
+///
+/// # Example
+///
+/// ```
+/// use hurl_core::parser::parse_header;
+///
+/// let header = parse_header("X-Request-Source: {{source}}").unwrap();
+/// assert_eq!(header.key.to_string(), "X-Request-Source");
+/// assert_eq!(header.value.to_string(), "{{source}}");
+/// ```
+pub fn parse_header(s: &str) -> ParseResult<KeyValue> {
+    let mut reader = Reader::new(s);
+    let header = primitives::key_value(&mut reader)?;
+    if !reader.is_eof() {
+        let kind = ParseErrorKind::Expecting {
+            value: "end of header".to_string(),
+        };
+        return Err(ParseError::new(reader.cursor().pos, false, kind));
+    }
+    Ok(header)
+}
+
 pub use self::error::{JsonErrorVariant, ParseError, ParseErrorKind};
 pub use self::json::{
     boolean_value as parse_json_boolean, null_value as parse_json_null,
     number_value as parse_json_number, parse as parse_json,
 };
+pub use self::option::suggest_option_name;
 pub use self::template::templatize;
-use crate::ast::HurlFile;
+use crate::ast::{HurlFile, KeyValue};
 use crate::reader::Reader;
 
 mod base64;
@@ -28,12 +28,29 @@ use crate::reader::Reader;
 
 pub fn multiline_string(reader: &mut Reader) -> ParseResult<MultilineString> {
     try_literal("```", reader)?;
+    let start = reader.cursor();
 
-    choice(&[json_text, xml_text, graphql, plain_text], reader)
+    let multiline = choice(
+        &[
+            json_text, xml_text, html_text, graphql, other_text, plain_text,
+        ],
+        reader,
+    )?;
+
+    // Lenient mode only makes sense for JSON.
+    if multiline
+        .attributes
+        .contains(&MultilineStringAttribute::Lenient)
+        && !matches!(multiline.kind, MultilineStringKind::Json(_))
+    {
+        let kind = ParseErrorKind::MultilineAttribute("lenient".to_string());
+        return Err(ParseError::new(start.pos, false, kind));
+    }
+    Ok(multiline)
 }
 
 fn text(lang: &str, reader: &mut Reader) -> ParseResult<(Text, Vec<MultilineStringAttribute>)> {
-    try_literal(lang, reader)?;
+    lang_hint(lang, reader)?;
     drop(try_literal(",", reader));
     let attributes = multiline_string_attributes(reader)?;
     let escape = attributes.contains(&MultilineStringAttribute::Escape);
@@ -62,8 +79,49 @@ fn xml_text(reader: &mut Reader) -> ParseResult<MultilineString> {
     Ok(MultilineString { kind, attributes })
 }
 
+fn html_text(reader: &mut Reader) -> ParseResult<MultilineString> {
+    let (text, attributes) = text("html", reader)?;
+    let kind = MultilineStringKind::Html(text);
+    Ok(MultilineString { kind, attributes })
+}
+
+/// Parses the language hint `lang`, that must be a whole word: ```` ```jsonl ```` is not a JSON
+/// multiline string.
+fn lang_hint(lang: &str, reader: &mut Reader) -> ParseResult<()> {
+    let start = reader.cursor();
+    try_literal(lang, reader)?;
+    if reader.peek().is_some_and(is_lang_char) {
+        let kind = ParseErrorKind::Expecting {
+            value: lang.to_string(),
+        };
+        return Err(ParseError::new(start.pos, true, kind));
+    }
+    Ok(())
+}
+
+fn is_lang_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_'
+}
+
+/// Parses a multiline string with a language hint unknown to Hurl.
+/// The hint is kept in the AST but has no effect on the string value.
+fn other_text(reader: &mut Reader) -> ParseResult<MultilineString> {
+    let start = reader.cursor();
+    let lang = reader.read_while(is_lang_char);
+    if lang.is_empty() || lang == "escape" || lang == "novariable" || lang == "lenient" {
+        let kind = ParseErrorKind::Expecting {
+            value: "lang".to_string(),
+        };
+        return Err(ParseError::new(start.pos, true, kind));
+    }
+    reader.seek(start);
+    let (text, attributes) = text(&lang, reader)?;
+    let kind = MultilineStringKind::Other(lang, text);
+    Ok(MultilineString { kind, attributes })
+}
+
 fn graphql(reader: &mut Reader) -> ParseResult<MultilineString> {
-    try_literal("graphql", reader)?;
+    lang_hint("graphql", reader)?;
     let space = zero_or_more_spaces(reader)?;
     drop(try_literal(",", reader));
     let attributes = multiline_string_attributes(reader)?;
@@ -101,7 +159,6 @@ fn graphql(reader: &mut Reader) -> ParseResult<MultilineString> {
                         value: template,
                         variables: Some(variables),
                     });
-                    let attributes = vec![];
                     return Ok(MultilineString { kind, attributes });
                 }
             }
@@ -145,6 +202,8 @@ fn multiline_string_attributes(reader: &mut Reader) -> ParseResult<Vec<Multiline
             attributes.push(MultilineStringAttribute::Escape);
         } else if attribute == "novariable" {
             attributes.push(MultilineStringAttribute::NoVariable);
+        } else if attribute == "lenient" {
+            attributes.push(MultilineStringAttribute::Lenient);
         } else {
             let kind = ParseErrorKind::MultilineAttribute(attribute);
             return Err(ParseError {
@@ -450,8 +509,8 @@ mod tests {
     #[test]
     fn test_multiline_string_failed() {
         let data = [
-            "```hexaaa\nline1\nline2\nline3\n```",
-            "```aaa\nline1\nline2\nline3\n```",
+            "```hexaaa bbb\nline1\nline2\nline3\n```",
+            "```aaa,bbb\nline1\nline2\nline3\n```",
         ];
 
         for text in data.iter() {
@@ -512,13 +571,60 @@ mod tests {
     fn test_multiline_string_hello_error() {
         let mut reader = Reader::new("```Hello World!```");
         let error = multiline_string(&mut reader).unwrap_err();
-        assert_eq!(error.pos, Pos::new(1, 4));
+        assert_eq!(error.pos, Pos::new(1, 10));
         assert_eq!(
             error.kind,
-            ParseErrorKind::MultilineAttribute("Hello World!```".to_string())
+            ParseErrorKind::MultilineAttribute("World!```".to_string())
+        );
+    }
+
+    #[test]
+    fn test_multiline_string_html() {
+        let mut reader = Reader::new("```html\n<p>Hello</p>\n```");
+        let multiline = multiline_string(&mut reader).unwrap();
+        assert_eq!(multiline.lang(), "html");
+        assert!(matches!(multiline.kind, MultilineStringKind::Html(_)));
+        assert_eq!(multiline.value().to_string(), "<p>Hello</p>\n");
+    }
+
+    #[test]
+    fn test_multiline_string_other_lang() {
+        let mut reader = Reader::new("```yaml,escape\nname: bob\\t\n```");
+        let multiline = multiline_string(&mut reader).unwrap();
+        assert_eq!(multiline.lang(), "yaml");
+        assert_eq!(multiline.attributes, vec![MultilineStringAttribute::Escape]);
+        assert_eq!(multiline.value().to_string(), "name: bob\t\n");
+
+        // Attributes are not language hints.
+        let mut reader = Reader::new("```novariable\nline1\n```");
+        let multiline = multiline_string(&mut reader).unwrap();
+        assert_eq!(multiline.lang(), "");
+        assert_eq!(
+            multiline.attributes,
+            vec![MultilineStringAttribute::NoVariable]
         );
     }
 
+    #[test]
+    fn test_multiline_string_lang_prefix() {
+        // A language hint starting with a known language is another language.
+        for (content, lang) in [
+            ("```jsonl\n{}\n{}\n```", "jsonl"),
+            ("```json5\n{a: 1}\n```", "json5"),
+            ("```xmlx\n<a/>\n```", "xmlx"),
+            ("```graphqls\ntype A\n```", "graphqls"),
+        ] {
+            let mut reader = Reader::new(content);
+            let multiline = multiline_string(&mut reader).unwrap();
+            assert_eq!(multiline.lang(), lang);
+            assert!(matches!(multiline.kind, MultilineStringKind::Other(..)));
+        }
+
+        let mut reader = Reader::new("```json,escape\n{}\n```");
+        let multiline = multiline_string(&mut reader).unwrap();
+        assert!(matches!(multiline.kind, MultilineStringKind::Json(_)));
+    }
+
     #[test]
     fn test_multiline_string_csv() {
         let mut reader = Reader::new("```\nline1\nline2\nline3\n```");
@@ -638,6 +744,31 @@ mod tests {
         assert_eq!(error.pos, Pos::new(1, 1));
     }
 
+    #[test]
+    fn test_multiline_string_lenient() {
+        let mut reader = Reader::new("```json,lenient\n{}\n```");
+        assert_eq!(
+            multiline_string(&mut reader).unwrap().attributes,
+            vec![MultilineStringAttribute::Lenient]
+        );
+
+        let mut reader = Reader::new("```xml,lenient\n<a/>\n```");
+        let error = multiline_string(&mut reader).unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::MultilineAttribute("lenient".to_string())
+        );
+        assert_eq!(error.pos, Pos::new(1, 4));
+        assert!(!error.recoverable);
+
+        let mut reader = Reader::new("```lenient\n{}\n```");
+        let error = multiline_string(&mut reader).unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::MultilineAttribute("lenient".to_string())
+        );
+    }
+
     #[test]
     fn test_multiline_string_escape() {
         let mut reader = Reader::new("```escape\n\\t\n```");
@@ -691,12 +822,12 @@ mod tests {
         );
         assert!(!error.recoverable);
 
-        let mut reader = Reader::new("```xxx");
+        let mut reader = Reader::new("```xxx,yyy");
         let error = multiline_string(&mut reader).err().unwrap();
-        assert_eq!(error.pos, Pos { line: 1, column: 4 });
+        assert_eq!(error.pos, Pos { line: 1, column: 8 });
         assert_eq!(
             error.kind,
-            ParseErrorKind::MultilineAttribute("xxx".to_string())
+            ParseErrorKind::MultilineAttribute("yyy".to_string())
         );
         assert!(!error.recoverable);
     }
@@ -809,6 +940,25 @@ variables {
         );
     }
 
+    #[test]
+    fn test_multiline_string_graphql_with_variables_and_attributes() {
+        let mut reader = Reader::new(
+            r#"```graphql,escape
+{ hero { name } }
+
+variables {}
+```"#,
+        );
+        let multiline = multiline_string(&mut reader).unwrap();
+        assert_eq!(multiline.attributes, vec![MultilineStringAttribute::Escape]);
+        match multiline.kind {
+            MultilineStringKind::GraphQl(GraphQl { variables, .. }) => {
+                assert!(variables.is_some());
+            }
+            _ => panic!("expecting a GraphQL multiline string"),
+        }
+    }
+
     #[test]
     fn test_multiline_string_graphql_with_variables_error() {
         let mut reader = Reader::new(
@@ -17,10 +17,11 @@
  */
 use crate::ast::{
     is_variable_reserved, BooleanOption, CountOption, DurationOption, EntryOption, NaturalOption,
-    OptionKind, SourceInfo, VariableDefinition, VariableValue,
+    OptionKind, SourceInfo, UnknownOption, VariableDefinition, VariableValue,
 };
 use crate::combinator::{choice, non_recover};
 use crate::parser::duration::duration;
+use crate::parser::error::suggestion;
 use crate::parser::number::{integer, natural, number};
 use crate::parser::primitives::{
     boolean, line_terminator, literal, null, optional_line_terminators, try_literal,
@@ -33,62 +34,86 @@ use crate::typing::Count;
 
 use super::placeholder;
 
+/// Parser of an option value.
+type OptionValueParser = fn(&mut Reader) -> ParseResult<OptionKind>;
+
+/// Options supported in an `[Options]` section, with the parser of their value.
+const OPTIONS: &[(&str, OptionValueParser)] = &[
+    ("aws-sigv4", option_aws_sigv4),
+    ("cacert", option_cacert),
+    ("cert", option_cert),
+    ("chunked", option_chunked),
+    ("compress-body", option_compress_body),
+    ("compressed", option_compressed),
+    ("connect-to", option_connect_to),
+    ("connect-timeout", option_connect_timeout),
+    ("delay", option_delay),
+    ("insecure", option_insecure),
+    ("http1.0", option_http_10),
+    ("http1.1", option_http_11),
+    ("http2", option_http_2),
+    ("http3", option_http_3),
+    ("ipv4", option_ipv4),
+    ("ipv6", option_ipv6),
+    ("key", option_key),
+    ("limit-rate", option_limit_rate),
+    ("location", option_follow_location),
+    ("location-trusted", option_follow_location_trusted),
+    ("max-time", option_max_time),
+    ("max-redirs", option_max_redirect),
+    ("netrc", option_netrc),
+    ("netrc-file", option_netrc_file),
+    ("netrc-optional", option_netrc_optional),
+    ("output", option_output),
+    ("path-as-is", option_path_as_is),
+    ("proxy", option_proxy),
+    ("repeat", option_repeat),
+    ("resolve", option_resolve),
+    ("retry", option_retry),
+    ("retry-interval", option_retry_interval),
+    ("retry-max-time", option_retry_max_time),
+    ("skip", option_skip),
+    ("skip-unless", option_skip_unless),
+    ("unix-socket", option_unix_socket),
+    ("user", option_user),
+    ("variable", option_variable),
+    ("verbose", option_verbose),
+    ("very-verbose", option_very_verbose),
+];
+
+/// Returns the names of the options supported in an `[Options]` section.
+pub fn option_names() -> Vec<&'static str> {
+    OPTIONS.iter().map(|(name, _)| *name).collect()
+}
+
+/// Returns the name of a supported option close to an unknown option `name`, if any.
+pub fn suggest_option_name(name: &str) -> Option<String> {
+    suggestion(&option_names(), name)
+}
+
 /// Parse an option in an `[Options]` section.
 pub fn parse(reader: &mut Reader) -> ParseResult<EntryOption> {
     let line_terminators = optional_line_terminators(reader)?;
     let space0 = zero_or_more_spaces(reader)?;
     let start = reader.cursor();
-    // We accept '_' even if there is no option name with this character. We do this to be able to
-    // enter the parsing of the option name and to have better error description (ex: 'max-redirs'
-    // vs 'max_redirs').
+    // We accept '_' even if there is no option name with this character. We do this to read a
+    // typo like 'max_redirs' as an unknown option, that can be reported with a suggestion
+    // ('max-redirs').
     let option =
         reader.read_while(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.' || c == '_');
     let space1 = zero_or_more_spaces(reader)?;
     try_literal(":", reader)?;
     let space2 = zero_or_more_spaces(reader)?;
-    let kind = match option.as_str() {
-        "aws-sigv4" => option_aws_sigv4(reader)?,
-        "cacert" => option_cacert(reader)?,
-        "cert" => option_cert(reader)?,
-        "compressed" => option_compressed(reader)?,
-        "connect-to" => option_connect_to(reader)?,
-        "connect-timeout" => option_connect_timeout(reader)?,
-        "delay" => option_delay(reader)?,
-        "insecure" => option_insecure(reader)?,
-        "http1.0" => option_http_10(reader)?,
-        "http1.1" => option_http_11(reader)?,
-        "http2" => option_http_2(reader)?,
-        "http3" => option_http_3(reader)?,
-        "ipv4" => option_ipv4(reader)?,
-        "ipv6" => option_ipv6(reader)?,
-        "key" => option_key(reader)?,
-        "limit-rate" => option_limit_rate(reader)?,
-        "location" => option_follow_location(reader)?,
-        "location-trusted" => option_follow_location_trusted(reader)?,
-        "max-redirs" => option_max_redirect(reader)?,
-        "netrc" => option_netrc(reader)?,
-        "netrc-file" => option_netrc_file(reader)?,
-        "netrc-optional" => option_netrc_optional(reader)?,
-        "output" => option_output(reader)?,
-        "path-as-is" => option_path_as_is(reader)?,
-        "proxy" => option_proxy(reader)?,
-        "repeat" => option_repeat(reader)?,
-        "resolve" => option_resolve(reader)?,
-        "retry" => option_retry(reader)?,
-        "retry-interval" => option_retry_interval(reader)?,
-        "skip" => option_skip(reader)?,
-        "unix-socket" => option_unix_socket(reader)?,
-        "user" => option_user(reader)?,
-        "variable" => option_variable(reader)?,
-        "verbose" => option_verbose(reader)?,
-        "very-verbose" => option_very_verbose(reader)?,
-        _ => {
+    let kind = match OPTIONS.iter().find(|(name, _)| *name == option) {
+        Some((_, parse_value)) => parse_value(reader)?,
+        None if option.is_empty() => {
             return Err(ParseError::new(
                 start.pos,
                 false,
-                ParseErrorKind::InvalidOption(option.to_string()),
+                ParseErrorKind::InvalidOption(option),
             ))
         }
+        None => option_unknown(option, reader),
     };
 
     let line_terminator0 = line_terminator(reader)?;
@@ -117,6 +142,16 @@ fn option_cert(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::ClientCert(value))
 }
 
+fn option_compress_body(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = unquoted_template(reader)?;
+    Ok(OptionKind::CompressBody(value))
+}
+
+fn option_chunked(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::Chunked(value))
+}
+
 fn option_compressed(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::Compressed(value))
@@ -197,6 +232,11 @@ fn option_max_redirect(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::MaxRedirect(value))
 }
 
+fn option_max_time(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = duration_option(reader)?;
+    Ok(OptionKind::MaxTime(value))
+}
+
 fn option_netrc(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::NetRc(value))
@@ -247,16 +287,46 @@ fn option_retry_interval(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::RetryInterval(value))
 }
 
+fn option_retry_max_time(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(duration_option, reader)?;
+    Ok(OptionKind::RetryMaxTime(value))
+}
+
 fn option_skip(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = non_recover(boolean_option, reader)?;
     Ok(OptionKind::Skip(value))
 }
 
+fn option_skip_unless(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = non_recover(boolean_option, reader)?;
+    Ok(OptionKind::SkipUnless(value))
+}
+
 fn option_user(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::User(value))
 }
 
+/// Parses the raw value of an unknown option, up to the end of the line or a comment.
+fn option_unknown(name: String, reader: &mut Reader) -> OptionKind {
+    let mut value = String::new();
+    let mut end = reader.cursor();
+    while let Some(c) = reader.peek() {
+        if c == '#' || c == '\n' || c == '\r' {
+            break;
+        }
+        reader.read();
+        value.push(c);
+        // Trailing spaces are not part of the value, they're parsed by the line terminator.
+        if c != ' ' && c != '\t' {
+            end = reader.cursor();
+        }
+    }
+    reader.seek(end);
+    let value = value.trim_end_matches([' ', '\t']).to_string();
+    OptionKind::Unknown(UnknownOption { name, value })
+}
+
 fn option_unix_socket(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = unquoted_template(reader)?;
     Ok(OptionKind::UnixSocket(value))
@@ -439,8 +509,9 @@ fn variable_value(reader: &mut Reader) -> ParseResult<VariableValue> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ast::{LineTerminator, Number, Template, TemplateElement, Whitespace, I64};
+    use crate::ast::{LineTerminator, Number, Template, TemplateElement, Whitespace, I64, U64};
     use crate::reader::Pos;
+    use crate::typing::{Duration, DurationUnit};
 
     #[test]
     fn test_option_insecure() {
@@ -511,6 +582,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_option_names() {
+        // Each supported option is parsed by its own value parser.
+        for name in option_names() {
+            let mut reader = Reader::new(&format!("{name}: _"));
+            if let Ok(option) = parse(&mut reader) {
+                assert!(!matches!(option.kind, OptionKind::Unknown(_)));
+                assert_eq!(option.kind.name(), name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_option_unknown() {
+        let mut reader = Reader::new("max_time: 10 s  # comment");
+        let option = parse(&mut reader).unwrap();
+        assert_eq!(
+            option.kind,
+            OptionKind::Unknown(UnknownOption {
+                name: "max_time".to_string(),
+                value: "10 s".to_string(),
+            })
+        );
+        assert_eq!(option.line_terminator0.space0.value, "  ");
+        assert_eq!(
+            suggest_option_name("max_time"),
+            Some("max-time".to_string())
+        );
+        assert_eq!(suggest_option_name("foo"), None);
+
+        let mut reader = Reader::new(": 10");
+        let error = parse(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 1));
+        assert_eq!(error.kind, ParseErrorKind::InvalidOption(String::new()));
+    }
+
     #[test]
     fn test_option_insecure_error() {
         let mut reader = Reader::new("insecure: error");
@@ -638,6 +745,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_option_max_time() {
+        let mut reader = Reader::new("max-time: 30s");
+        let option = parse(&mut reader).unwrap();
+        assert_eq!(
+            option.kind,
+            OptionKind::MaxTime(DurationOption::Literal(Duration::new(
+                U64::new(30, "30".to_string()),
+                Some(DurationUnit::Second)
+            )))
+        );
+        assert_eq!(option.kind.value_as_str(), "30s");
+    }
+
+    #[test]
+    fn test_option_retry_max_time() {
+        let mut reader = Reader::new("retry-max-time: 2m");
+        let option = parse(&mut reader).unwrap();
+        assert_eq!(
+            option.kind,
+            OptionKind::RetryMaxTime(DurationOption::Literal(Duration::new(
+                U64::new(2, "2".to_string()),
+                Some(DurationUnit::Minute)
+            )))
+        );
+        assert_eq!(option.kind.value_as_str(), "2m");
+
+        let mut reader = Reader::new("retry-max-time: ###");
+        let error = parse(&mut reader).err().unwrap();
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_variable_definition() {
         let mut reader = Reader::new("a=1");
@@ -269,6 +269,68 @@ mod tests {
         assert_eq!(e.response.unwrap().status.value, StatusValue::Specific(200));
     }
 
+    #[test]
+    fn test_entry_with_comments() {
+        let mut reader = Reader::new(
+            "POST http://localhost/upload\n\
+             # before body\n\
+             file,data.bin; # after body\n\
+             # before response\n\
+             HTTP 200\n",
+        );
+        let e = entry(&mut reader).unwrap();
+
+        let comments = |lts: &[LineTerminator]| {
+            lts.iter()
+                .filter_map(|lt| lt.comment.as_ref().map(|c| c.value.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        let body = e.request.body.unwrap();
+        assert_eq!(comments(&body.line_terminators), vec![" before body"]);
+        assert!(matches!(body.value, Bytes::File(_)));
+        assert_eq!(
+            body.line_terminator0.comment.unwrap().value,
+            " after body".to_string()
+        );
+
+        let response = e.response.unwrap();
+        assert_eq!(
+            comments(&response.line_terminators),
+            vec![" before response"]
+        );
+        assert_eq!(response.status.value, StatusValue::Specific(200));
+    }
+
+    #[test]
+    fn test_hurl_file_body_without_semicolon_and_trailing_comments() {
+        let mut reader = Reader::new(
+            "POST http://localhost/upload\n\
+             hex,0102 # after body\n\
+             # end of file\n",
+        );
+        let hurl_file = hurl_file(&mut reader).unwrap();
+        assert_eq!(hurl_file.entries.len(), 1);
+
+        let body = hurl_file.entries[0].request.body.as_ref().unwrap();
+        let Bytes::Hex(hex) = &body.value else {
+            panic!("expecting an hex body");
+        };
+        assert_eq!(hex.value, vec![1, 2]);
+        assert_eq!(
+            body.line_terminator0.comment.as_ref().unwrap().value,
+            " after body".to_string()
+        );
+        assert_eq!(
+            hurl_file.line_terminators[0]
+                .comment
+                .as_ref()
+                .unwrap()
+                .value,
+            " end of file".to_string()
+        );
+    }
+
     #[test]
     fn test_request() {
         let mut reader = Reader::new("GET http://google.fr");
@@ -81,6 +81,7 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
             end_with_predicate,
             contain_predicate,
             include_predicate,
+            match_schema_predicate,
             match_predicate,
             integer_predicate,
             float_predicate,
@@ -264,6 +265,24 @@ fn match_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::Match { space0, value })
 }
 
+fn match_schema_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("matchesSchema", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !matches!(
+        value,
+        PredicateValue::File(_) | PredicateValue::String(_) | PredicateValue::MultilineString(_)
+    ) {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::MatchSchema { space0, value })
+}
+
 fn integer_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     try_literal("isInteger", reader)?;
     Ok(PredicateFuncValue::IsInteger)
@@ -492,6 +511,23 @@ mod tests {
         assert_eq!(error.kind, ParseErrorKind::PredicateValue);
     }
 
+    #[test]
+    fn test_match_schema_predicate() {
+        let mut reader = Reader::new("matchesSchema file,schema.json;");
+        let value = predicate_func_value(&mut reader).unwrap();
+        let PredicateFuncValue::MatchSchema { space0, value } = value else {
+            panic!("Expecting a matchesSchema predicate");
+        };
+        assert_eq!(space0.value, " ");
+        assert!(matches!(value, PredicateValue::File(_)));
+
+        let mut reader = Reader::new("matchesSchema 1");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 15));
+        assert!(!error.recoverable);
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+    }
+
     #[test]
     fn test_date_predicate() {
         let mut reader = Reader::new("isDate");
@@ -210,14 +210,27 @@ pub fn key_value(reader: &mut Reader) -> ParseResult<KeyValue> {
 }
 
 pub fn hex(reader: &mut Reader) -> ParseResult<Hex> {
+    parse_hex(reader, false)
+}
+
+/// Parses an hex body. Contrary to [`hex`], the final `;` can be omitted at the end of the input.
+pub(crate) fn hex_body(reader: &mut Reader) -> ParseResult<Hex> {
+    parse_hex(reader, true)
+}
+
+fn parse_hex(reader: &mut Reader, optional_at_eof: bool) -> ParseResult<Hex> {
     try_literal("hex", reader)?;
     literal(",", reader)?;
     let space0 = zero_or_more_spaces(reader)?;
     let mut value: Vec<u8> = vec![];
     let start = reader.cursor();
     let mut current: i32 = -1;
+    // Last position after a hex digit, used to exclude trailing whitespaces from the encoded value.
+    let mut end = start;
     loop {
         let s = reader.cursor();
+        // Like base64, whitespaces may be present anywhere and are ignored on decoding.
+        reader.read_while(|c| c == ' ' || c == '\t' || c == '\n');
         match hex_digit(reader) {
             Ok(d) => {
                 if current != -1 {
@@ -226,6 +239,7 @@ pub fn hex(reader: &mut Reader) -> ParseResult<Hex> {
                 } else {
                     current = d as i32;
                 }
+                end = reader.cursor();
             }
             Err(_) => {
                 reader.seek(s);
@@ -235,14 +249,26 @@ pub fn hex(reader: &mut Reader) -> ParseResult<Hex> {
     }
     if current != -1 {
         return Err(ParseError::new(
-            reader.cursor().pos,
+            end.pos,
             false,
             ParseErrorKind::OddNumberOfHexDigits,
         ));
     }
+    reader.seek(end);
     let encoded = reader.read_from(start.index);
     let space1 = zero_or_more_spaces(reader)?;
-    literal(";", reader)?;
+    if let Some(c) = reader.peek() {
+        // A body without final `;` can be followed by a comment.
+        let comment_start = optional_at_eof && c == '#';
+        if c != ';' && !comment_start && hex_digit_value(c).is_none() && !c.is_whitespace() {
+            return Err(ParseError::new(
+                reader.cursor().pos,
+                false,
+                ParseErrorKind::HexDigit,
+            ));
+        }
+    }
+    semicolon(reader, optional_at_eof)?;
 
     Ok(Hex {
         space0,
@@ -337,13 +363,21 @@ pub fn boolean(reader: &mut Reader) -> ParseResult<bool> {
 }
 
 pub(crate) fn file(reader: &mut Reader) -> ParseResult<File> {
-    let _start = reader.cursor();
+    parse_file(reader, false)
+}
+
+/// Parses a file body. Contrary to [`file`], the final `;` can be omitted at the end of the input.
+pub(crate) fn file_body(reader: &mut Reader) -> ParseResult<File> {
+    parse_file(reader, true)
+}
+
+fn parse_file(reader: &mut Reader, optional_at_eof: bool) -> ParseResult<File> {
     try_literal("file", reader)?;
     literal(",", reader)?;
     let space0 = zero_or_more_spaces(reader)?;
     let f = filename::parse(reader)?;
     let space1 = zero_or_more_spaces(reader)?;
-    literal(";", reader)?;
+    semicolon(reader, optional_at_eof)?;
     Ok(File {
         space0,
         filename: f,
@@ -352,26 +386,66 @@ pub(crate) fn file(reader: &mut Reader) -> ParseResult<File> {
 }
 
 pub(crate) fn base64(reader: &mut Reader) -> ParseResult<Base64> {
+    parse_base64(reader, false)
+}
+
+/// Parses a base64 body. Contrary to [`base64`], the final `;` can be omitted at the end of the input.
+pub(crate) fn base64_body(reader: &mut Reader) -> ParseResult<Base64> {
+    parse_base64(reader, true)
+}
+
+fn parse_base64(reader: &mut Reader, optional_at_eof: bool) -> ParseResult<Base64> {
     // base64 => can have whitespace
     // support parser position
-    try_literal("base64", reader)?;
+    // base64url must be tested first as base64 is one of its prefixes.
+    let url_safe = try_literal("base64url", reader).is_ok();
+    if !url_safe {
+        try_literal("base64", reader)?;
+    }
     literal(",", reader)?;
     let space0 = zero_or_more_spaces(reader)?;
     let save_state = reader.cursor();
-    let value = base64::parse(reader);
+    let value = if url_safe {
+        base64::parse_url_safe(reader)
+    } else {
+        base64::parse(reader)
+    };
     let count = reader.cursor().index - save_state.index;
     reader.seek(save_state);
     let encoded = reader.read_n(count);
     let space1 = zero_or_more_spaces(reader)?;
-    literal(";", reader)?;
+    semicolon(reader, optional_at_eof)?;
     Ok(Base64 {
         space0,
         value,
         encoded,
         space1,
+        url_safe,
     })
 }
 
+/// Parses the `;` ending a file, base64 or hex value.
+///
+/// If `optional_at_eof` is true, the `;` can be omitted when the value is only followed by
+/// whitespaces and comments until the end of the input.
+fn semicolon(reader: &mut Reader, optional_at_eof: bool) -> ParseResult<()> {
+    if optional_at_eof {
+        let save = reader.cursor();
+        loop {
+            reader.read_while(|c| c.is_whitespace());
+            if comment(reader).is_err() {
+                break;
+            }
+        }
+        let at_eof = reader.is_eof();
+        reader.seek(save);
+        if at_eof {
+            return Ok(());
+        }
+    }
+    literal(";", reader)
+}
+
 pub fn eof(reader: &mut Reader) -> ParseResult<()> {
     if reader.is_eof() {
         Ok(())
@@ -813,6 +887,54 @@ mod tests {
         let error = hex(&mut reader).err().unwrap();
         assert_eq!(error.pos, Pos { line: 1, column: 8 });
         assert_eq!(error.kind, ParseErrorKind::OddNumberOfHexDigits);
+
+        let mut reader = Reader::new("hex,01 2 ;");
+        let error = hex(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 9 });
+        assert_eq!(error.kind, ParseErrorKind::OddNumberOfHexDigits);
+
+        let mut reader = Reader::new("hex,01 zz;");
+        let error = hex(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 8 });
+        assert_eq!(error.kind, ParseErrorKind::HexDigit);
+        assert!(!error.recoverable);
+
+        // Newlines are only allowed between digits: the final `;` must follow the last digit on
+        // the same line.
+        for content in ["hex,01\n;", "hex,01 \n ;"] {
+            for parse in [hex, hex_body] {
+                let mut reader = Reader::new(content);
+                let error = parse(&mut reader).err().unwrap();
+                assert_eq!(error.pos.line, 1);
+                assert_eq!(
+                    error.kind,
+                    ParseErrorKind::Expecting {
+                        value: ";".to_string()
+                    }
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_hex_with_whitespace() {
+        let mut reader = Reader::new("hex, 48 65\n6c6c 6f ;");
+        assert_eq!(
+            hex(&mut reader).unwrap(),
+            Hex {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                },
+                value: b"Hello".to_vec(),
+                encoded: "48 65\n6c6c 6f".to_string(),
+                space1: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(2, 8), Pos::new(2, 9)),
+                },
+            }
+        );
+        assert_eq!(reader.cursor().index, 20);
     }
 
     #[test]
@@ -1033,8 +1155,36 @@ mod tests {
                     value: String::new(),
                     source_info: SourceInfo::new(Pos::new(1, 15), Pos::new(1, 15)),
                 },
+                url_safe: false,
             }
         );
         assert_eq!(reader.cursor().index, 15);
     }
+
+    #[test]
+    fn test_base64_url_safe() {
+        let mut reader = Reader::new("base64url, -_-_ 8A;xxx");
+        assert_eq!(
+            base64(&mut reader).unwrap(),
+            Base64 {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 11), Pos::new(1, 12)),
+                },
+                value: vec![251, 255, 191, 240],
+                encoded: String::from("-_-_ 8A"),
+                space1: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(1, 19), Pos::new(1, 19)),
+                },
+                url_safe: true,
+            }
+        );
+        assert_eq!(reader.cursor().index, 19);
+
+        // Same bytes as the standard alphabet, without padding.
+        let mut reader = Reader::new("base64url,YW55IGNhcm5hbCBwbGVhcw;");
+        let standard = base64(&mut Reader::new("base64,YW55IGNhcm5hbCBwbGVhcw==;")).unwrap();
+        assert_eq!(base64(&mut reader).unwrap().value, standard.value);
+    }
 }
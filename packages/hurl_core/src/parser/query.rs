@@ -37,7 +37,10 @@ fn query_value(reader: &mut Reader) -> ParseResult<QueryValue> {
     choice(
         &[
             status_query,
+            version_query,
             url_query,
+            redirects_query,
+            location_query,
             header_query,
             cookie_query,
             body_query,
@@ -60,11 +63,26 @@ fn status_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Status)
 }
 
+fn version_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("version", reader)?;
+    Ok(QueryValue::Version)
+}
+
 fn url_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("url", reader)?;
     Ok(QueryValue::Url)
 }
 
+fn redirects_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("redirects", reader)?;
+    Ok(QueryValue::Redirects)
+}
+
+fn location_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("location", reader)?;
+    Ok(QueryValue::Location)
+}
+
 fn header_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("header", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -244,6 +262,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_redirects_query() {
+        let mut reader = Reader::new("redirects");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+                value: QueryValue::Redirects,
+            }
+        );
+    }
+
+    #[test]
+    fn test_location_query() {
+        let mut reader = Reader::new("location");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+                value: QueryValue::Location,
+            }
+        );
+    }
+
+    #[test]
+    fn test_version_query() {
+        let mut reader = Reader::new("version");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 8)),
+                value: QueryValue::Version,
+            }
+        );
+    }
+
     #[test]
     fn test_header_query() {
         let mut reader = Reader::new("header \"Foo\"");
@@ -16,8 +16,8 @@
  *
  */
 use crate::ast::{
-    Assert, Capture, Cookie, FileParam, FileValue, MultipartParam, Section, SectionValue,
-    SourceInfo, Whitespace,
+    Assert, Capture, Cookie, FileParam, FileValue, MultipartParam, PartFilename, Section,
+    SectionValue, SourceInfo, Template, Whitespace,
 };
 use crate::combinator::{optional, recover, zero_or_more};
 use crate::parser::filter::filters;
@@ -225,7 +225,7 @@ fn file_value(reader: &mut Reader) -> ParseResult<FileValue> {
     let space1 = zero_or_more_spaces(reader)?;
     literal(";", reader)?;
     let save = reader.cursor();
-    let (space2, content_type) = match line_terminator(reader) {
+    let (space2, content_type, part_filename) = match line_terminator(reader) {
         Ok(_) => {
             reader.seek(save);
             let space2 = Whitespace {
@@ -235,13 +235,29 @@ fn file_value(reader: &mut Reader) -> ParseResult<FileValue> {
                     end: save.pos,
                 },
             };
-            (space2, None)
+            (space2, None, None)
         }
         Err(_) => {
             reader.seek(save);
             let space2 = zero_or_more_spaces(reader)?;
-            let content_type = file_content_type(reader)?;
-            (space2, Some(content_type))
+            if reader.peek_n(FILENAME_ATTRIBUTE.len()) == FILENAME_ATTRIBUTE {
+                let start = reader.cursor();
+                let space0 = Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(start.pos, start.pos),
+                };
+                let value = part_filename_value(reader)?;
+                let part_filename = PartFilename {
+                    space0: space0.clone(),
+                    space1: space0,
+                    value,
+                };
+                (space2, None, Some(Box::new(part_filename)))
+            } else {
+                let content_type = file_content_type(reader)?;
+                let part_filename = optional_part_filename(reader)?;
+                (space2, Some(content_type), part_filename)
+            }
         }
     };
 
@@ -251,15 +267,56 @@ fn file_value(reader: &mut Reader) -> ParseResult<FileValue> {
         space1,
         space2,
         content_type,
+        part_filename,
     })
 }
 
+const FILENAME_ATTRIBUTE: &str = "filename=";
+
+/// Parses an optional `; filename=...` following a file content type.
+fn optional_part_filename(reader: &mut Reader) -> ParseResult<Option<Box<PartFilename>>> {
+    let save = reader.cursor();
+    let space0 = zero_or_more_spaces(reader)?;
+    if try_literal(";", reader).is_err() {
+        reader.seek(save);
+        return Ok(None);
+    }
+    let space1 = zero_or_more_spaces(reader)?;
+    let value = part_filename_value(reader)?;
+    Ok(Some(Box::new(PartFilename {
+        space0,
+        space1,
+        value,
+    })))
+}
+
+fn part_filename_value(reader: &mut Reader) -> ParseResult<Template> {
+    literal(FILENAME_ATTRIBUTE, reader)?;
+    filename::parse(reader)
+}
+
+/// Returns true if the `reader` is positioned on a `; filename=` separator.
+fn is_part_filename_separator(reader: &mut Reader) -> bool {
+    let save = reader.cursor();
+    let found = try_literal(";", reader).is_ok()
+        && zero_or_more_spaces(reader).is_ok()
+        && reader.peek_n(FILENAME_ATTRIBUTE.len()) == FILENAME_ATTRIBUTE;
+    reader.seek(save);
+    found
+}
+
 fn file_content_type(reader: &mut Reader) -> ParseResult<String> {
     let start = reader.cursor();
     let mut buf = String::new();
     let mut spaces = String::new();
     let mut save = reader.cursor();
-    while let Some(c) = reader.read() {
+    loop {
+        if is_part_filename_separator(reader) {
+            break;
+        }
+        let Some(c) = reader.read() else {
+            break;
+        };
         if c.is_alphanumeric() || c == '/' || c == ';' || c == '=' || c == '-' {
             buf.push_str(spaces.as_str());
             spaces = String::new();
@@ -524,6 +581,7 @@ mod tests {
                     source_info: SourceInfo::new(Pos::new(1, 16), Pos::new(1, 16)),
                 },
                 content_type: None,
+                part_filename: None,
             }
         );
         let mut reader = Reader::new("file,hello.txt; text/html");
@@ -551,10 +609,51 @@ mod tests {
                     source_info: SourceInfo::new(Pos::new(1, 16), Pos::new(1, 17)),
                 },
                 content_type: Some("text/html".to_string()),
+                part_filename: None,
             }
         );
     }
 
+    #[test]
+    fn test_file_value_part_filename() {
+        let mut reader = Reader::new("file,avatar.bin; image/png; filename=avatar.png");
+        let value = file_value(&mut reader).unwrap();
+        assert_eq!(value.content_type, Some("image/png".to_string()));
+        assert_eq!(
+            value.part_filename,
+            Some(Box::new(PartFilename {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(1, 27), Pos::new(1, 27)),
+                },
+                space1: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 28), Pos::new(1, 29)),
+                },
+                value: Template {
+                    delimiter: None,
+                    elements: vec![TemplateElement::String {
+                        value: "avatar.png".to_string(),
+                        encoded: "avatar.png".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 38), Pos::new(1, 48)),
+                },
+            }))
+        );
+        assert_eq!(reader.cursor().index, 47);
+
+        let mut reader = Reader::new("file,avatar.bin; filename=avatar.png");
+        let value = file_value(&mut reader).unwrap();
+        assert_eq!(value.content_type, None);
+        assert_eq!(value.part_filename.unwrap().value.to_string(), "avatar.png");
+        assert_eq!(reader.cursor().index, 36);
+
+        let mut reader = Reader::new("file,avatar.bin; image/png; filename=");
+        let error = file_value(&mut reader).err().unwrap();
+        assert_eq!(error.kind, ParseErrorKind::Filename);
+        assert_eq!(error.pos, Pos::new(1, 38));
+    }
+
     #[test]
     fn test_file_content_type() {
         let mut reader = Reader::new("text/html");
@@ -577,6 +676,13 @@ mod tests {
             "text/html".to_string()
         );
         assert_eq!(reader.cursor().index, 9);
+
+        let mut reader = Reader::new("text/plain; charset=us-ascii; filename=a.txt");
+        assert_eq!(
+            file_content_type(&mut reader).unwrap(),
+            "text/plain; charset=us-ascii".to_string()
+        );
+        assert_eq!(reader.cursor().index, 28);
     }
 
     #[test]
@@ -834,4 +940,31 @@ mod tests {
         );
         assert_eq!(reader.cursor().pos, Pos { line: 2, column: 1 });
     }
+
+    #[test]
+    fn test_query_params_section() {
+        for (name, short) in [("Query", true), ("QueryStringParams", false)] {
+            let mut reader = Reader::new(&format!(
+                "[{name}]\nid: 1\nname: {{{{name}}}}\nid: 2\nHTTP 200\n"
+            ));
+            let section = request_section(&mut reader).unwrap();
+            let SectionValue::QueryParams(params, is_short) = section.value else {
+                panic!("expected a query params section");
+            };
+            assert_eq!(is_short, short);
+            let params = params
+                .iter()
+                .map(|p| (p.key.to_string(), p.value.to_string()))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                params,
+                vec![
+                    ("id".to_string(), "1".to_string()),
+                    ("name".to_string(), "{{name}}".to_string()),
+                    ("id".to_string(), "2".to_string()),
+                ]
+            );
+            assert_eq!(reader.cursor().pos, Pos { line: 5, column: 1 });
+        }
+    }
 }
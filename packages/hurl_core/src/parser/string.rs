@@ -19,7 +19,7 @@ use crate::ast::{SourceInfo, Template};
 use crate::combinator::one_or_more;
 use crate::parser::primitives::{hex_digit, literal, try_literal};
 use crate::parser::{template, ParseError, ParseErrorKind, ParseResult};
-use crate::reader::Reader;
+use crate::reader::{Cursor, Pos, Reader};
 
 /// Steps:
 /// 1- parse String until end of stream, end of line
@@ -81,13 +81,51 @@ pub fn quoted_oneline_string(reader: &mut Reader) -> ParseResult<String> {
 
 pub fn quoted_template(reader: &mut Reader) -> ParseResult<Template> {
     let start = reader.cursor();
-    let mut end = start;
     try_literal("\"", reader)?;
+    let chars_start = reader.cursor();
+    let (mut chars, mut end, unclosed) = quoted_chars(reader, true)?;
+    if unclosed {
+        // With an unclosed placeholder, we read the string again, ending at the first quote, so
+        // the error is reported on the placeholder.
+        reader.seek(chars_start);
+        (chars, end, _) = quoted_chars(reader, false)?;
+    }
+    literal("\"", reader)?;
+    let end = end.unwrap_or(start);
+    let encoded_string = template::EncodedString {
+        source_info: SourceInfo::new(start.pos, end.pos),
+        chars,
+    };
+    let elements = template::templatize(encoded_string)?;
+    Ok(Template {
+        delimiter: Some('"'),
+        elements,
+        source_info: SourceInfo::new(start.pos, reader.cursor().pos),
+    })
+}
+
+/// Reads the chars of a quoted string, up to the closing quote.
+///
+/// If `quote_in_placeholder` is `true`, a quote inside a placeholder `{{ }}` doesn't end the
+/// string. Returns the chars, the cursor after the last char and whether the chars end inside
+/// a placeholder.
+#[allow(clippy::type_complexity)]
+fn quoted_chars(
+    reader: &mut Reader,
+    quote_in_placeholder: bool,
+) -> ParseResult<(Vec<(char, String, Pos)>, Option<Cursor>, bool)> {
     let mut chars = vec![];
+    let mut end = None;
+    let mut placeholder = template::PlaceholderState::default();
     loop {
         let pos = reader.cursor().pos;
         let save = reader.cursor();
-        match any_char(&['"'], reader) {
+        let except: &[char] = if quote_in_placeholder && placeholder.is_inside() {
+            &[]
+        } else {
+            &['"']
+        };
+        match any_char(except, reader) {
             Err(e) => {
                 if e.recoverable {
                     reader.seek(save);
@@ -97,22 +135,13 @@ pub fn quoted_template(reader: &mut Reader) -> ParseResult<Template> {
                 }
             }
             Ok((c, s)) => {
+                placeholder.push(&s);
                 chars.push((c, s, pos));
-                end = reader.cursor();
+                end = Some(reader.cursor());
             }
         }
     }
-    literal("\"", reader)?;
-    let encoded_string = template::EncodedString {
-        source_info: SourceInfo::new(start.pos, end.pos),
-        chars,
-    };
-    let elements = template::templatize(encoded_string)?;
-    Ok(Template {
-        delimiter: Some('"'),
-        elements,
-        source_info: SourceInfo::new(start.pos, reader.cursor().pos),
-    })
+    Ok((chars, end, placeholder.is_inside()))
 }
 
 pub fn backtick_template(reader: &mut Reader) -> ParseResult<Template> {
@@ -25,6 +25,43 @@ pub struct EncodedString {
     pub chars: Vec<(char, String, Pos)>,
 }
 
+/// Tracks whether the chars of an encoded string are inside a placeholder `{{ }}`.
+///
+/// Inside a placeholder, a string delimiter doesn't end the string, so a quoted string can
+/// contain a placeholder with a quoted default value: `"{{name | default "Bob"}}"`.
+#[derive(Default)]
+pub struct PlaceholderState {
+    inside: bool,
+    last: Option<char>,
+}
+
+impl PlaceholderState {
+    /// Updates the state with the next `encoded` char of the string.
+    pub fn push(&mut self, encoded: &str) {
+        let mut chars = encoded.chars();
+        let c = match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        };
+        match (self.last, c) {
+            (Some('{'), Some('{')) => {
+                self.inside = true;
+                self.last = None;
+            }
+            (Some('}'), Some('}')) => {
+                self.inside = false;
+                self.last = None;
+            }
+            _ => self.last = c,
+        }
+    }
+
+    /// Returns `true` if the chars pushed so far end inside a placeholder.
+    pub fn is_inside(&self) -> bool {
+        self.inside
+    }
+}
+
 pub fn templatize(encoded_string: EncodedString) -> ParseResult<Vec<TemplateElement>> {
     enum State {
         String,
@@ -151,15 +151,11 @@ impl Reader {
         let mut s = String::new();
         loop {
             match self.peek() {
-                None => return s,
-                Some(c) => {
-                    if predicate(c) {
-                        _ = self.read();
-                        s.push(c);
-                    } else {
-                        return s;
-                    }
+                Some(c) if predicate(c) => {
+                    _ = self.read();
+                    s.push(c);
                 }
+                _ => return s,
             }
         }
     }
@@ -200,7 +196,7 @@ impl Reader {
 }
 
 fn is_combining_character(c: char) -> bool {
-    c > '\u{0300}' && c < '\u{036F}' // Combining Diacritical Marks (0300–036F)
+    ('\u{0300}'..='\u{036F}').contains(&c) // Combining Diacritical Marks (0300–036F)
 }
 
 #[cfg(test)]
@@ -224,6 +220,24 @@ mod tests {
         assert_eq!(reader.read(), None);
     }
 
+    #[test]
+    fn read_multibyte_chars() {
+        // Columns are counted in chars, not in bytes.
+        let mut reader = Reader::new("été🚀\nà");
+        assert_eq!(reader.read_n(4), "été🚀");
+        assert_eq!(reader.cursor().index, 4);
+        assert_eq!(reader.cursor().pos, Pos::new(1, 5));
+        assert_eq!(reader.read(), Some('\n'));
+        assert_eq!(reader.read(), Some('à'));
+        assert_eq!(reader.cursor().pos, Pos::new(2, 2));
+
+        // Combining characters don't advance the column.
+        let mut reader = Reader::new("e\u{0300}e\u{0301}e\u{036F}x");
+        assert_eq!(reader.read_n(6), "e\u{0300}e\u{0301}e\u{036F}");
+        assert_eq!(reader.cursor().index, 6);
+        assert_eq!(reader.cursor().pos, Pos::new(1, 4));
+    }
+
     #[test]
     fn peek_back() {
         let mut reader = Reader::new("abcdefgh");
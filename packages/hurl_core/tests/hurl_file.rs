@@ -0,0 +1,79 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+use hurl_core::parser::{parse_hurl_file, ParseErrorKind};
+use hurl_core::reader::Pos;
+
+#[test]
+fn test_parse_hurl_file() {
+    let content = r#"# Get a user
+GET https://example.org/users/{{id}}
+Accept: application/json
+HTTP 200
+[Asserts]
+jsonpath "$.name" == "Bob"
+
+POST https://example.org/users
+{"name": "Alice"}
+HTTP 201
+"#;
+    let hurl_file = parse_hurl_file(content).unwrap();
+    assert_eq!(hurl_file.entries.len(), 2);
+
+    let entry = &hurl_file.entries[0];
+    assert_eq!(entry.request.method.to_string(), "GET");
+    assert_eq!(
+        entry.request.url.source_info,
+        SourceInfo::new(Pos::new(2, 5), Pos::new(2, 37))
+    );
+    assert_eq!(entry.request.headers.len(), 1);
+    assert_eq!(
+        entry.request.headers[0].value.source_info,
+        SourceInfo::new(Pos::new(3, 9), Pos::new(3, 25))
+    );
+    let response = entry.response.as_ref().unwrap();
+    assert_eq!(response.asserts().len(), 1);
+
+    let entry = &hurl_file.entries[1];
+    assert_eq!(entry.request.method.to_string(), "POST");
+    assert!(entry.request.body.is_some());
+
+    // The AST is cloneable so tools can transform it.
+    let clone = hurl_file.clone();
+    assert_eq!(clone, hurl_file);
+}
+
+#[test]
+fn test_parse_hurl_file_error() {
+    let error = parse_hurl_file("GET https://example.org\nHTTP abc\n")
+        .err()
+        .unwrap();
+    assert_eq!(error.pos, Pos::new(2, 6));
+    assert!(matches!(error.kind, ParseErrorKind::Status));
+}
+
+#[test]
+fn test_parse_hurl_file_error_multibyte() {
+    // Error columns are counted in chars, not in bytes.
+    let error = parse_hurl_file(
+        "GET https://example.org/été\nHTTP 200\n[Asserts]\nheader \"été 🚀\" ?? 1\n",
+    )
+    .err()
+    .unwrap();
+    assert_eq!(error.pos, Pos::new(4, 16));
+}
@@ -162,9 +162,16 @@ impl ToJson for Bytes {
                 ("type".to_string(), JValue::String("json".to_string())),
                 ("value".to_string(), value.to_json()),
             ]),
+            Bytes::NdJson(value) => JValue::Object(vec![
+                ("type".to_string(), JValue::String("ndjson".to_string())),
+                (
+                    "value".to_string(),
+                    JValue::List(value.values.iter().map(|v| v.value.to_json()).collect()),
+                ),
+            ]),
             Bytes::Xml(value) => JValue::Object(vec![
                 ("type".to_string(), JValue::String("xml".to_string())),
-                ("value".to_string(), JValue::String(value.clone())),
+                ("value".to_string(), JValue::String(value.to_string())),
             ]),
             Bytes::OnelineString(value) => JValue::Object(vec![
                 ("type".to_string(), JValue::String("text".to_string())),
@@ -199,10 +206,18 @@ impl ToJson for Bytes {
                         kind: MultilineStringKind::Xml(_),
                         ..
                     } => "xml",
+                    MultilineString {
+                        kind: MultilineStringKind::Html(_),
+                        ..
+                    } => "html",
                     MultilineString {
                         kind: MultilineStringKind::GraphQl(_),
                         ..
                     } => "graphql",
+                    MultilineString {
+                        kind: MultilineStringKind::Other(lang, _),
+                        ..
+                    } => lang,
                 };
                 JValue::Object(vec![
                     ("type".to_string(), JValue::String(lang.to_string())),
@@ -287,6 +302,12 @@ impl ToJson for FileParam {
         if let Some(content_type) = self.value.content_type.clone() {
             attributes.push(("content_type".to_string(), JValue::String(content_type)));
         }
+        if let Some(part_filename) = &self.value.part_filename {
+            attributes.push((
+                "part_filename".to_string(),
+                JValue::String(part_filename.value.to_string()),
+            ));
+        }
         JValue::Object(attributes)
     }
 }
@@ -306,8 +327,10 @@ impl ToJson for EntryOption {
         let value = match &self.kind {
             OptionKind::AwsSigV4(value) => JValue::String(value.to_string()),
             OptionKind::CaCertificate(filename) => JValue::String(filename.to_string()),
+            OptionKind::Chunked(value) => value.to_json(),
             OptionKind::ClientCert(filename) => JValue::String(filename.to_string()),
             OptionKind::ClientKey(filename) => JValue::String(filename.to_string()),
+            OptionKind::CompressBody(value) => JValue::String(value.to_string()),
             OptionKind::Compressed(value) => value.to_json(),
             OptionKind::ConnectTo(value) => JValue::String(value.to_string()),
             OptionKind::ConnectTimeout(value) => value.to_json(),
@@ -323,6 +346,7 @@ impl ToJson for EntryOption {
             OptionKind::IpV6(value) => value.to_json(),
             OptionKind::LimitRate(value) => value.to_json(),
             OptionKind::MaxRedirect(value) => value.to_json(),
+            OptionKind::MaxTime(value) => value.to_json(),
             OptionKind::NetRc(value) => value.to_json(),
             OptionKind::NetRcFile(filename) => JValue::String(filename.to_string()),
             OptionKind::NetRcOptional(value) => value.to_json(),
@@ -333,7 +357,10 @@ impl ToJson for EntryOption {
             OptionKind::Resolve(value) => JValue::String(value.to_string()),
             OptionKind::Retry(value) => value.to_json(),
             OptionKind::RetryInterval(value) => value.to_json(),
+            OptionKind::RetryMaxTime(value) => value.to_json(),
             OptionKind::Skip(value) => value.to_json(),
+            OptionKind::SkipUnless(value) => value.to_json(),
+            OptionKind::Unknown(option) => JValue::String(option.value.clone()),
             OptionKind::UnixSocket(value) => JValue::String(value.to_string()),
             OptionKind::User(value) => JValue::String(value.to_string()),
             OptionKind::Variable(value) => {
@@ -456,6 +483,12 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         QueryValue::Url => {
             attributes.push(("type".to_string(), JValue::String("url".to_string())));
         }
+        QueryValue::Redirects => {
+            attributes.push(("type".to_string(), JValue::String("redirects".to_string())));
+        }
+        QueryValue::Location => {
+            attributes.push(("type".to_string(), JValue::String("location".to_string())));
+        }
         QueryValue::Body => {
             attributes.push(("type".to_string(), JValue::String("body".to_string())));
         }
@@ -483,6 +516,9 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
             attributes.push(("type".to_string(), JValue::String("variable".to_string())));
             attributes.push(("name".to_string(), JValue::String(name.to_string())));
         }
+        QueryValue::Version => {
+            attributes.push(("type".to_string(), JValue::String("version".to_string())));
+        }
         QueryValue::Duration => {
             attributes.push(("type".to_string(), JValue::String("duration".to_string())));
         }
@@ -598,6 +634,13 @@ impl ToJson for Predicate {
                 attributes.push(("type".to_string(), JValue::String("match".to_string())));
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::MatchSchema { value, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("match-schema".to_string()),
+                ));
+                add_predicate_value(&mut attributes, value);
+            }
             PredicateFuncValue::IsInteger => {
                 attributes.push(("type".to_string(), JValue::String("isInteger".to_string())));
             }
@@ -704,6 +747,24 @@ impl ToJson for FilterValue {
     fn to_json(&self) -> JValue {
         let mut attributes = vec![];
         match self {
+            FilterValue::Base64Decode => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("base64Decode".to_string()),
+                ));
+            }
+            FilterValue::Base64Encode => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("base64Encode".to_string()),
+                ));
+            }
+            FilterValue::Base64UrlSafeEncode => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("base64UrlSafeEncode".to_string()),
+                ));
+            }
             FilterValue::Count => {
                 attributes.push(("type".to_string(), JValue::String("count".to_string())));
             }
@@ -731,6 +792,12 @@ impl ToJson for FilterValue {
                 attributes.push(("type".to_string(), JValue::String("jsonpath".to_string())));
                 attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
             }
+            FilterValue::JwtPayload => {
+                attributes.push(("type".to_string(), JValue::String("jwtPayload".to_string())));
+            }
+            FilterValue::Length => {
+                attributes.push(("type".to_string(), JValue::String("length".to_string())));
+            }
             FilterValue::Nth { n, .. } => {
                 attributes.push(("type".to_string(), JValue::String("nth".to_string())));
                 attributes.push(("n".to_string(), JValue::Number(n.to_string())));
@@ -777,9 +844,15 @@ impl ToJson for FilterValue {
             FilterValue::ToFloat => {
                 attributes.push(("type".to_string(), JValue::String("toFloat".to_string())));
             }
+            FilterValue::ToHex => {
+                attributes.push(("type".to_string(), JValue::String("toHex".to_string())));
+            }
             FilterValue::ToInt => {
                 attributes.push(("type".to_string(), JValue::String("toInt".to_string())));
             }
+            FilterValue::ToLower => {
+                attributes.push(("type".to_string(), JValue::String("toLower".to_string())));
+            }
             FilterValue::XPath { expr, .. } => {
                 attributes.push(("type".to_string(), JValue::String("xpath".to_string())));
                 attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
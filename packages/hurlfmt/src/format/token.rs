@@ -24,7 +24,7 @@ use hurl_core::ast::{
     MultipartParam, NaturalOption, OptionKind, Placeholder, Predicate, PredicateFunc,
     PredicateFuncValue, PredicateValue, Query, QueryValue, Regex, RegexValue, Request, Response,
     Section, SectionValue, Status, StatusValue, Template, TemplateElement, Text, Variable,
-    VariableDefinition, VariableValue, Version, Whitespace, I64, U64,
+    VariableDefault, VariableDefinition, VariableValue, Version, Whitespace, I64, U64,
 };
 use hurl_core::typing::{Count, Duration};
 
@@ -178,7 +178,13 @@ impl Tokenizable for Bytes {
         let mut tokens: Vec<Token> = vec![];
         match self {
             Bytes::Json(value) => tokens.append(&mut value.tokenize()),
-            Bytes::Xml(value) => tokens.push(Token::String(value.to_string())),
+            Bytes::NdJson(value) => {
+                for value in &value.values {
+                    tokens.push(Token::Whitespace(value.space0.clone()));
+                    tokens.append(&mut value.value.tokenize());
+                }
+            }
+            Bytes::Xml(value) => tokens.append(&mut value.tokenize()),
             Bytes::MultilineString(value) => tokens.append(&mut value.tokenize()),
             Bytes::OnelineString(value) => tokens.append(&mut value.tokenize()),
             Bytes::Base64(value) => tokens.append(&mut value.tokenize()),
@@ -244,7 +250,7 @@ impl Tokenizable for SectionValue {
 
 impl Tokenizable for Base64 {
     fn tokenize(&self) -> Vec<Token> {
-        let mut tokens: Vec<Token> = vec![Token::Keyword(String::from("base64,"))];
+        let mut tokens: Vec<Token> = vec![Token::Keyword(format!("{},", self.keyword()))];
         tokens.append(&mut self.space0.tokenize());
         tokens.push(Token::String(self.encoded.to_string()));
         tokens.append(&mut self.space1.tokenize());
@@ -330,6 +336,15 @@ impl Tokenizable for FileValue {
         if let Some(content_type) = self.content_type.clone() {
             tokens.push(Token::String(content_type));
         }
+        if let Some(part_filename) = &self.part_filename {
+            if self.content_type.is_some() {
+                tokens.append(&mut part_filename.space0.tokenize());
+                tokens.push(Token::Keyword(";".to_string()));
+                tokens.append(&mut part_filename.space1.tokenize());
+            }
+            tokens.push(Token::Keyword("filename=".to_string()));
+            tokens.append(&mut part_filename.value.tokenize());
+        }
         tokens
     }
 }
@@ -417,6 +432,8 @@ impl Tokenizable for QueryValue {
         match self.clone() {
             QueryValue::Status => tokens.push(Token::QueryType(String::from("status"))),
             QueryValue::Url => tokens.push(Token::QueryType(String::from("url"))),
+            QueryValue::Redirects => tokens.push(Token::QueryType(String::from("redirects"))),
+            QueryValue::Location => tokens.push(Token::QueryType(String::from("location"))),
             QueryValue::Header { space0, name } => {
                 tokens.push(Token::QueryType(String::from("header")));
                 tokens.append(&mut space0.tokenize());
@@ -450,6 +467,7 @@ impl Tokenizable for QueryValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut name.tokenize());
             }
+            QueryValue::Version => tokens.push(Token::QueryType(String::from("version"))),
             QueryValue::Duration => tokens.push(Token::QueryType(String::from("duration"))),
             QueryValue::Bytes => tokens.push(Token::QueryType(String::from("bytes"))),
             QueryValue::Sha256 => tokens.push(Token::QueryType(String::from("sha256"))),
@@ -593,6 +611,11 @@ impl Tokenizable for PredicateFuncValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut value.tokenize());
             }
+            PredicateFuncValue::MatchSchema { space0, value } => {
+                tokens.push(Token::PredicateType(name));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+            }
             PredicateFuncValue::IsInteger => {
                 tokens.push(Token::PredicateType(name));
             }
@@ -667,6 +690,14 @@ impl Tokenizable for MultilineString {
             | MultilineString {
                 kind: MultilineStringKind::Xml(text),
                 ..
+            }
+            | MultilineString {
+                kind: MultilineStringKind::Html(text),
+                ..
+            }
+            | MultilineString {
+                kind: MultilineStringKind::Other(_, text),
+                ..
             } => tokens.append(&mut text.tokenize()),
             MultilineString {
                 kind: MultilineStringKind::GraphQl(graphql),
@@ -683,6 +714,7 @@ impl Tokenizable for MultilineStringAttribute {
         match self {
             MultilineStringAttribute::Escape => vec![Token::String("escape".to_string())],
             MultilineStringAttribute::NoVariable => vec![Token::String("novariable".to_string())],
+            MultilineStringAttribute::Lenient => vec![Token::String("lenient".to_string())],
         }
     }
 }
@@ -792,11 +824,25 @@ impl Tokenizable for ExprKind {
     fn tokenize(&self) -> Vec<Token> {
         match self {
             ExprKind::Variable(variable) => variable.tokenize(),
+            ExprKind::VariableDefault(default) => default.tokenize(),
             ExprKind::Function(function) => function.tokenize(),
         }
     }
 }
 
+impl Tokenizable for VariableDefault {
+    fn tokenize(&self) -> Vec<Token> {
+        let mut tokens = self.variable.tokenize();
+        tokens.append(&mut self.space0.tokenize());
+        tokens.push(Token::CodeDelimiter(String::from("|")));
+        tokens.append(&mut self.space1.tokenize());
+        tokens.push(Token::Keyword(String::from("default")));
+        tokens.append(&mut self.space2.tokenize());
+        tokens.append(&mut self.value.tokenize());
+        tokens
+    }
+}
+
 impl Tokenizable for Variable {
     fn tokenize(&self) -> Vec<Token> {
         vec![Token::CodeVariable(self.name.clone())]
@@ -945,8 +991,10 @@ impl Tokenizable for OptionKind {
         match self {
             OptionKind::AwsSigV4(value) => value.tokenize(),
             OptionKind::CaCertificate(filename) => filename.tokenize(),
+            OptionKind::Chunked(value) => value.tokenize(),
             OptionKind::ClientCert(filename) => filename.tokenize(),
             OptionKind::ClientKey(filename) => filename.tokenize(),
+            OptionKind::CompressBody(value) => value.tokenize(),
             OptionKind::Compressed(value) => value.tokenize(),
             OptionKind::ConnectTo(value) => value.tokenize(),
             OptionKind::ConnectTimeout(value) => value.tokenize(),
@@ -962,6 +1010,7 @@ impl Tokenizable for OptionKind {
             OptionKind::IpV6(value) => value.tokenize(),
             OptionKind::LimitRate(value) => value.tokenize(),
             OptionKind::MaxRedirect(value) => value.tokenize(),
+            OptionKind::MaxTime(value) => value.tokenize(),
             OptionKind::NetRc(value) => value.tokenize(),
             OptionKind::NetRcFile(filename) => filename.tokenize(),
             OptionKind::NetRcOptional(value) => value.tokenize(),
@@ -972,7 +1021,10 @@ impl Tokenizable for OptionKind {
             OptionKind::Resolve(value) => value.tokenize(),
             OptionKind::Retry(value) => value.tokenize(),
             OptionKind::RetryInterval(value) => value.tokenize(),
+            OptionKind::RetryMaxTime(value) => value.tokenize(),
             OptionKind::Skip(value) => value.tokenize(),
+            OptionKind::SkipUnless(value) => value.tokenize(),
+            OptionKind::Unknown(option) => vec![Token::String(option.value.clone())],
             OptionKind::UnixSocket(value) => value.tokenize(),
             OptionKind::User(value) => value.tokenize(),
             OptionKind::Variable(value) => value.tokenize(),
@@ -1074,6 +1126,11 @@ impl Tokenizable for VariableValue {
 impl Tokenizable for Filter {
     fn tokenize(&self) -> Vec<Token> {
         match self.value.clone() {
+            FilterValue::Base64Decode => vec![Token::FilterType(String::from("base64Decode"))],
+            FilterValue::Base64Encode => vec![Token::FilterType(String::from("base64Encode"))],
+            FilterValue::Base64UrlSafeEncode => {
+                vec![Token::FilterType(String::from("base64UrlSafeEncode"))]
+            }
             FilterValue::Count => vec![Token::FilterType(String::from("count"))],
             FilterValue::DaysAfterNow => vec![Token::FilterType(String::from("daysAfterNow"))],
             FilterValue::DaysBeforeNow => vec![Token::FilterType(String::from("daysBeforeNow"))],
@@ -1099,6 +1156,8 @@ impl Tokenizable for Filter {
                 tokens.append(&mut expr.tokenize());
                 tokens
             }
+            FilterValue::JwtPayload => vec![Token::FilterType(String::from("jwtPayload"))],
+            FilterValue::Length => vec![Token::FilterType(String::from("length"))],
             FilterValue::Nth { space0, n } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("nth"))];
                 tokens.append(&mut space0.tokenize());
@@ -1139,7 +1198,9 @@ impl Tokenizable for Filter {
                 tokens
             }
             FilterValue::ToFloat => vec![Token::FilterType(String::from("toFloat"))],
+            FilterValue::ToHex => vec![Token::FilterType(String::from("toHex"))],
             FilterValue::ToInt => vec![Token::FilterType(String::from("toInt"))],
+            FilterValue::ToLower => vec![Token::FilterType(String::from("toLower"))],
             FilterValue::XPath { space0, expr } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("xpath"))];
                 tokens.append(&mut space0.tokenize());
@@ -112,6 +112,15 @@ fn check_response(response: &Response) -> Vec<LinterError> {
             kind: LinterErrorKind::UnnecessarySpace,
         });
     }
+    if response.space1.value != " " {
+        errors.push(LinterError {
+            source_info: response.space1.source_info,
+            kind: LinterErrorKind::OneSpace,
+        });
+    }
+    for error in check_line_terminator(&response.line_terminator0) {
+        errors.push(error);
+    }
     errors.extend(response.sections.iter().flat_map(check_section));
     errors
 }
@@ -120,13 +129,13 @@ fn lint_response(response: &Response) -> Response {
     let line_terminators = response.line_terminators.clone();
     let space0 = empty_whitespace();
     let version = response.version.clone();
-    let space1 = response.space1.clone();
+    let space1 = one_whitespace();
     let status = response.status.clone();
-    let line_terminator0 = response.line_terminator0.clone();
+    let line_terminator0 = lint_line_terminator(&response.line_terminator0);
     let headers = response.headers.iter().map(lint_key_value).collect();
     let mut sections: Vec<Section> = response.sections.iter().map(lint_section).collect();
     sections.sort_by_key(|k| section_value_index(k.value.clone()));
-    let body = response.body.clone();
+    let body = response.body.as_ref().map(lint_body);
 
     Response {
         line_terminators,
@@ -260,6 +269,8 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
     match query_value {
         QueryValue::Status => QueryValue::Status,
         QueryValue::Url => QueryValue::Url,
+        QueryValue::Redirects => QueryValue::Redirects,
+        QueryValue::Location => QueryValue::Location,
         QueryValue::Header { name, .. } => QueryValue::Header {
             name: name.clone(),
             space0: one_whitespace(),
@@ -294,6 +305,7 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
             name: name.clone(),
             space0: one_whitespace(),
         },
+        QueryValue::Version => QueryValue::Version,
         QueryValue::Duration => QueryValue::Duration,
         QueryValue::Bytes => QueryValue::Bytes,
         QueryValue::Sha256 => QueryValue::Sha256,
@@ -400,6 +412,10 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
             space0: one_whitespace(),
             value: lint_predicate_value(value),
         },
+        PredicateFuncValue::MatchSchema { value, .. } => PredicateFuncValue::MatchSchema {
+            space0: one_whitespace(),
+            value: lint_predicate_value(value),
+        },
         PredicateFuncValue::StartWith { value, .. } => PredicateFuncValue::StartWith {
             space0: one_whitespace(),
             value: lint_predicate_value(value),
@@ -461,6 +477,20 @@ fn lint_multiline_string(multiline_string: &MultilineString) -> MultilineString
             kind: MultilineStringKind::Xml(lint_text(value)),
             attributes: lint_multiline_string_attributes(attributes),
         },
+        MultilineString {
+            kind: MultilineStringKind::Html(value),
+            attributes,
+        } => MultilineString {
+            kind: MultilineStringKind::Html(lint_text(value)),
+            attributes: lint_multiline_string_attributes(attributes),
+        },
+        MultilineString {
+            kind: MultilineStringKind::Other(lang, value),
+            attributes,
+        } => MultilineString {
+            kind: MultilineStringKind::Other(lang.clone(), lint_text(value)),
+            attributes: lint_multiline_string_attributes(attributes),
+        },
         MultilineString {
             kind: MultilineStringKind::GraphQl(value),
             attributes,
@@ -524,6 +554,7 @@ fn lint_bytes(bytes: &Bytes) -> Bytes {
         Bytes::Base64(value) => Bytes::Base64(lint_base64(value)),
         Bytes::Hex(value) => Bytes::Hex(lint_hex(value)),
         Bytes::Json(value) => Bytes::Json(value.clone()),
+        Bytes::NdJson(value) => Bytes::NdJson(value.clone()),
         Bytes::OnelineString(value) => Bytes::OnelineString(lint_template(value)),
         Bytes::MultilineString(value) => Bytes::MultilineString(lint_multiline_string(value)),
         Bytes::Xml(value) => Bytes::Xml(value.clone()),
@@ -536,6 +567,7 @@ fn lint_base64(base64: &Base64) -> Base64 {
         value: base64.value.clone(),
         encoded: base64.encoded.clone(),
         space1: empty_whitespace(),
+        url_safe: base64.url_safe,
     }
 }
 
@@ -688,6 +720,9 @@ fn lint_option_kind(option_kind: &OptionKind) -> OptionKind {
         OptionKind::RetryInterval(duration) => {
             OptionKind::RetryInterval(lint_duration_option(duration, DurationUnit::MilliSecond))
         }
+        OptionKind::RetryMaxTime(duration) => {
+            OptionKind::RetryMaxTime(lint_duration_option(duration, DurationUnit::Second))
+        }
         OptionKind::Variable(var_def) => OptionKind::Variable(lint_variable_definition(var_def)),
         _ => option_kind.clone(),
     }
@@ -0,0 +1,80 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::parser::parse_hurl_file;
+use hurlfmt::format::format_text;
+use hurlfmt::linter::lint_hurl_file;
+
+/// Formats a Hurl file content to its canonical form.
+fn format(content: &str) -> String {
+    let hurl_file = parse_hurl_file(content).unwrap();
+    let hurl_file = lint_hurl_file(&hurl_file);
+    format_text(&hurl_file, false)
+}
+
+#[test]
+fn test_format_bodies() {
+    let samples = [
+        (
+            "POST http://localhost:8000/file\nfile,   data.bin   ;\n",
+            "POST http://localhost:8000/file\nfile,data.bin;\n",
+        ),
+        (
+            "POST http://localhost:8000/base64\nbase64,  SGVsbG8gd29ybGQ=  ;\n",
+            "POST http://localhost:8000/base64\nbase64,SGVsbG8gd29ybGQ=;\n",
+        ),
+        (
+            "POST http://localhost:8000/hex\nhex,  48656c6c6f ;\n",
+            "POST http://localhost:8000/hex\nhex,48656c6c6f;\n",
+        ),
+        (
+            "POST   http://localhost:8000/json\n{ \"name\" :  \"{{name}}\", \"ids\": [1,2] }\nHTTP   200\n",
+            "POST http://localhost:8000/json\n{ \"name\" :  \"{{name}}\", \"ids\": [1,2] }\nHTTP 200\n",
+        ),
+        (
+            "POST http://localhost:8000/xml\n<a id=\"{{id}}\">&amp;</a>\n",
+            "POST http://localhost:8000/xml\n<a id=\"{{id}}\">&amp;</a>\n",
+        ),
+        (
+            "POST http://localhost:8000/xml\n<a id=\"{{ id }}\">&#123;{</a>\n",
+            "POST http://localhost:8000/xml\n<a id=\"{{ id }}\">&#123;{</a>\n",
+        ),
+        (
+            "POST http://localhost:8000/text\n```\nHello {{name}}\n```\n",
+            "POST http://localhost:8000/text\n```\nHello {{name}}\n```\n",
+        ),
+    ];
+    for (content, expected) in samples {
+        let formatted = format(content);
+        assert_eq!(formatted, expected);
+        // Formatting is stable: formatting a formatted file doesn't change it.
+        assert_eq!(format(&formatted), formatted);
+    }
+}
+
+#[test]
+fn test_format_keeps_json_bodies() {
+    // JSON and NDJSON bodies are sent as written, and implicit response body asserts compare
+    // the exact text: linting must not change them.
+    let contents = [
+        "POST http://localhost:8000/json\n[ {\"id\":1,\"tags\":[ ]},{ } ]\nHTTP 200\n{\n    \"id\" :1\n}\n",
+        "POST http://localhost:8000/ndjson\n{\"id\":1,  \"tags\":[\"a\",\"b\"]}\n\n   {\"id\" : 2}\nHTTP 200\n{\"id\":1}\n[ 2 ]\n",
+    ];
+    for content in contents {
+        assert_eq!(format(content), content);
+    }
+}